@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use namada_sdk::address::PGF;
 use namada_sdk::governance::utils::TallyResult;
 use namada_sdk::rpc;
 use namada_sdk::token::Amount;
@@ -23,20 +24,39 @@ impl DoCheck for InflationCheck {
             .await
             .map_err(|e| format!("Failed to query total supply: {e}"))?;
 
+        let current_pgf_balance =
+            rpc::get_token_balance(&sdk.namada.client, &native_token, &PGF, None)
+                .await
+                .map_err(|e| format!("Failed to query PGF balance: {e}"))?;
+
         let rejected = count_rejected_proposals(sdk, state).await?;
         let burned_amount = Amount::from_u64(rejected * PROPOSAL_DEPOSIT);
         let last_total_supply = state
             .last_total_supply
             .checked_sub(burned_amount)
             .unwrap_or_default();
+        // The PGF inflation is minted to the PGF account, and the PGF payments are sent from it.
+        // The minted amount should be at least the increase of the PGF balance.
+        let pgf_minted = current_pgf_balance
+            .checked_sub(state.last_pgf_balance)
+            .unwrap_or_default();
+        let min_total_supply = last_total_supply
+            .checked_add(pgf_minted)
+            .ok_or_else(|| "Total supply is overflowing".to_string())?;
 
-        if last_total_supply <= current_total_supply {
+        if min_total_supply <= current_total_supply {
             tracing::info!(
-                "Total supply ok: before {} -> after {current_total_supply}",
+                "Total supply ok: before {} -> after {current_total_supply} (PGF minted at least {pgf_minted})",
                 state.last_total_supply
             );
             state.last_total_supply = current_total_supply;
+            state.last_pgf_balance = current_pgf_balance;
             Ok(())
+        } else if last_total_supply <= current_total_supply {
+            Err(format!(
+                "Total supply didn't include PGF minting: before: {} + PGF minted {} -> after {}",
+                last_total_supply, pgf_minted, current_total_supply
+            ))
         } else {
             Err(format!(
                 "Total supply decreased: before: {} -> after {}",
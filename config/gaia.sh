@@ -8,7 +8,9 @@ GRPC_PORT=9090
 NUM_USERS=3
 
 STAKE="100000000000stake"
-USER_COINS="${STAKE},1000000samoleans"
+# the over-limit token exceeds the default IBC mint limit on Namada
+OVER_LIMIT_COINS="10000000000000000000overlimit"
+USER_COINS="${STAKE},1000000samoleans,${OVER_LIMIT_COINS}"
 
 if [ ! -d "${BASE_DIR}/config" ]
 then
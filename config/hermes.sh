@@ -107,4 +107,24 @@ then
     echo "cosmos->namada ${gaia_channel_id}" >> /container_ready/ibc_channels
 fi
 
+# ICS-721 channel is created only when the counterparty has the nft-transfer port
+if [ ! -e /container_ready/ibc_nft_channels ]
+then
+    result=$(hermes --config config.toml \
+      create channel --a-chain $NAMADA_CHAIN_ID \
+      --b-chain $GAIA_CHAIN_ID \
+      --a-port nft-transfer \
+      --b-port nft-transfer \
+      --new-client-connection --yes)
+
+    namada_nft_channel_id=$(echo $result | sed -n 's/.*a_side:.*channel_id: Some( ChannelId( "\([^"]*\)".*/\1/p')
+    gaia_nft_channel_id=$(echo $result | sed -n 's/.*b_side:.*channel_id: Some( ChannelId( "\([^"]*\)".*/\1/p')
+
+    if [ -n "$namada_nft_channel_id" ] && [ -n "$gaia_nft_channel_id" ]
+    then
+        echo "namada->cosmos ${namada_nft_channel_id}" > /container_ready/ibc_nft_channels
+        echo "cosmos->namada ${gaia_nft_channel_id}" >> /container_ready/ibc_nft_channels
+    fi
+fi
+
 hermes --config config.toml start
@@ -1,5 +1,7 @@
 touch state-123.json
 echo "" > state-123.json
+rm -f state-123.json.*
+rm -f steps-123.jsonl
 
 mkdir -p base/wallet-123
 mkdir -p base/masp-123
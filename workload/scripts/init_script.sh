@@ -41,6 +41,16 @@ done
 namada_channel_id=$(grep "namada->cosmos" /container_ready/ibc_channels | grep -o "channel-[0-9]\+")
 cosmos_channel_id=$(grep "cosmos->namada" /container_ready/ibc_channels | grep -o "channel-[0-9]\+")
 
+# NFT transfer channels exist only when the counterparty supports ICS-721
+nft_channels=""
+if [ -f /container_ready/ibc_nft_channels ]
+then
+    namada_nft_channel_id=$(grep "namada->cosmos" /container_ready/ibc_nft_channels | grep -o "channel-[0-9]\+")
+    cosmos_nft_channel_id=$(grep "cosmos->namada" /container_ready/ibc_nft_channels | grep -o "channel-[0-9]\+")
+    nft_channels="namada_nft_channel_id = \"${namada_nft_channel_id}\"
+cosmos_nft_channel_id = \"${cosmos_nft_channel_id}\""
+fi
+
 echo "Creating config.toml..."
 cat <<EOF > config.toml
 id = ${WORKLOAD_ID}
@@ -48,11 +58,17 @@ chain_id = "${CHAIN_ID}"
 rpc = "http://${RPC}"
 masp_indexer_url = "${MASP_INDEXER_URL}"
 faucet_sk = "${FAUCET_SK}"
-cosmos_rpc = "http://${COSMOS_RPC}"
-cosmos_grpc = "http://${COSMOS_GRPC}"
-cosmos_base_dir = "${COSMOS_DIR}"
+
+[[counterparties]]
+chain_id = "gaia-0"
+rpc = "http://${COSMOS_RPC}"
+grpc = "http://${COSMOS_GRPC}"
+base_dir = "${COSMOS_DIR}"
 namada_channel_id = "${namada_channel_id}"
 cosmos_channel_id = "${cosmos_channel_id}"
+tokens = ["samoleans"]
+over_limit_token = "overlimit"
+${nft_channels}
 EOF
 
 echo "Initializing workload-${WORKLOAD_ID} state..."
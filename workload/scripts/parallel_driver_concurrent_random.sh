@@ -0,0 +1,5 @@
+#!/bin/bash
+
+set -e
+
+/app/namada-chain-workload --config config.toml concurrent-random --concurrent
\ No newline at end of file
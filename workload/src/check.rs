@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Display, Formatter};
 
 use enum_dispatch::enum_dispatch;
@@ -11,14 +11,27 @@ use crate::types::{Alias, Balance, Fee, Height};
 use crate::utils::{is_native_denom, RetryConfig};
 
 pub mod account_exist;
+pub mod balance_foreign_source;
+pub mod balance_foreign_target;
 pub mod balance_shielded_source;
 pub mod balance_shielded_target;
 pub mod balance_source;
 pub mod balance_target;
 pub mod bond_decrease;
 pub mod bond_increase;
+pub mod foreign_nft_owner;
+pub mod ibc_mint_limit;
+pub mod masp_conservation;
+pub mod nft_owner;
+pub mod proposal_code;
+pub mod proposal_tally;
 pub mod reveal_pk;
+pub mod steward_commission;
+pub mod steward_membership;
+pub mod unbond_increase;
+pub mod unbond_withdrawn;
 pub mod validator_account;
+pub mod validator_commission;
 pub mod validator_status;
 pub mod vote_result;
 
@@ -29,12 +42,25 @@ pub enum Check {
     BalanceSource(balance_source::BalanceSource),
     BalanceShieldedTarget(balance_shielded_target::BalanceShieldedTarget),
     BalanceShieldedSource(balance_shielded_source::BalanceShieldedSource),
+    BalanceForeignSource(balance_foreign_source::BalanceForeignSource),
+    BalanceForeignTarget(balance_foreign_target::BalanceForeignTarget),
     BondIncrease(bond_increase::BondIncrease),
     BondDecrease(bond_decrease::BondDecrease),
+    UnbondIncrease(unbond_increase::UnbondIncrease),
+    UnbondWithdrawn(unbond_withdrawn::UnbondWithdrawn),
     AccountExist(account_exist::AccountExist),
     IsValidatorAccount(validator_account::ValidatorAccount),
     ValidatorStatus(validator_status::ValidatorStatus),
+    ValidatorCommission(validator_commission::ValidatorCommission),
     VoteResult(vote_result::VoteResult),
+    StewardMembership(steward_membership::StewardMembership),
+    StewardCommission(steward_commission::StewardCommission),
+    ProposalCode(proposal_code::ProposalCode),
+    ProposalTally(proposal_tally::ProposalTally),
+    NftOwner(nft_owner::NftOwner),
+    ForeignNftOwner(foreign_nft_owner::ForeignNftOwner),
+    MaspConservation(masp_conservation::MaspConservation),
+    IbcMintLimit(ibc_mint_limit::IbcMintLimit),
 }
 
 impl Display for Check {
@@ -44,14 +70,41 @@ impl Display for Check {
 }
 
 impl Check {
+    /// The balance check of the rejected tx, which shouldn't change the balance
+    /// except for the fee
+    pub fn into_rejected(mut self) -> Option<Check> {
+        match &mut self {
+            Check::BalanceSource(bs) => bs.set_amount(0),
+            Check::BalanceTarget(bt) => bt.set_amount(0),
+            Check::BalanceShieldedSource(bss) => bss.set_amount(0),
+            Check::BalanceShieldedTarget(bst) => bst.set_amount(0),
+            _ => return None,
+        }
+        Some(self)
+    }
+
+    /// Expect the continuous PGF payments which aren't included in the pre
+    /// balance
+    pub fn add_pgf_payments(&mut self, payments: &BTreeMap<Alias, u64>) {
+        match self {
+            Check::BalanceSource(bs) if is_native_denom(bs.denom()) => {
+                if let Some(payment) = payments.get(bs.target()) {
+                    bs.set_pgf_payment(*payment);
+                }
+            }
+            Check::BalanceTarget(bt) if is_native_denom(bt.denom()) => {
+                if let Some(payment) = payments.get(bt.target()) {
+                    bt.set_pgf_payment(*payment);
+                }
+            }
+            _ => {}
+        }
+    }
+
     pub fn assert_pre_balance(&self, state: &State) {
         let (matched, details) = match self {
             Check::BalanceSource(bs) => {
-                let expected_pre_balance = if is_native_denom(bs.denom()) {
-                    state.get_balance_for(bs.target())
-                } else {
-                    state.get_ibc_balance_for(bs.target(), bs.denom())
-                };
+                let expected_pre_balance = state.get_balance_for(bs.target(), bs.denom());
                 let matched = bs.pre_balance() == Balance::from_u64(expected_pre_balance);
                 let details = json!({
                     "source_alias": bs.target(),
@@ -67,11 +120,7 @@ impl Check {
                 (matched, details)
             }
             Check::BalanceTarget(bt) => {
-                let expected_pre_balance = if is_native_denom(bt.denom()) {
-                    state.get_balance_for(bt.target())
-                } else {
-                    state.get_ibc_balance_for(bt.target(), bt.denom())
-                };
+                let expected_pre_balance = state.get_balance_for(bt.target(), bt.denom());
                 let matched = bt.pre_balance() == Balance::from_u64(expected_pre_balance);
                 let details = json!({
                     "target_alias": bt.target(),
@@ -87,14 +136,12 @@ impl Check {
                 (matched, details)
             }
             Check::BalanceShieldedSource(bss) => {
-                let expected_pre_balance = if is_native_denom(bss.denom()) {
-                    state.get_shielded_balance_for(bss.target())
-                } else {
-                    state.get_ibc_balance_for(bss.target(), bss.denom())
-                };
+                let expected_pre_balance =
+                    state.get_shielded_balance_for(bss.target(), bss.denom());
                 let matched = bss.pre_balance() == Balance::from_u64(expected_pre_balance);
                 let details = json!({
                     "source_alias": bss.target(),
+                    "denom": bss.denom(),
                     "expected_pre_balance": expected_pre_balance,
                     "actual_pre_balance": bss.pre_balance(),
                 });
@@ -106,14 +153,12 @@ impl Check {
                 (matched, details)
             }
             Check::BalanceShieldedTarget(bst) => {
-                let expected_pre_balance = if is_native_denom(bst.denom()) {
-                    state.get_shielded_balance_for(bst.target())
-                } else {
-                    state.get_ibc_balance_for(bst.target(), bst.denom())
-                };
+                let expected_pre_balance =
+                    state.get_shielded_balance_for(bst.target(), bst.denom());
                 let matched = bst.pre_balance() == Balance::from_u64(expected_pre_balance);
                 let details = json!({
                     "target_alias": bst.target(),
+                    "denom": bst.denom(),
                     "expected_pre_balance": expected_pre_balance,
                     "actual_pre_balance": bst.pre_balance(),
                 });
@@ -124,6 +169,92 @@ impl Check {
                 );
                 (matched, details)
             }
+            Check::BalanceForeignSource(bfs) if bfs.is_wrapped_nam() => {
+                let expected_pre_balance = state.get_foreign_balance_for(bfs.target());
+                let matched = bfs.pre_balance() == expected_pre_balance;
+                let details = json!({
+                    "source": bfs.target(),
+                    "denom": bfs.denom(),
+                    "expected_pre_balance": expected_pre_balance,
+                    "actual_pre_balance": bfs.pre_balance(),
+                });
+                antithesis_sdk::assert_always_or_unreachable!(
+                    matched,
+                    "Foreign source pre balance matched",
+                    &details
+                );
+                (matched, details)
+            }
+            Check::BalanceForeignTarget(bft) if bft.is_wrapped_nam() => {
+                let expected_pre_balance = state.get_foreign_balance_for(bft.target());
+                let matched = bft.pre_balance() == expected_pre_balance;
+                let details = json!({
+                    "target": bft.target(),
+                    "denom": bft.denom(),
+                    "expected_pre_balance": expected_pre_balance,
+                    "actual_pre_balance": bft.pre_balance(),
+                });
+                antithesis_sdk::assert_always_or_unreachable!(
+                    matched,
+                    "Foreign target pre balance matched",
+                    &details
+                );
+                (matched, details)
+            }
+            Check::BalanceForeignTarget(bft) if bft.trace().is_some() => {
+                let trace = bft.trace().expect("Trace should exist");
+                let expected_pre_balance = state.get_ibc_balance_for(bft.target(), trace);
+                let matched = bft.pre_balance() == expected_pre_balance;
+                let details = json!({
+                    "target": bft.target(),
+                    "denom": bft.denom(),
+                    "trace": trace,
+                    "expected_pre_balance": expected_pre_balance,
+                    "actual_pre_balance": bft.pre_balance(),
+                });
+                antithesis_sdk::assert_always_or_unreachable!(
+                    matched,
+                    "Foreign target pre multi-hop balance matched",
+                    &details
+                );
+                (matched, details)
+            }
+            Check::MaspConservation(mc) => {
+                let expected_pre_balance = state.get_shielded_balance_for(mc.source(), mc.denom())
+                    + state.get_shielded_balance_for(mc.target(), mc.denom());
+                let matched = mc.pre_total_balance() == Balance::from_u64(expected_pre_balance);
+                let details = json!({
+                    "source_alias": mc.source(),
+                    "target_alias": mc.target(),
+                    "denom": mc.denom(),
+                    "expected_pre_total_balance": expected_pre_balance,
+                    "actual_pre_total_balance": mc.pre_total_balance(),
+                });
+                antithesis_sdk::assert_always_or_unreachable!(
+                    matched,
+                    "Pre total shielded balance matched",
+                    &details
+                );
+                (matched, details)
+            }
+            Check::UnbondIncrease(ui) => {
+                let expected_pre_unbonds = state.get_unbonds_for(ui.target(), ui.validator());
+                let expected_total = expected_pre_unbonds.values().sum::<u64>();
+                let actual_total = unbond_increase::total_unbonds(ui.pre_unbonds());
+                let matched = actual_total == Balance::from_u64(expected_total);
+                let details = json!({
+                    "source_alias": ui.target(),
+                    "validator": ui.validator(),
+                    "expected_pre_unbonds": expected_pre_unbonds,
+                    "actual_pre_unbonds": ui.pre_unbonds(),
+                });
+                antithesis_sdk::assert_always_or_unreachable!(
+                    matched,
+                    "Pre unbonds matched",
+                    &details
+                );
+                (matched, details)
+            }
             _ => (true, json!({})),
         };
 
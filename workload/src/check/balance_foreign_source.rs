@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use namada_sdk::ibc::core::host::types::identifiers::ChannelId;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::constants::{COSMOS_FEE_AMOUNT, COSMOS_FEE_TOKEN};
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, Amount, Fee};
+use crate::utils::{get_cosmos_balance, RetryConfig};
+
+#[derive(TypedBuilder)]
+pub struct BalanceForeignSource {
+    /// Channel on Namada to the counterparty
+    channel_id: ChannelId,
+    target: Alias,
+    pre_balance: Amount,
+    /// Denom on Cosmos
+    denom: String,
+    amount: Amount,
+    /// Number of txs which the target submitted on Cosmos
+    #[builder(default = 1)]
+    num_txs: u64,
+    /// Whether the denom is NAM tracked by `foreign_balances`
+    #[builder(default)]
+    is_wrapped_nam: bool,
+}
+
+impl BalanceForeignSource {
+    pub fn target(&self) -> &Alias {
+        &self.target
+    }
+
+    pub fn pre_balance(&self) -> Amount {
+        self.pre_balance
+    }
+
+    pub fn denom(&self) -> &str {
+        &self.denom
+    }
+
+    pub fn is_wrapped_nam(&self) -> bool {
+        self.is_wrapped_nam
+    }
+}
+
+impl CheckContext for BalanceForeignSource {
+    fn summary(&self) -> String {
+        format!(
+            "balance-foreign/source/'{}'/{}",
+            self.denom, self.target.name
+        )
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let post_balance = get_cosmos_balance(
+            ctx.counterparty(&self.channel_id)?,
+            &self.target,
+            &self.denom,
+            retry_config,
+        )
+        .await?;
+
+        let fee = if self.denom == COSMOS_FEE_TOKEN {
+            COSMOS_FEE_AMOUNT * self.num_txs
+        } else {
+            0u64
+        };
+        let check_balance = self
+            .pre_balance
+            .checked_sub(self.amount + fee)
+            .ok_or_else(|| {
+                CheckError::State(format!(
+                    "BalanceForeignSource check error: {} balance is underflowing",
+                    self.target.name
+                ))
+            })?;
+
+        let details = json!({
+            "source": self.target,
+            "denom": self.denom,
+            "pre_balance": self.pre_balance,
+            "amount": self.amount,
+            "paid_fee": fee,
+            "post_balance": post_balance,
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height,
+        });
+
+        antithesis_sdk::assert_always!(
+            post_balance == check_balance,
+            "Balance foreign source decreased",
+            &details
+        );
+
+        if post_balance == check_balance {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!("BalanceForeignSource check error: post source amount is not equal to pre balance - amount - fee: {} - {} - {fee} = {check_balance} != {post_balance}", self.pre_balance, self.amount)))
+        }
+    }
+}
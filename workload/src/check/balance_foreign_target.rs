@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use namada_sdk::ibc::core::host::types::identifiers::ChannelId;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, Amount, Fee};
+use crate::utils::{get_cosmos_balance, RetryConfig};
+
+#[derive(TypedBuilder)]
+pub struct BalanceForeignTarget {
+    /// Channel on Namada to the counterparty
+    channel_id: ChannelId,
+    target: Alias,
+    pre_balance: Amount,
+    /// Denom on Cosmos
+    denom: String,
+    amount: Amount,
+    /// Whether the denom is NAM tracked by `foreign_balances`
+    #[builder(default)]
+    is_wrapped_nam: bool,
+    /// Multi-hop trace of the denom tracked by `ibc_balances`
+    #[builder(default)]
+    trace: Option<String>,
+}
+
+impl BalanceForeignTarget {
+    pub fn target(&self) -> &Alias {
+        &self.target
+    }
+
+    pub fn pre_balance(&self) -> Amount {
+        self.pre_balance
+    }
+
+    pub fn denom(&self) -> &str {
+        &self.denom
+    }
+
+    pub fn is_wrapped_nam(&self) -> bool {
+        self.is_wrapped_nam
+    }
+
+    pub fn trace(&self) -> Option<&str> {
+        self.trace.as_deref()
+    }
+}
+
+impl CheckContext for BalanceForeignTarget {
+    fn summary(&self) -> String {
+        format!(
+            "balance-foreign/target/'{}'/{}",
+            self.denom, self.target.name
+        )
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let post_balance = get_cosmos_balance(
+            ctx.counterparty(&self.channel_id)?,
+            &self.target,
+            &self.denom,
+            retry_config,
+        )
+        .await?;
+
+        let check_balance = self.pre_balance.checked_add(self.amount).ok_or_else(|| {
+            CheckError::State(format!(
+                "BalanceForeignTarget check error: {} balance is overflowing",
+                self.target.name
+            ))
+        })?;
+
+        let details = json!({
+            "target": self.target,
+            "denom": self.denom,
+            "pre_balance": self.pre_balance,
+            "amount": self.amount,
+            "post_balance": post_balance,
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height,
+        });
+
+        antithesis_sdk::assert_always!(
+            post_balance == check_balance,
+            "Balance foreign target increased",
+            &details
+        );
+
+        if post_balance == check_balance {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!("BalanceForeignTarget check error: post target amount is not equal to pre balance + amount: {} + {} = {check_balance} != {post_balance}", self.pre_balance, self.amount)))
+        }
+    }
+}
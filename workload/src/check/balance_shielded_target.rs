@@ -8,7 +8,10 @@ use crate::check::{CheckContext, CheckInfo};
 use crate::context::Ctx;
 use crate::error::CheckError;
 use crate::types::{Alias, Amount, Balance, Fee};
-use crate::utils::{get_shielded_balance, is_native_denom, shielded_sync_with_retry, RetryConfig};
+use crate::utils::{
+    get_masp_epoch_at_height, get_shielded_balance, get_shielded_reward, is_native_denom,
+    shielded_sync_with_retry, RetryConfig,
+};
 
 #[derive(TypedBuilder)]
 pub struct BalanceShieldedTarget {
@@ -34,6 +37,10 @@ impl BalanceShieldedTarget {
     pub fn amount(&self) -> Amount {
         self.amount
     }
+
+    pub fn set_amount(&mut self, amount: Amount) {
+        self.amount = amount;
+    }
 }
 
 impl CheckContext for BalanceShieldedTarget {
@@ -96,11 +103,27 @@ impl CheckContext for BalanceShieldedTarget {
                 ))
             })?;
 
+        // Shielded rewards are earned when the MASP epoch changed after the execution
+        let reward = if is_native_denom(&self.denom) {
+            let execution_epoch =
+                get_masp_epoch_at_height(ctx, check_info.execution_height, retry_config).await?;
+            get_shielded_reward(ctx, &self.denom, check_balance, execution_epoch).await?
+        } else {
+            token::Amount::zero()
+        };
+        let check_balance = check_balance.checked_add(reward).ok_or_else(|| {
+            CheckError::State(format!(
+                "BalanceShieldedTarget check error: {} balance is overflowing",
+                self.target.name
+            ))
+        })?;
+
         let details = json!({
             "target_alias": self.target,
             "pre_balance": self.pre_balance,
             "amount": self.amount,
             "paid_fee": fee,
+            "reward": reward,
             "post_balance": post_balance,
             "execution_height": check_info.execution_height,
             "check_height": check_info.check_height
@@ -116,7 +139,7 @@ impl CheckContext for BalanceShieldedTarget {
             Ok(())
         } else {
             tracing::error!("{}", details);
-            Err(CheckError::State(format!("BalanceShieldedTarget check error: post target amount is not equal to pre balance + amount - fee: {} + {} - {fee} = {check_balance} != {post_balance}", self.pre_balance, self.amount)))
+            Err(CheckError::State(format!("BalanceShieldedTarget check error: post target amount is not equal to pre balance + amount - fee + reward: {} + {} - {fee} + {reward} = {check_balance} != {post_balance}", self.pre_balance, self.amount)))
         }
     }
 }
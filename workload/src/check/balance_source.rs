@@ -16,6 +16,9 @@ pub struct BalanceSource {
     pre_balance: Balance,
     denom: String,
     amount: Amount,
+    /// Continuous PGF payment made after the pre balance was taken
+    #[builder(default)]
+    pgf_payment: Amount,
 }
 
 impl BalanceSource {
@@ -34,6 +37,14 @@ impl BalanceSource {
     pub fn amount(&self) -> Amount {
         self.amount
     }
+
+    pub fn set_amount(&mut self, amount: Amount) {
+        self.amount = amount;
+    }
+
+    pub fn set_pgf_payment(&mut self, pgf_payment: Amount) {
+        self.pgf_payment = pgf_payment;
+    }
 }
 
 impl CheckContext for BalanceSource {
@@ -58,7 +69,8 @@ impl CheckContext for BalanceSource {
         };
         let check_balance = self
             .pre_balance
-            .checked_sub(token::Amount::from_u64(self.amount + fee))
+            .checked_add(token::Amount::from_u64(self.pgf_payment))
+            .and_then(|b| b.checked_sub(token::Amount::from_u64(self.amount + fee)))
             .ok_or_else(|| {
                 CheckError::State(format!(
                     "BalanceSource check error: {} balance is underflowing",
@@ -72,6 +84,7 @@ impl CheckContext for BalanceSource {
             "pre_balance": self.pre_balance,
             "amount": self.amount,
             "paid_fee": fee,
+            "pgf_payment": self.pgf_payment,
             "post_balance": post_balance,
             "execution_height": check_info.execution_height,
             "check_height": check_info.check_height,
@@ -87,7 +100,7 @@ impl CheckContext for BalanceSource {
             Ok(())
         } else {
             tracing::error!("{}", details);
-            Err(CheckError::State(format!("BalanceSource check error: post source amount is not equal to pre balance - amount - fee: {} - {} - {fee} = {check_balance} != {post_balance}", self.pre_balance, self.amount)))
+            Err(CheckError::State(format!("BalanceSource check error: post source amount is not equal to pre balance + PGF payment - amount - fee: {} + {} - {} - {fee} = {check_balance} != {post_balance}", self.pre_balance, self.pgf_payment, self.amount)))
         }
     }
 }
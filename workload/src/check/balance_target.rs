@@ -8,7 +8,7 @@ use crate::check::{CheckContext, CheckInfo};
 use crate::context::Ctx;
 use crate::error::CheckError;
 use crate::types::{Alias, Amount, Balance, Fee};
-use crate::utils::{get_balance, RetryConfig};
+use crate::utils::{get_balance, is_native_denom, RetryConfig};
 
 #[derive(TypedBuilder)]
 pub struct BalanceTarget {
@@ -18,6 +18,9 @@ pub struct BalanceTarget {
     amount: Amount,
     #[builder(default)]
     allow_greater: bool,
+    /// Continuous PGF payment made after the pre balance was taken
+    #[builder(default)]
+    pgf_payment: Amount,
 }
 
 impl BalanceTarget {
@@ -36,6 +39,14 @@ impl BalanceTarget {
     pub fn amount(&self) -> Amount {
         self.amount
     }
+
+    pub fn set_amount(&mut self, amount: Amount) {
+        self.amount = amount;
+    }
+
+    pub fn set_pgf_payment(&mut self, pgf_payment: Amount) {
+        self.pgf_payment = pgf_payment;
+    }
 }
 
 impl CheckContext for BalanceTarget {
@@ -53,11 +64,15 @@ impl CheckContext for BalanceTarget {
         let (target_address, post_balance) =
             get_balance(ctx, &self.target, &self.denom, retry_config).await?;
 
-        let fee = fees.get(&self.target).cloned().unwrap_or_default();
+        let fee = if is_native_denom(&self.denom) {
+            fees.get(&self.target).cloned().unwrap_or_default()
+        } else {
+            0u64
+        };
 
         let check_balance = self
             .pre_balance
-            .checked_add(token::Amount::from_u64(self.amount))
+            .checked_add(token::Amount::from_u64(self.amount + self.pgf_payment))
             .and_then(|b| b.checked_sub(token::Amount::from_u64(fee)))
             .ok_or_else(|| {
                 CheckError::State(format!(
@@ -74,6 +89,7 @@ impl CheckContext for BalanceTarget {
             "pre_balance": self.pre_balance,
             "amount": self.amount,
             "paid_fee": fee,
+            "pgf_payment": self.pgf_payment,
             "allow_greater": self.allow_greater,
             "post_balance": post_balance,
             "execution_height": check_info.execution_height,
@@ -86,7 +102,7 @@ impl CheckContext for BalanceTarget {
             Ok(())
         } else {
             tracing::error!("{}", details);
-            Err(CheckError::State(format!("BalanceTarget check error: post target amount is not equal to pre balance + amount: {} + {} - {fee} = {check_balance} != {post_balance}", self.pre_balance, self.amount)))
+            Err(CheckError::State(format!("BalanceTarget check error: post target amount is not equal to pre balance + amount + PGF payment - fee: {} + {} + {} - {fee} = {check_balance} != {post_balance}", self.pre_balance, self.amount, self.pgf_payment)))
         }
     }
 }
@@ -9,7 +9,7 @@ use crate::constants::PIPELINE_LEN;
 use crate::context::Ctx;
 use crate::error::CheckError;
 use crate::types::{Alias, Amount, Balance, Epoch, Fee, ValidatorAddress};
-use crate::utils::{get_bond, get_epoch, RetryConfig};
+use crate::utils::{get_bond, get_epoch, get_slashed_amount_since, RetryConfig};
 
 #[derive(TypedBuilder)]
 pub struct BondIncrease {
@@ -62,23 +62,33 @@ impl CheckContext for BondIncrease {
                 ))
             })?;
 
+        // the bond could be reduced by the slashes processed after the pre bond query
+        let slashed =
+            get_slashed_amount_since(ctx, &self.target, &self.validator, self.epoch, retry_config)
+                .await?;
+        let expected_bond = check_bond.checked_sub(slashed.bond).unwrap_or_default();
+        let is_expected = slashed.is_within_tolerance(post_bond, expected_bond);
+
         let details = json!({
             "target_alias": self.target,
             "validator": self.validator,
             "pre_bond": self.pre_bond,
             "amount": self.amount,
             "post_bond": post_bond,
+            "slashed_bond": slashed.bond,
+            "expected_bond": expected_bond,
+            "tolerance": slashed.tolerance,
             "execution_height": check_info.execution_height,
             "check_height": check_info.check_height,
         });
 
-        antithesis_sdk::assert_always!(post_bond.eq(&check_bond), "Bond increased", &details);
+        antithesis_sdk::assert_always!(is_expected, "Bond increased", &details);
 
-        if post_bond.eq(&check_bond) {
+        if is_expected {
             Ok(())
         } else {
             tracing::error!("{}", details);
-            Err(CheckError::State(format!("BondIncrease check error: post bond amount is not equal to pre bond + amount: {} + {} = {check_bond} != {post_bond}", self.pre_bond, self.amount)))
+            Err(CheckError::State(format!("BondIncrease check error: post bond amount is not equal to pre bond + amount - slashed: {} + {} - {} = {expected_bond} != {post_bond}", self.pre_bond, self.amount, slashed.bond)))
         }
     }
 }
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use namada_sdk::ibc::core::host::types::identifiers::ChannelId;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, Fee};
+use crate::utils::{get_cosmos_nft_owner, RetryConfig};
+
+#[derive(TypedBuilder)]
+pub struct ForeignNftOwner {
+    /// Channel on Namada to the counterparty
+    channel_id: ChannelId,
+    owner: Alias,
+    class_id: String,
+    token_id: String,
+    is_owner: bool,
+}
+
+impl CheckContext for ForeignNftOwner {
+    fn summary(&self) -> String {
+        format!(
+            "foreign-nft-owner/{}/{}/{}/{}",
+            self.owner.name,
+            self.class_id,
+            self.token_id,
+            if self.is_owner { "owner" } else { "non-owner" }
+        )
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let owner = get_cosmos_nft_owner(
+            ctx.counterparty(&self.channel_id)?,
+            &self.class_id,
+            &self.token_id,
+            retry_config,
+        )
+        .await?;
+        let is_owner = owner.as_deref() == Some(self.owner.name.as_str());
+
+        let is_expected = is_owner == self.is_owner;
+        let details = json!({
+            "owner": self.owner,
+            "class_id": self.class_id,
+            "token_id": self.token_id,
+            "actual_owner": owner,
+            "expected_ownership": self.is_owner,
+            "ownership": is_owner,
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height
+        });
+
+        antithesis_sdk::assert_always!(
+            is_expected,
+            "NFT ownership on Cosmos is as expected",
+            &details
+        );
+
+        if is_expected {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!(
+                "ForeignNftOwner check error: {} ownership of {}/{} should be {}, but {is_owner}",
+                self.owner.name, self.class_id, self.token_id, self.is_owner
+            )))
+        }
+    }
+}
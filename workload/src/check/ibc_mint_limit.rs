@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, Balance, Fee};
+use crate::utils::{get_ibc_mint_amount, RetryConfig};
+
+#[derive(TypedBuilder)]
+pub struct IbcMintLimit {
+    denom: String,
+    mint_limit: Balance,
+}
+
+impl CheckContext for IbcMintLimit {
+    fn summary(&self) -> String {
+        format!("ibc-mint-limit/'{}'", self.denom)
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let minted = get_ibc_mint_amount(ctx, &self.denom, retry_config).await?;
+
+        let details = json!({
+            "denom": self.denom,
+            "mint_limit": self.mint_limit,
+            "minted": minted,
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height
+        });
+
+        antithesis_sdk::assert_always!(
+            minted <= self.mint_limit,
+            "IBC token isn't minted beyond the limit",
+            &details
+        );
+
+        if minted <= self.mint_limit {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!(
+                "IbcMintLimit check error: {} minted {minted} exceeds the limit {}",
+                self.denom, self.mint_limit
+            )))
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use namada_sdk::token;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, Balance, Fee};
+use crate::utils::{
+    get_masp_epoch_at_height, get_shielded_balance, get_shielded_reward, is_native_denom,
+    shielded_sync_with_retry, RetryConfig,
+};
+
+/// The total shielded balance of the source and the target is conserved
+/// after the tokens went out of the MASP and came back
+#[derive(TypedBuilder)]
+pub struct MaspConservation {
+    source: Alias,
+    target: Alias,
+    denom: String,
+    pre_source_balance: Balance,
+    pre_target_balance: Balance,
+}
+
+impl MaspConservation {
+    pub fn source(&self) -> &Alias {
+        &self.source
+    }
+
+    pub fn target(&self) -> &Alias {
+        &self.target
+    }
+
+    pub fn denom(&self) -> &str {
+        &self.denom
+    }
+
+    pub fn pre_total_balance(&self) -> Balance {
+        self.pre_source_balance
+            .checked_add(self.pre_target_balance)
+            .expect("Balance shouldn't overflow")
+    }
+}
+
+impl CheckContext for MaspConservation {
+    fn summary(&self) -> String {
+        format!(
+            "masp-conservation/'{}'/{}/{}",
+            self.denom, self.source.name, self.target.name
+        )
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let mut post_balances = vec![];
+        for alias in [&self.source, &self.target] {
+            shielded_sync_with_retry(
+                ctx,
+                alias,
+                Some(check_info.execution_height),
+                true,
+                retry_config,
+            )
+            .await?;
+            let balance = get_shielded_balance(ctx, alias, &self.denom, retry_config)
+                .await?
+                .unwrap_or_default();
+            post_balances.push(balance);
+        }
+        let post_total_balance =
+            post_balances[0]
+                .checked_add(post_balances[1])
+                .ok_or_else(|| {
+                    CheckError::State("MaspConservation check error: balance is overflowing".into())
+                })?;
+
+        let fee = if is_native_denom(&self.denom) {
+            fees.get(&self.source.spending_key())
+                .cloned()
+                .unwrap_or_default()
+        } else {
+            0u64
+        };
+        let check_balance = self
+            .pre_total_balance()
+            .checked_sub(token::Amount::from_u64(fee))
+            .ok_or_else(|| {
+                CheckError::State(format!(
+                    "MaspConservation check error: {} balance is underflowing",
+                    self.source.name
+                ))
+            })?;
+
+        // Shielded rewards are earned when the MASP epoch changed after the execution
+        let reward = if is_native_denom(&self.denom) {
+            let execution_epoch =
+                get_masp_epoch_at_height(ctx, check_info.execution_height, retry_config).await?;
+            get_shielded_reward(ctx, &self.denom, check_balance, execution_epoch).await?
+        } else {
+            token::Amount::zero()
+        };
+        let check_balance = check_balance.checked_add(reward).ok_or_else(|| {
+            CheckError::State(format!(
+                "MaspConservation check error: {} balance is overflowing",
+                self.source.name
+            ))
+        })?;
+
+        let details = json!({
+            "source_alias": self.source,
+            "target_alias": self.target,
+            "denom": self.denom,
+            "pre_source_balance": self.pre_source_balance,
+            "pre_target_balance": self.pre_target_balance,
+            "post_source_balance": post_balances[0],
+            "post_target_balance": post_balances[1],
+            "paid_fee": fee,
+            "reward": reward,
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height
+        });
+
+        antithesis_sdk::assert_always!(
+            post_total_balance.eq(&check_balance),
+            "Shielded balances conserved over IBC round trip",
+            &details
+        );
+
+        if post_total_balance.eq(&check_balance) {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!("MaspConservation check error: post total amount is not equal to pre total balance - fee + reward: {} - {fee} + {reward} = {check_balance} != {post_total_balance}", self.pre_total_balance())))
+        }
+    }
+}
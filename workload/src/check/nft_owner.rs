@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, Fee};
+use crate::utils::{get_balance, RetryConfig};
+
+#[derive(TypedBuilder)]
+pub struct NftOwner {
+    owner: Alias,
+    trace: String,
+    is_owner: bool,
+}
+
+impl CheckContext for NftOwner {
+    fn summary(&self) -> String {
+        format!(
+            "nft-owner/{}/{}/{}",
+            self.owner.name,
+            self.trace,
+            if self.is_owner { "owner" } else { "non-owner" }
+        )
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let (owner_address, balance) =
+            get_balance(ctx, &self.owner, &self.trace, retry_config).await?;
+        let is_owner = !balance.is_zero();
+
+        let is_expected = is_owner == self.is_owner;
+        let details = json!({
+            "owner_alias": self.owner,
+            "owner": owner_address.to_pretty_string(),
+            "trace": self.trace,
+            "expected_ownership": self.is_owner,
+            "ownership": is_owner,
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height
+        });
+
+        antithesis_sdk::assert_always!(is_expected, "NFT ownership is as expected", &details);
+
+        if is_expected {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!(
+                "NftOwner check error: {} ownership of {} should be {}, but {is_owner}",
+                self.owner.name, self.trace, self.is_owner
+            )))
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, Fee, ProposalId};
+use crate::utils::{has_proposal_code, has_storage_key, RetryConfig};
+
+#[derive(TypedBuilder)]
+pub struct ProposalCode {
+    proposal_id: ProposalId,
+    /// Storage key written by the proposal code
+    key: String,
+    is_passed: bool,
+}
+
+impl CheckContext for ProposalCode {
+    fn summary(&self) -> String {
+        format!("proposal-code/{}", self.proposal_id)
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let has_code = has_proposal_code(ctx, self.proposal_id, retry_config).await?;
+        // the code is executed and writes the key only when the proposal passed
+        let is_written = has_storage_key(ctx, &self.key, retry_config).await?;
+        let is_expected = has_code && is_written == self.is_passed;
+
+        let details = json!({
+            "proposal_id": self.proposal_id,
+            "key": self.key,
+            "is_passed": self.is_passed,
+            "has_code": has_code,
+            "is_written": is_written,
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height
+        });
+
+        antithesis_sdk::assert_always!(has_code, "Proposal wasm code was stored", &details);
+        antithesis_sdk::assert_always!(
+            is_written == self.is_passed,
+            "Proposal wasm code was executed only when passed",
+            &details
+        );
+        antithesis_sdk::assert_sometimes!(is_written, "Proposal wasm code was executed", &details);
+
+        if is_expected {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!(
+                "ProposalCode check error: proposal {} code exists: {has_code}, key {} is written: {is_written}, passed: {}",
+                self.proposal_id, self.key, self.is_passed
+            )))
+        }
+    }
+}
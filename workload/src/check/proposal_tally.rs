@@ -0,0 +1,141 @@
+use std::collections::{BTreeMap, HashMap};
+
+use namada_sdk::governance::utils::TallyResult;
+use namada_sdk::token;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::state::VoteRecord;
+use crate::types::{Alias, Epoch, Fee, ProposalId};
+use crate::utils::{get_proposal_result, get_proposal_votes, RetryConfig};
+
+#[derive(TypedBuilder)]
+pub struct ProposalTally {
+    proposal_id: ProposalId,
+    end_epoch: Epoch,
+    votes: BTreeMap<Alias, VoteRecord>,
+}
+
+impl CheckContext for ProposalTally {
+    fn summary(&self) -> String {
+        format!("proposal-tally/{}", self.proposal_id)
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        if self.votes.values().any(|record| !record.is_stake_known) {
+            tracing::info!(
+                "Skip the tally check for proposal {}: the stake of a voter is unpredictable",
+                self.proposal_id
+            );
+            return Ok(());
+        }
+
+        let wallet = ctx.namada.wallet.read().await;
+        let voters = self
+            .votes
+            .keys()
+            .filter_map(|alias| {
+                wallet
+                    .find_address(&alias.name)
+                    .map(|address| (address.into_owned(), alias.clone()))
+            })
+            .collect::<HashMap<_, _>>();
+        drop(wallet);
+
+        let onchain_votes = get_proposal_votes(ctx, self.proposal_id, retry_config).await?;
+        // the tally can be predicted only when all voters are under the workload control
+        if onchain_votes
+            .iter()
+            .any(|(_, delegator, _)| !voters.contains_key(delegator))
+        {
+            tracing::info!(
+                "Skip the tally check for proposal {}: other voters exist",
+                self.proposal_id
+            );
+            return Ok(());
+        }
+
+        // the voting power is the bonded stake at the end epoch recorded at the vote
+        let mut yay_power = token::Amount::zero();
+        let mut nay_power = token::Amount::zero();
+        let mut abstain_power = token::Amount::zero();
+        for (validator, delegator, _) in &onchain_votes {
+            let voter = voters.get(delegator).expect("Voter should exist");
+            let VoteRecord { vote, stakes, .. } = self.votes.get(voter).expect("Vote should exist");
+            let stake = token::Amount::from_u64(
+                stakes
+                    .get(&validator.to_string())
+                    .copied()
+                    .unwrap_or_default(),
+            );
+
+            if vote.is_yay() {
+                yay_power += stake;
+            } else if vote.is_nay() {
+                nay_power += stake;
+            } else {
+                abstain_power += stake;
+            }
+        }
+
+        let result = get_proposal_result(ctx, self.proposal_id, retry_config)
+            .await?
+            .ok_or_else(|| {
+                CheckError::State(format!(
+                    "ProposalTally check error: proposal {} result doesn't exist",
+                    self.proposal_id
+                ))
+            })?;
+        let predicted = TallyResult::new(
+            &result.tally_type,
+            yay_power,
+            nay_power,
+            abstain_power,
+            result.total_voting_power,
+        )
+        .map_err(|e| CheckError::State(format!("ProposalTally check error: {e}")))?;
+
+        let is_expected = result.total_yay_power == yay_power
+            && result.total_nay_power == nay_power
+            && result.total_abstain_power == abstain_power
+            && matches!(
+                (&predicted, &result.result),
+                (TallyResult::Passed, TallyResult::Passed)
+                    | (TallyResult::Rejected, TallyResult::Rejected)
+            );
+
+        let details = json!({
+            "proposal_id": self.proposal_id,
+            "end_epoch": self.end_epoch,
+            "votes": self.votes,
+            "predicted_yay_power": yay_power,
+            "predicted_nay_power": nay_power,
+            "predicted_abstain_power": abstain_power,
+            "predicted_result": predicted.to_string(),
+            "result": result.to_string(),
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height
+        });
+
+        antithesis_sdk::assert_always!(is_expected, "Proposal tally was predicted", &details);
+
+        if is_expected {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!(
+                "ProposalTally check error: proposal {} result {result} doesn't correspond to the prediction {predicted}",
+                self.proposal_id
+            )))
+        }
+    }
+}
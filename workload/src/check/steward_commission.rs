@@ -0,0 +1,80 @@
+use std::collections::{BTreeMap, HashMap};
+
+use namada_sdk::dec::Dec;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, Fee};
+use crate::utils::{get_pgf_stewards, RetryConfig};
+
+#[derive(TypedBuilder)]
+pub struct StewardCommission {
+    steward: Alias,
+    commission: BTreeMap<Alias, Dec>,
+}
+
+impl CheckContext for StewardCommission {
+    fn summary(&self) -> String {
+        format!("steward-commission/{}", self.steward.name)
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let wallet = ctx.namada.wallet.read().await;
+        let steward_address = wallet
+            .find_address(&self.steward.name)
+            .ok_or_else(|| CheckError::State(format!("No steward address: {}", self.steward.name)))?
+            .into_owned();
+        let mut expected_commission = HashMap::new();
+        for (target, rate) in &self.commission {
+            let target_address = wallet
+                .find_address(&target.name)
+                .ok_or_else(|| CheckError::State(format!("No target address: {}", target.name)))?
+                .into_owned();
+            expected_commission.insert(target_address, *rate);
+        }
+        drop(wallet);
+
+        let stewards = get_pgf_stewards(ctx, retry_config).await?;
+        let commission = stewards.get(&steward_address);
+
+        let is_expected = commission.is_some_and(|commission| *commission == expected_commission);
+        let details = json!({
+            "steward_alias": self.steward,
+            "steward": steward_address.to_pretty_string(),
+            "expected_commission": self
+                .commission
+                .iter()
+                .map(|(target, rate)| (target.name.clone(), rate.to_string()))
+                .collect::<BTreeMap<_, _>>(),
+            "commission": commission.map(|commission| {
+                commission
+                    .iter()
+                    .map(|(target, rate)| (target.to_pretty_string(), rate.to_string()))
+                    .collect::<BTreeMap<_, _>>()
+            }),
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height
+        });
+
+        antithesis_sdk::assert_always!(is_expected, "Steward commission was updated", &details);
+
+        if is_expected {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!(
+                "StewardCommission check error: {} commission doesn't correspond to the requested one",
+                self.steward.name
+            )))
+        }
+    }
+}
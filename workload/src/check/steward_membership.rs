@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, Fee};
+use crate::utils::{is_pgf_steward, RetryConfig};
+
+#[derive(TypedBuilder)]
+pub struct StewardMembership {
+    steward: Alias,
+    is_steward: bool,
+}
+
+impl CheckContext for StewardMembership {
+    fn summary(&self) -> String {
+        format!(
+            "steward-membership/{}/{}",
+            self.steward.name,
+            if self.is_steward {
+                "member"
+            } else {
+                "non-member"
+            }
+        )
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let (steward_address, is_steward) =
+            is_pgf_steward(ctx, &self.steward, retry_config).await?;
+
+        let is_expected = is_steward == self.is_steward;
+        let details = json!({
+            "steward_alias": self.steward,
+            "steward": steward_address.to_pretty_string(),
+            "expected_membership": self.is_steward,
+            "membership": is_steward,
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height
+        });
+
+        antithesis_sdk::assert_always!(is_expected, "Steward membership is as expected", &details);
+
+        if is_expected {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!(
+                "StewardMembership check error: {} membership should be {}, but {is_steward}",
+                self.steward.name, self.is_steward
+            )))
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::{BTreeMap, HashMap};
+
+use namada_sdk::token;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::constants::WITHDRAW_EPOCH_OFFSET;
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, Amount, Balance, Epoch, Fee, ValidatorAddress};
+use crate::utils::{get_slashed_amount_since, get_unbonds, RetryConfig};
+
+#[derive(TypedBuilder)]
+pub struct UnbondIncrease {
+    target: Alias,
+    validator: ValidatorAddress,
+    pre_unbonds: BTreeMap<Epoch, Balance>,
+    withdraw_epoch: Epoch,
+    amount: Amount,
+}
+
+impl UnbondIncrease {
+    pub fn target(&self) -> &Alias {
+        &self.target
+    }
+
+    pub fn validator(&self) -> &ValidatorAddress {
+        &self.validator
+    }
+
+    pub fn pre_unbonds(&self) -> &BTreeMap<Epoch, Balance> {
+        &self.pre_unbonds
+    }
+
+    pub fn withdraw_epoch(&self) -> Epoch {
+        self.withdraw_epoch
+    }
+
+    pub fn amount(&self) -> Amount {
+        self.amount
+    }
+}
+
+pub(crate) fn total_unbonds(unbonds: &BTreeMap<Epoch, Balance>) -> Balance {
+    unbonds
+        .values()
+        .fold(token::Amount::zero(), |acc, amount| acc + *amount)
+}
+
+impl CheckContext for UnbondIncrease {
+    fn summary(&self) -> String {
+        format!("unbond/{}/{}/increase", &self.target.name, self.validator)
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let post_unbonds = get_unbonds(ctx, &self.target, &self.validator, retry_config).await?;
+
+        let pre_total = total_unbonds(&self.pre_unbonds);
+        let post_total = total_unbonds(&post_unbonds);
+        let check_total = pre_total
+            .checked_add(token::Amount::from_u64(self.amount))
+            .ok_or_else(|| {
+                CheckError::State(format!(
+                    "UnbondIncrease check error: {} unbond is overflowing",
+                    self.target.name
+                ))
+            })?;
+
+        // the unbonds could be reduced by the slashes processed after the pre unbonds query
+        let slashed = get_slashed_amount_since(
+            ctx,
+            &self.target,
+            &self.validator,
+            self.withdraw_epoch - WITHDRAW_EPOCH_OFFSET,
+            retry_config,
+        )
+        .await?;
+        let expected_total = check_total.checked_sub(slashed.unbond).unwrap_or_default();
+
+        // the unbond was requested at the epoch, but the execution could be at the next epoch
+        let has_chunk = [self.withdraw_epoch, self.withdraw_epoch + 1]
+            .iter()
+            .any(|epoch| {
+                let pre = self.pre_unbonds.get(epoch).cloned().unwrap_or_default();
+                let expected = (pre + token::Amount::from_u64(self.amount))
+                    .checked_sub(slashed.unbond)
+                    .unwrap_or_default();
+                post_unbonds
+                    .get(epoch)
+                    .is_some_and(|post| *post >= expected)
+            });
+        let is_expected = slashed.is_within_tolerance(post_total, expected_total) && has_chunk;
+
+        let details = json!({
+            "target_alias": self.target,
+            "validator": self.validator,
+            "pre_unbonds": self.pre_unbonds,
+            "amount": self.amount,
+            "withdraw_epoch": self.withdraw_epoch,
+            "post_unbonds": post_unbonds,
+            "slashed_unbonds": slashed.unbond,
+            "expected_total": expected_total,
+            "tolerance": slashed.tolerance,
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height,
+        });
+
+        antithesis_sdk::assert_always!(is_expected, "Unbond increased", &details);
+
+        if is_expected {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!("UnbondIncrease check error: post unbonds are not equal to pre unbonds + amount - slashed at withdraw epoch {}: {pre_total} + {} - {} = {expected_total} != {post_total}", self.withdraw_epoch, self.amount, slashed.unbond)))
+        }
+    }
+}
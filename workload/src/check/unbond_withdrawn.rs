@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, Epoch, Fee, ValidatorAddress};
+use crate::utils::{get_unbonds, RetryConfig};
+
+/// The unbond chunks withdrawable at the epoch are gone after the withdrawal
+#[derive(TypedBuilder)]
+pub struct UnbondWithdrawn {
+    target: Alias,
+    validator: ValidatorAddress,
+    epoch: Epoch,
+}
+
+impl CheckContext for UnbondWithdrawn {
+    fn summary(&self) -> String {
+        format!("unbond/{}/{}/withdrawn", &self.target.name, self.validator)
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let post_unbonds = get_unbonds(ctx, &self.target, &self.validator, retry_config).await?;
+
+        let remaining_epochs: Vec<Epoch> = post_unbonds
+            .keys()
+            .filter(|withdraw_epoch| **withdraw_epoch <= self.epoch)
+            .cloned()
+            .collect();
+        let is_withdrawn = remaining_epochs.is_empty();
+
+        let details = json!({
+            "target_alias": self.target,
+            "validator": self.validator,
+            "epoch": self.epoch,
+            "post_unbonds": post_unbonds,
+            "remaining_epochs": remaining_epochs,
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height,
+        });
+
+        antithesis_sdk::assert_always!(is_withdrawn, "Unbonds withdrawn", &details);
+
+        if is_withdrawn {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!(
+                "UnbondWithdrawn check error: unbonds at {remaining_epochs:?} still exist after withdrawal at epoch {}",
+                self.epoch
+            )))
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{CheckContext, CheckInfo};
+use crate::constants::PIPELINE_LEN;
+use crate::context::Ctx;
+use crate::error::CheckError;
+use crate::types::{Alias, CommissionRate, Fee};
+use crate::utils::{get_epoch, get_validator_commission, RetryConfig};
+
+#[derive(TypedBuilder)]
+pub struct ValidatorCommission {
+    target: Alias,
+    requested_rate: CommissionRate,
+    expected_rate: CommissionRate,
+}
+
+impl CheckContext for ValidatorCommission {
+    fn summary(&self) -> String {
+        format!(
+            "validator-commission/{}/{}",
+            self.target.name, self.expected_rate
+        )
+    }
+
+    async fn do_check(
+        &self,
+        ctx: &Ctx,
+        _fees: &HashMap<Alias, Fee>,
+        check_info: CheckInfo,
+        retry_config: RetryConfig,
+    ) -> Result<(), CheckError> {
+        let epoch = get_epoch(ctx, retry_config).await?;
+        let (target_address, commission) =
+            get_validator_commission(ctx, &self.target, epoch + PIPELINE_LEN, retry_config).await?;
+        let (rate, max_change) = commission.ok_or_else(|| {
+            antithesis_sdk::assert_unreachable!(
+                "OnChain validator commission doesn't exist",
+                &json!({
+                    "target_alias": self.target,
+                    "target": target_address.to_pretty_string(),
+                    "execution_height": check_info.execution_height,
+                    "check_height": check_info.check_height
+                })
+            );
+            CheckError::State(format!(
+                "ValidatorCommission check error: validator {} commission doesn't exist",
+                self.target.name
+            ))
+        })?;
+
+        let is_expected = rate == self.expected_rate;
+        let details = json!({
+            "target_alias": self.target,
+            "target": target_address.to_pretty_string(),
+            "requested_rate": self.requested_rate.to_string(),
+            "expected_rate": self.expected_rate.to_string(),
+            "post_rate": rate.to_string(),
+            "max_change": max_change.to_string(),
+            "execution_height": check_info.execution_height,
+            "check_height": check_info.check_height
+        });
+
+        antithesis_sdk::assert_always!(
+            is_expected,
+            "Validator commission correctly changed",
+            &details
+        );
+
+        if is_expected {
+            Ok(())
+        } else {
+            tracing::error!("{}", details);
+            Err(CheckError::State(format!("ValidatorCommission check error: post commission rate {rate} doesn't correspond to the expected rate {}", self.expected_rate)))
+        }
+    }
+}
@@ -51,6 +51,12 @@ impl CheckContext for ValidatorStatus {
             Status::Inactive => {
                 matches!(state, ValidatorState::Inactive)
             }
+            Status::Jailed => {
+                matches!(state, ValidatorState::Jailed)
+            }
+            Status::Unjailing => {
+                !matches!(state, ValidatorState::Inactive | ValidatorState::Jailed)
+            }
             _ => !matches!(state, ValidatorState::Inactive),
         };
         let details = json!({
@@ -12,6 +12,8 @@ pub enum Code {
     // No execution
     Skip(StepType),
     NoTask(StepType),
+    // Expected rejection
+    Rejected(StepType, TaskError),
     // Other failures
     StepFailure(StepType, StepError),
     TaskFailure(StepType, TaskError),
@@ -22,6 +24,7 @@ pub enum CodeType {
     Success,
     Fatal,
     Skip,
+    Rejected,
     Failed,
 }
 
@@ -46,6 +49,7 @@ impl Code {
             Code::ConfigFatal(_) => None,
             Code::Skip(st) => Some(st),
             Code::NoTask(st) => Some(st),
+            Code::Rejected(st, _) => Some(st),
             Code::StepFailure(st, _) => Some(st),
             Code::TaskFailure(st, _) => Some(st),
             Code::CheckFailure(st, _) => Some(st),
@@ -71,6 +75,9 @@ impl Code {
                 tracing::warn!("Invalid step for {step_type}, skipping...")
             }
             Code::NoTask(step_type) => tracing::info!("No task for {step_type}, skipping..."),
+            Code::Rejected(step_type, reason) => {
+                tracing::warn!("Expected rejection for {step_type} -> {reason}")
+            }
             Code::StateFatal(reason) => {
                 tracing::error!("State error -> {reason}")
             }
@@ -90,6 +97,7 @@ impl Code {
                 CodeType::Fatal
             }
             Code::Skip(_) | Code::NoTask(_) => CodeType::Skip,
+            Code::Rejected(_, _) => CodeType::Rejected,
             _ => CodeType::Failed,
         }
     }
@@ -103,6 +111,7 @@ impl Code {
             Code::ConfigFatal(e) => ("Fatal config failure", e.clone()),
             Code::Skip(_) => ("Skipped step", Default::default()),
             Code::NoTask(_) => ("No task", Default::default()),
+            Code::Rejected(_, e) => ("Expected rejection", e.to_string()),
             Code::StepFailure(_, e) => ("Step failure", e.to_string()),
             Code::TaskFailure(_, e) => ("Task failure", e.to_string()),
             Code::CheckFailure(_, e) => ("Check failure", e.to_string()),
@@ -1,25 +1,66 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use serde::Deserialize;
 
+use crate::constants::COSMOS_TOKEN;
 use crate::error::Error;
 use crate::step::StepType;
 
 #[derive(clap::Parser, Clone, Debug)]
+#[command(subcommand_negates_reqs = true)]
 pub struct Args {
     #[clap(long, env)]
     #[arg(required = true)]
     pub config: PathBuf,
+    #[command(subcommand)]
+    pub command: Option<Command>,
     #[arg(required = true)]
-    pub step_type: StepType,
+    pub step_type: Option<StepType>,
     #[clap(long, env)]
     #[clap(default_value_t = false)]
     pub no_check: bool,
+    /// Submit the independent tasks of a step concurrently
+    #[clap(long, env)]
+    #[clap(default_value_t = false)]
+    pub concurrent: bool,
+    /// Seed the RNG to make the random choices reproducible
+    #[clap(long, env)]
+    pub seed: Option<u64>,
     #[clap(long)]
     #[clap(default_value_t = false)]
     pub setup_complete: bool,
 }
 
+#[derive(clap::Subcommand, Clone, Debug)]
+pub enum Command {
+    /// Keep executing steps chosen from the weighted distribution
+    Run {
+        /// Weight of a step like `bond=3`, overriding the weight in the config
+        #[clap(long = "weight", value_parser = parse_step_weight)]
+        weights: Vec<(String, u64)>,
+        /// Stop after executing the number of steps
+        #[clap(long)]
+        max_steps: Option<u64>,
+    },
+    /// Re-execute the steps recorded in a step log from a fresh state
+    Replay {
+        /// Step log `steps-<id>.jsonl` written by a previous run
+        #[clap(long)]
+        log: PathBuf,
+    },
+}
+
+fn parse_step_weight(arg: &str) -> Result<(String, u64), String> {
+    let (step, weight) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("Invalid step weight, expected `<step>=<weight>`: {arg}"))?;
+    let weight = weight
+        .parse()
+        .map_err(|e| format!("Invalid weight for {step}: {e}"))?;
+    Ok((step.to_string(), weight))
+}
+
 #[derive(Debug, Deserialize)]
 pub struct AppConfig {
     pub id: u64,
@@ -27,11 +68,33 @@ pub struct AppConfig {
     pub rpc: String,
     pub masp_indexer_url: String,
     pub faucet_sk: String,
-    pub cosmos_rpc: String,
-    pub cosmos_grpc: String,
-    pub cosmos_base_dir: PathBuf,
+    pub counterparties: Vec<CounterpartyConfig>,
+    /// Weights of the steps executed by `run`
+    #[serde(default)]
+    pub step_weights: BTreeMap<String, u64>,
+}
+
+/// IBC counterparty chain connected to Namada
+#[derive(Debug, Deserialize)]
+pub struct CounterpartyConfig {
+    pub chain_id: String,
+    pub rpc: String,
+    pub grpc: String,
+    pub base_dir: PathBuf,
     pub namada_channel_id: String,
     pub cosmos_channel_id: String,
+    /// Channels of ICS-721 NFT transfer, if the counterparty supports it
+    pub namada_nft_channel_id: Option<String>,
+    pub cosmos_nft_channel_id: Option<String>,
+    /// Native tokens on the counterparty which the workload sends to Namada
+    #[serde(default = "default_cosmos_tokens")]
+    pub tokens: Vec<String>,
+    /// Token funded beyond the IBC mint limit on Namada to exceed the limit, if any
+    pub over_limit_token: Option<String>,
+}
+
+fn default_cosmos_tokens() -> Vec<String> {
+    vec![COSMOS_TOKEN.to_string()]
 }
 
 impl AppConfig {
@@ -10,9 +10,19 @@ pub const MAX_DELAY_SEC: u64 = 10;
 // For batch
 pub const MAX_BATCH_TX_NUM: u64 = 3;
 
+// For concurrent execution
+pub const MAX_CONCURRENT_TX_NUM: u64 = 5;
+
+// For state persistence
+/// The number of the previous states kept to recover a corrupted state file
+pub const STATE_JOURNAL_LEN: usize = 5;
+
 // For bonding (They depend on the Namada parameters)
 pub const PIPELINE_LEN: u64 = 2;
 pub const UNBONDING_LEN: u64 = 3;
+pub const CUBIC_SLASHING_WINDOW_LEN: u64 = 1;
+pub const WITHDRAW_EPOCH_OFFSET: u64 = PIPELINE_LEN + UNBONDING_LEN + CUBIC_SLASHING_WINDOW_LEN;
+pub const SLASH_PROCESSING_EPOCH_OFFSET: u64 = UNBONDING_LEN + 1 + CUBIC_SLASHING_WINDOW_LEN;
 
 pub const NATIVE_SCALE: u64 = namada_sdk::token::NATIVE_SCALE;
 pub const FAUCET_AMOUNT: u64 = 1_000_000 * NATIVE_SCALE;
@@ -23,7 +33,6 @@ pub const MIN_TRANSFER_BALANCE: u64 = MAX_BATCH_TX_NUM * NATIVE_SCALE + DEFAULT_
 pub const PROPOSAL_DEPOSIT: u64 = 50 * NATIVE_SCALE;
 
 // For Cosmos
-pub const COSMOS_CHAIN_ID: &str = "gaia-0";
 pub const COSMOS_TOKEN: &str = "samoleans";
 pub const COSMOS_FEE_TOKEN: &str = "stake";
 pub const MAX_COSMOS_TRANSFER_AMOUNT: u64 = 100;
@@ -32,3 +41,10 @@ pub const COSMOS_GAS_LIMIT: u64 = 200_000;
 
 // For IBC
 pub const IBC_TIMEOUT_HEIGHT_OFFSET: u64 = 20;
+/// The packet expires as soon as it is sent so that it is always timed out
+pub const IBC_EXPIRED_TIMEOUT_SEC_OFFSET: u64 = 0;
+
+// For IBC NFT (ICS-721)
+pub const NFT_PORT_ID: &str = "nft-transfer";
+/// NFT class on the counterparty which the workload can mint freely
+pub const COSMOS_NFT_CLASS: &str = "workloadnft";
@@ -1,27 +1,90 @@
 use namada_sdk::ibc::core::host::types::identifiers::ChannelId;
+use rand::seq::IteratorRandom;
 
 use crate::config::AppConfig;
+use crate::error::QueryError;
+use crate::utils::{parse_trace, WorkloadRng};
 
 mod cosmos;
 mod namada;
 
+pub use cosmos::CosmosCtx;
+
 pub struct Ctx {
     pub namada: namada::NamadaCtx,
-    pub cosmos: cosmos::CosmosCtx,
-    pub namada_channel_id: ChannelId,
-    pub cosmos_channel_id: ChannelId,
+    pub counterparties: Vec<CosmosCtx>,
     pub masp_indexer_url: String,
 }
 
 impl Ctx {
     pub async fn new(config: &AppConfig) -> Result<Self, String> {
+        let counterparties = config
+            .counterparties
+            .iter()
+            .map(|counterparty| CosmosCtx::new(config.id, counterparty))
+            .collect::<Result<Vec<_>, _>>()?;
+        if counterparties.is_empty() {
+            return Err("No IBC counterparty".to_string());
+        }
+
         Ok(Self {
             namada: namada::namada_ctx(config).await?,
-            cosmos: cosmos::CosmosCtx::new(config)?,
-            // TODO: set channels
-            namada_channel_id: config.namada_channel_id.parse().unwrap(),
-            cosmos_channel_id: config.cosmos_channel_id.parse().unwrap(),
+            counterparties,
             masp_indexer_url: format!("{}/api/v1", config.masp_indexer_url.clone()),
         })
     }
+
+    pub fn random_counterparty(&self) -> &CosmosCtx {
+        self.counterparties
+            .iter()
+            .choose(&mut WorkloadRng)
+            .expect("Counterparty should exist")
+    }
+
+    pub fn random_nft_counterparty(&self) -> Option<&CosmosCtx> {
+        self.counterparties
+            .iter()
+            .filter(|counterparty| counterparty.namada_nft_channel_id.is_some())
+            .choose(&mut WorkloadRng)
+    }
+
+    pub fn any_nft_counterparty(&self) -> bool {
+        self.counterparties
+            .iter()
+            .any(|counterparty| counterparty.namada_nft_channel_id.is_some())
+    }
+
+    pub fn random_over_limit_counterparty(&self) -> Option<&CosmosCtx> {
+        self.counterparties
+            .iter()
+            .filter(|counterparty| counterparty.over_limit_token.is_some())
+            .choose(&mut WorkloadRng)
+    }
+
+    pub fn any_over_limit_counterparty(&self) -> bool {
+        self.counterparties
+            .iter()
+            .any(|counterparty| counterparty.over_limit_token.is_some())
+    }
+
+    /// The counterparty connected through the channel on Namada
+    pub fn counterparty(&self, namada_channel_id: &ChannelId) -> Result<&CosmosCtx, QueryError> {
+        self.counterparties
+            .iter()
+            .find(|counterparty| {
+                counterparty.namada_channel_id == *namada_channel_id
+                    || counterparty.namada_nft_channel_id.as_ref() == Some(namada_channel_id)
+            })
+            .ok_or_else(|| QueryError::Ibc(format!("No counterparty of {namada_channel_id}")))
+    }
+
+    /// The counterparty to send the token to.
+    /// A token from a counterparty goes back through the channel it came from,
+    /// which is the first hop of the trace on Namada.
+    pub fn counterparty_for_denom(&self, denom: &str) -> Result<&CosmosCtx, QueryError> {
+        match parse_trace(denom).0.first() {
+            Some((_, channel_id)) => self.counterparty(channel_id),
+            None => Ok(self.random_counterparty()),
+        }
+    }
 }
@@ -5,31 +5,70 @@ use bip32::{DerivationPath, Language, Mnemonic};
 use cosmrs::crypto::secp256k1::SigningKey;
 use cosmrs::rpc::HttpClient;
 use cosmrs::AccountId;
+use namada_sdk::ibc::core::host::types::identifiers::ChannelId;
+use rand::seq::IteratorRandom;
 use serde::Deserialize;
 
-use crate::config::AppConfig;
+use crate::config::CounterpartyConfig;
+use crate::utils::WorkloadRng;
 
 pub struct CosmosCtx {
+    pub chain_id: String,
     pub client: HttpClient,
     pub grpc_endpoint: String,
     pub account: AccountId,
     pub signing_key: SigningKey,
+    pub namada_channel_id: ChannelId,
+    pub cosmos_channel_id: ChannelId,
+    pub namada_nft_channel_id: Option<ChannelId>,
+    pub cosmos_nft_channel_id: Option<ChannelId>,
+    pub tokens: Vec<String>,
+    pub over_limit_token: Option<String>,
 }
 
 impl CosmosCtx {
-    pub fn new(config: &AppConfig) -> Result<Self, String> {
-        let client = HttpClient::new(&*config.cosmos_rpc).expect("invalid RPC");
-        let wallet_path = config
-            .cosmos_base_dir
-            .join(format!("user_{}_seed.json", config.id));
+    pub fn new(id: u64, config: &CounterpartyConfig) -> Result<Self, String> {
+        let client = HttpClient::new(&*config.rpc).expect("invalid RPC");
+        let wallet_path = config.base_dir.join(format!("user_{id}_seed.json"));
         let (account, signing_key) = load_key(&wallet_path)?;
+        let parse_channel = |channel_id: &String| {
+            channel_id
+                .parse::<ChannelId>()
+                .map_err(|e| format!("Invalid channel ID of {}: {e}", config.chain_id))
+        };
+        if config.tokens.is_empty() {
+            return Err(format!("No token on {}", config.chain_id));
+        }
         Ok(Self {
+            chain_id: config.chain_id.clone(),
             client,
-            grpc_endpoint: config.cosmos_grpc.clone(),
+            grpc_endpoint: config.grpc.clone(),
             account,
             signing_key,
+            namada_channel_id: parse_channel(&config.namada_channel_id)?,
+            cosmos_channel_id: parse_channel(&config.cosmos_channel_id)?,
+            namada_nft_channel_id: config
+                .namada_nft_channel_id
+                .as_ref()
+                .map(parse_channel)
+                .transpose()?,
+            cosmos_nft_channel_id: config
+                .cosmos_nft_channel_id
+                .as_ref()
+                .map(parse_channel)
+                .transpose()?,
+            tokens: config.tokens.clone(),
+            over_limit_token: config.over_limit_token.clone(),
         })
     }
+
+    pub fn random_token(&self) -> String {
+        self.tokens
+            .iter()
+            .choose(&mut WorkloadRng)
+            .expect("Token should exist")
+            .clone()
+    }
 }
 
 #[derive(Deserialize)]
@@ -35,13 +35,24 @@ pub enum TaskError {
     #[error("Broadcasting tx failed: `{0}`")]
     Broadcast(namada_sdk::error::Error),
     #[error("Executing tx failed: `{err}`")]
-    Execution { err: String, height: Height },
+    Execution {
+        err: String,
+        height: Height,
+        /// The tx was rejected by the invalid signatures
+        is_invalid_sig: bool,
+    },
     #[error("Unexpected tx response: `{0}`")]
     TxResp(String),
+    #[error("Tx with insufficient signatures was rejected: `{err}`")]
+    InsufficientSignatures { err: String, height: Height },
     #[error("Executing tx failed due to the gas: `{err}`")]
     InsufficientGas { err: String, height: Height },
+    #[error("Shielded tx without enough shielded balance for the fee was rejected: `{err}`")]
+    InsufficientShieldedFee { err: String, height: Height },
     #[error("Shielded tx failed due to crossing the epoch boundary: `{err}`")]
     InvalidShielded { err: String, was_fee_paid: bool },
+    #[error("Task failed after the first tx was executed at {height}: `{err}`")]
+    PartialExecution { err: Box<TaskError>, height: Height },
     #[error("Query failed: `{0}`")]
     Query(QueryError),
     #[error("Cosmos tx failed: `{0}`")]
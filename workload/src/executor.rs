@@ -1,28 +1,41 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::time::Instant;
 
+use futures::future::join_all;
+use namada_sdk::governance::utils::TallyResult;
 use namada_sdk::rpc;
+use namada_sdk::token;
+use serde_json::json;
 use tokio::time::{sleep, Duration};
 
-use crate::check::{Check, CheckContext, CheckInfo};
+use crate::check::{self, Check, CheckContext, CheckInfo};
+use crate::constants::PROPOSAL_DEPOSIT;
 use crate::context::Ctx;
-use crate::error::{CheckError, StepError, TaskError};
-use crate::state::State;
+use crate::error::{CheckError, QueryError, StepError, TaskError};
+use crate::state::{PendingProposal, ProposalPayload, State};
 use crate::step::{StepContext, StepType};
 use crate::task::{Task, TaskContext};
 use crate::types::{Alias, Epoch, Fee, Height};
 use crate::utils::{
-    execute_reveal_pk, get_block_height, get_proposals, is_pk_revealed, retry_config,
+    execute_reveal_pk, find_proposal_id, get_block_height, get_bond, get_epoch, get_masp_epoch,
+    get_pgf_stewards, get_proposal_activation_epoch, get_proposal_result, get_proposals,
+    get_shielded_reward, get_slashes, get_unbonds, is_pk_revealed, retry_config,
 };
 
 pub struct WorkloadExecutor {
     ctx: Ctx,
     state: State,
+    // submit the independent tasks concurrently
+    concurrent: bool,
 }
 
 impl WorkloadExecutor {
-    pub fn new(ctx: Ctx, state: State) -> Self {
-        Self { ctx, state }
+    pub fn new(ctx: Ctx, state: State, concurrent: bool) -> Self {
+        Self {
+            ctx,
+            state,
+            concurrent,
+        }
     }
 
     pub fn ctx(&self) -> &Ctx {
@@ -33,6 +46,11 @@ impl WorkloadExecutor {
         &self.state
     }
 
+    /// Restore the state which was taken before a failed step
+    pub fn restore_state(&mut self, state: State) {
+        self.state = state;
+    }
+
     async fn fetch_epoch_at_height(&self, height: Height) -> Epoch {
         loop {
             let epoch = rpc::query_epoch_at_height(&self.ctx.namada.client, height.into()).await;
@@ -103,6 +121,273 @@ impl WorkloadExecutor {
         Ok(())
     }
 
+    /// Rescale the bonds and unbonds to the validators slashed since the last run
+    pub async fn sync_slashes(&mut self) -> Result<(), StepError> {
+        let retry_config = retry_config();
+        let epoch = get_epoch(&self.ctx, retry_config).await?;
+
+        for validator in self.state.get_bonded_validators() {
+            let slashes = get_slashes(&self.ctx, &validator, retry_config).await?;
+            let num_slashes = slashes.len() as u64;
+            if num_slashes <= self.state.get_processed_slashes(&validator) {
+                continue;
+            }
+
+            tracing::warn!("Validator {validator} has been slashed, rescaling the bonds...");
+            for source in self.state.get_delegators_of(&validator) {
+                let bond = get_bond(&self.ctx, &source, &validator, epoch, retry_config).await?;
+                let bond: u64 = bond
+                    .to_string()
+                    .parse()
+                    .expect("Amount conversion shouldn't fail");
+                let unbonds = get_unbonds(&self.ctx, &source, &validator, retry_config)
+                    .await?
+                    .into_iter()
+                    .map(|(withdraw_epoch, amount)| {
+                        let amount: u64 = amount
+                            .to_string()
+                            .parse()
+                            .expect("Amount conversion shouldn't fail");
+                        (withdraw_epoch, amount)
+                    })
+                    .collect::<BTreeMap<_, _>>();
+
+                // slashing only reduces the bond and the unbonds in the state
+                let (own_bond, redelegated_bond) = self.state.get_bond_parts(&source, &validator);
+                let pre_bond = own_bond + redelegated_bond;
+                let pre_unbonds = self.state.get_unbonds_for(&source, &validator);
+                let pre_unbond: u64 = pre_unbonds.values().sum();
+                let slashed_unbond: u64 = unbonds.values().sum();
+                let is_reduced = bond <= pre_bond && slashed_unbond <= pre_unbond;
+                let details = json!({
+                    "source_alias": source,
+                    "validator": validator,
+                    "slashes": slashes.len(),
+                    "pre_bond": pre_bond,
+                    "pre_unbonds": pre_unbonds,
+                    "slashed_bond": bond,
+                    "slashed_unbonds": unbonds,
+                });
+                antithesis_sdk::assert_always!(
+                    is_reduced,
+                    "Bonds were reduced by slashing",
+                    &details
+                );
+                antithesis_sdk::assert_sometimes!(
+                    bond < pre_bond || slashed_unbond < pre_unbond,
+                    "Bonds were rescaled by slashing",
+                    &details
+                );
+                if !is_reduced {
+                    tracing::error!("Slashing increased the bonds: {details}");
+                }
+
+                self.state
+                    .modify_slashed_bond(&source, &validator, bond, unbonds);
+            }
+            self.state.set_processed_slashes(&validator, num_slashes);
+        }
+
+        Ok(())
+    }
+
+    pub async fn sync_stewards(&mut self) -> Result<(), StepError> {
+        let stewards = get_pgf_stewards(&self.ctx, retry_config()).await?;
+
+        let wallet = self.ctx.namada.wallet.read().await;
+        let workload_stewards = self
+            .state
+            .accounts
+            .keys()
+            .filter(|alias| {
+                wallet
+                    .find_address(&alias.name)
+                    .is_some_and(|address| stewards.contains_key(&address))
+            })
+            .cloned()
+            .collect();
+        drop(wallet);
+
+        self.state.set_stewards(workload_stewards);
+
+        Ok(())
+    }
+
+    /// Accrue the shielded rewards of the MASP accounts since the last run
+    pub async fn sync_masp_rewards(&mut self) -> Result<(), StepError> {
+        let masp_epoch = get_masp_epoch(&self.ctx, retry_config()).await?;
+        let native_denom = Alias::nam().name;
+
+        for (alias, balances) in self.state.masp_balances.clone() {
+            let Some(synced_epoch) = self.state.get_masp_epoch(&alias) else {
+                self.state.set_masp_epoch(&alias, masp_epoch);
+                continue;
+            };
+            if synced_epoch >= masp_epoch {
+                continue;
+            }
+
+            let mut reward = token::Amount::zero();
+            for (denom, amount) in &balances {
+                let amount = token::Amount::from_u64(*amount);
+                let denom_reward =
+                    get_shielded_reward(&self.ctx, denom, amount, synced_epoch).await?;
+                reward = reward.checked_add(denom_reward).ok_or_else(|| {
+                    QueryError::Convert("Shielded reward is overflowing".to_string())
+                })?;
+            }
+            let reward: u64 = reward
+                .to_string()
+                .parse()
+                .map_err(|e: std::num::ParseIntError| QueryError::Convert(e.to_string()))?;
+
+            if reward > 0 {
+                antithesis_sdk::assert_sometimes!(
+                    true,
+                    "Shielded rewards were accrued",
+                    &json!({
+                        "alias": alias,
+                        "balances": balances,
+                        "from_masp_epoch": synced_epoch.to_string(),
+                        "to_masp_epoch": masp_epoch.to_string(),
+                        "reward": reward,
+                    })
+                );
+                self.state
+                    .increase_masp_balance(&alias, &native_denom, reward);
+            }
+            self.state.set_masp_epoch(&alias, masp_epoch);
+        }
+
+        Ok(())
+    }
+
+    /// Check the execution of the workload proposals after their activation
+    pub async fn check_activated_proposals(&mut self) -> Result<(), CheckError> {
+        let retry_config = retry_config();
+        let epoch = get_epoch(&self.ctx, retry_config).await?;
+        let height = get_block_height(&self.ctx, retry_config)
+            .await
+            .unwrap_or_default();
+
+        let mut checks = vec![];
+        for (proposal_id, proposal) in self.state.get_activated_proposals(epoch) {
+            let Some(result) = get_proposal_result(&self.ctx, proposal_id, retry_config).await?
+            else {
+                continue;
+            };
+            let is_passed = matches!(result.result, TallyResult::Passed);
+            // the deposit is refunded only when the proposal passed
+            if is_passed {
+                self.state
+                    .increase_balance(&proposal.author, &Alias::nam().name, PROPOSAL_DEPOSIT);
+            }
+
+            let check = match &proposal.payload {
+                ProposalPayload::Default => None,
+                ProposalPayload::PgfFunding {
+                    target,
+                    amount,
+                    is_continuous,
+                } => {
+                    let pre_balance = self.state.get_balance_for(target, &Alias::nam().name);
+                    // the continuous funding is expected as the PGF payments
+                    // made since the activation epoch
+                    let amount = if is_passed && !is_continuous {
+                        *amount
+                    } else {
+                        0
+                    };
+                    Some(Check::BalanceTarget(
+                        check::balance_target::BalanceTarget::builder()
+                            .target(target.clone())
+                            .pre_balance(token::Amount::from_u64(pre_balance))
+                            .denom(Alias::nam().name)
+                            .amount(amount)
+                            .build(),
+                    ))
+                }
+                ProposalPayload::PgfSteward { steward, is_add } => Some(Check::StewardMembership(
+                    check::steward_membership::StewardMembership::builder()
+                        .steward(steward.clone())
+                        .is_steward(if is_passed { *is_add } else { !is_add })
+                        .build(),
+                )),
+                ProposalPayload::Wasm { key } => Some(Check::ProposalCode(
+                    check::proposal_code::ProposalCode::builder()
+                        .proposal_id(proposal_id)
+                        .key(key.clone())
+                        .is_passed(is_passed)
+                        .build(),
+                )),
+            };
+
+            checks.extend(check);
+
+            if let ProposalPayload::PgfFunding {
+                target,
+                amount,
+                is_continuous,
+            } = &proposal.payload
+            {
+                if is_passed && *is_continuous {
+                    self.state.add_pgf_funding(
+                        proposal_id,
+                        target,
+                        *amount,
+                        proposal.activation_epoch,
+                    );
+                } else if is_passed {
+                    self.state
+                        .increase_balance(target, &Alias::nam().name, *amount);
+                }
+            }
+            self.state.remove_pending_proposal(proposal_id);
+        }
+
+        for (proposal_id, votes) in self.state.votes.clone() {
+            // the votes for the unknown proposal can't be tallied
+            let Some(activation_epoch) =
+                get_proposal_activation_epoch(&self.ctx, proposal_id, retry_config).await?
+            else {
+                tracing::warn!("Proposal {proposal_id} doesn't exist on chain, dropping the votes");
+                self.state.remove_votes(proposal_id);
+                continue;
+            };
+            // the proposal is executed at the beginning of the activation epoch
+            if epoch < activation_epoch {
+                continue;
+            }
+            let Some((_, end_epoch)) = self.state.proposals.get(&proposal_id).cloned() else {
+                tracing::warn!("Proposal {proposal_id} isn't in the state, dropping the votes");
+                self.state.remove_votes(proposal_id);
+                continue;
+            };
+
+            checks.push(Check::ProposalTally(
+                check::proposal_tally::ProposalTally::builder()
+                    .proposal_id(proposal_id)
+                    .end_epoch(end_epoch)
+                    .votes(votes)
+                    .build(),
+            ));
+            self.state.remove_votes(proposal_id);
+        }
+
+        self.checks(vec![checks], &[height], &HashMap::default())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Credit the continuous PGF payments made until the current epoch
+    pub async fn sync_pgf_payments(&mut self) -> Result<(), StepError> {
+        let epoch = get_epoch(&self.ctx, retry_config()).await?;
+        self.state.credit_pgf_payments(epoch);
+
+        Ok(())
+    }
+
     pub async fn is_valid(&self, step_type: &StepType) -> Result<bool, StepError> {
         step_type.is_valid(&self.ctx, &self.state).await
     }
@@ -111,7 +396,8 @@ impl WorkloadExecutor {
         step_type.build_task(&self.ctx, &self.state).await
     }
 
-    pub async fn build_check(&self, tasks: &[Task]) -> Result<Vec<Check>, TaskError> {
+    /// Build the checks for each task
+    pub async fn build_check(&self, tasks: &[Task]) -> Result<Vec<Vec<Check>>, TaskError> {
         let retry_config = retry_config();
         let mut checks = vec![];
         for task in tasks {
@@ -119,70 +405,77 @@ impl WorkloadExecutor {
             built_checks
                 .iter()
                 .for_each(|check| check.assert_pre_balance(&self.state));
-            checks.extend(built_checks)
+            checks.push(built_checks)
         }
         Ok(checks)
     }
 
+    /// Run the checks of each task with the execution height of the task
     pub async fn checks(
         &self,
-        checks: Vec<Check>,
-        execution_height: Height,
+        checks: Vec<Vec<Check>>,
+        execution_heights: &[Height],
         fees: &HashMap<Alias, Fee>,
     ) -> Result<(), CheckError> {
         let retry_config = retry_config();
 
-        if checks.is_empty() {
+        if checks.iter().all(|task_checks| task_checks.is_empty()) {
             return Ok(());
         }
 
         let check_height = get_block_height(&self.ctx, retry_config)
             .await
             .unwrap_or_default();
-        for check in checks {
-            tracing::info!("Running {check} check...");
-            check
-                .do_check(
-                    &self.ctx,
-                    fees,
-                    CheckInfo {
-                        execution_height,
-                        check_height,
-                    },
-                    retry_config,
-                )
-                .await?;
+        // the continuous PGF payments could be made after the pre balances
+        let epoch = get_epoch(&self.ctx, retry_config).await?;
+        let pgf_payments = self.state.get_pgf_payments(epoch);
+        for (task_checks, execution_height) in checks.into_iter().zip(execution_heights) {
+            for mut check in task_checks {
+                check.add_pgf_payments(&pgf_payments);
+                tracing::info!("Running {check} check...");
+                check
+                    .do_check(
+                        &self.ctx,
+                        fees,
+                        CheckInfo {
+                            execution_height: *execution_height,
+                            check_height,
+                        },
+                        retry_config,
+                    )
+                    .await?;
+            }
         }
 
         Ok(())
     }
 
+    /// Execute the tasks and return the result of each executed task. The
+    /// tasks after a failure aren't executed and have no result.
+    /// In the concurrent mode, the tasks with disjoint signers and gas payers
+    /// are submitted at the same time.
     pub async fn execute(
         &self,
         tasks: &[Task],
-    ) -> (Result<Height, TaskError>, HashMap<Alias, Fee>) {
+    ) -> (Vec<Result<Height, TaskError>>, HashMap<Alias, Fee>) {
+        if self.concurrent {
+            return self.execute_concurrently(tasks).await;
+        }
+
         let mut fees = HashMap::new();
-        let mut execution_height = 0;
+        let mut results = Vec::with_capacity(tasks.len());
 
         // Execute transactions sequentially.
         // But other workloads could execute transactions at the same block.
         for task in tasks {
             tracing::info!("Executing {task}...");
             let now = Instant::now();
-            execution_height = match task.execute(&self.ctx).await {
-                Ok(height) => height,
+            match task.execute(&self.ctx).await {
+                Ok(height) => results.push(Ok(height)),
                 Err(e) => {
-                    match e {
-                        // aggreate fees when the tx has been executed
-                        TaskError::Execution { .. } | TaskError::IbcTransfer(_) => {
-                            task.aggregate_fees(&mut fees, false)
-                        }
-                        TaskError::InvalidShielded { was_fee_paid, .. } if was_fee_paid => {
-                            task.aggregate_fees(&mut fees, false)
-                        }
-                        _ => {}
-                    }
-                    return (Err(e), fees);
+                    Self::aggregate_failed_fees(task, &e, &mut fees);
+                    results.push(Err(e));
+                    return (results, fees);
                 }
             };
             tracing::info!("Execution took {}s...", now.elapsed().as_secs());
@@ -190,15 +483,73 @@ impl WorkloadExecutor {
             task.aggregate_fees(&mut fees, true);
         }
 
-        (Ok(execution_height), fees)
+        (results, fees)
+    }
+
+    async fn execute_concurrently(
+        &self,
+        tasks: &[Task],
+    ) -> (Vec<Result<Height, TaskError>>, HashMap<Alias, Fee>) {
+        let mut fees = HashMap::new();
+        let mut task_results = Vec::with_capacity(tasks.len());
+
+        // the groups are consecutive, so the results are in the order of the tasks
+        for group in independent_task_groups(tasks) {
+            tracing::info!("Executing {} tasks concurrently...", group.len());
+            let now = Instant::now();
+            let results = join_all(group.iter().map(|i| tasks[*i].execute(&self.ctx))).await;
+            tracing::info!("Execution took {}s...", now.elapsed().as_secs());
+
+            let mut is_failed = false;
+            for (i, result) in group.into_iter().zip(results) {
+                let task = &tasks[i];
+                match &result {
+                    Ok(_) => task.aggregate_fees(&mut fees, true),
+                    Err(e) => {
+                        tracing::warn!("Task {task} failed: {e}");
+                        Self::aggregate_failed_fees(task, e, &mut fees);
+                        is_failed = true;
+                    }
+                }
+                task_results.push(result);
+            }
+            if is_failed {
+                break;
+            }
+        }
+
+        (task_results, fees)
+    }
+
+    fn aggregate_failed_fees(task: &Task, error: &TaskError, fees: &mut HashMap<Alias, Fee>) {
+        match error {
+            // aggreate fees when the tx has been executed
+            TaskError::Execution { .. }
+            | TaskError::InsufficientSignatures { .. }
+            | TaskError::PartialExecution { .. }
+            | TaskError::IbcTransfer(_) => task.aggregate_fees(fees, false),
+            TaskError::InvalidShielded { was_fee_paid, .. } if *was_fee_paid => {
+                task.aggregate_fees(fees, false)
+            }
+            _ => {}
+        }
+    }
+
+    /// Update the state for the txs executed before the tasks failed
+    pub fn post_execute_failures(&mut self, tasks: &[Task], results: &[Result<Height, TaskError>]) {
+        for (task, result) in tasks.iter().zip(results) {
+            if let Err(e) = result {
+                task.update_failed_state(e, &mut self.state);
+            }
+        }
     }
 
     pub async fn post_execute(
         &mut self,
         tasks: &[Task],
-        execution_height: Height,
+        execution_heights: &[Height],
     ) -> Result<(), TaskError> {
-        for task in tasks {
+        for (task, execution_height) in tasks.iter().zip(execution_heights) {
             // update state
             task.update_state(&mut self.state);
             task.update_stats(&mut self.state);
@@ -213,13 +564,25 @@ impl WorkloadExecutor {
                         crate::utils::retry_config(),
                     )
                     .await?;
-                    let balance = balance
+                    let balance: u64 = balance
                         .to_string()
                         .parse()
                         .expect("Balance conversion shouldn't fail");
-                    self.state.overwrite_balance(cr.source(), balance);
+                    // the continuous PGF payments are credited by the next sync
+                    let epoch = get_epoch(&self.ctx, crate::utils::retry_config()).await?;
+                    let pgf_payment = self
+                        .state
+                        .get_pgf_payments(epoch)
+                        .get(cr.source())
+                        .copied()
+                        .unwrap_or_default();
+                    self.state.overwrite_balance(
+                        cr.source(),
+                        &Alias::nam().name,
+                        balance.saturating_sub(pgf_payment),
+                    );
 
-                    let claimed_epoch = self.fetch_epoch_at_height(execution_height).await;
+                    let claimed_epoch = self.fetch_epoch_at_height(*execution_height).await;
                     self.state.set_claimed_epoch(cr.source(), claimed_epoch);
                 }
                 Task::InitAccount(_) => {
@@ -229,10 +592,15 @@ impl WorkloadExecutor {
                         .save()
                         .map_err(|e| TaskError::Wallet(e.to_string()))?;
                 }
-                Task::DefaultProposal(_) | Task::Vote(_) => {
+                Task::DefaultProposal(_)
+                | Task::PgfFundingProposal(_)
+                | Task::PgfStewardProposal(_)
+                | Task::Vote(_) => {
                     let last_proposal_id = self.state.proposals.keys().max().cloned();
                     let new_proposals = get_proposals(&self.ctx, last_proposal_id).await?;
                     self.state.add_proposals(new_proposals);
+
+                    self.add_pending_proposal(task).await?;
                 }
                 _ => {}
             }
@@ -240,8 +608,62 @@ impl WorkloadExecutor {
         Ok(())
     }
 
+    async fn add_pending_proposal(&mut self, task: &Task) -> Result<(), TaskError> {
+        let Some((author, start_epoch, activation_epoch, payload)) = task.proposal() else {
+            return Ok(());
+        };
+        let Some(last_proposal_id) = self.state.proposals.keys().max().cloned() else {
+            return Ok(());
+        };
+
+        match find_proposal_id(
+            &self.ctx,
+            &author,
+            start_epoch,
+            activation_epoch,
+            last_proposal_id,
+            retry_config(),
+        )
+        .await?
+        {
+            Some(proposal_id) => self.state.add_pending_proposal(
+                proposal_id,
+                PendingProposal {
+                    author,
+                    activation_epoch,
+                    payload,
+                },
+            ),
+            None => tracing::warn!("Submitted proposal by {} wasn't found", author.name),
+        }
+
+        Ok(())
+    }
+
     pub fn apply_fee_payments(&mut self, fees: &HashMap<Alias, Fee>) {
         fees.iter()
             .for_each(|(payer, fee)| self.state.modify_balance_fee(payer, *fee));
     }
 }
+
+/// Split the tasks into the consecutive groups whose tasks have disjoint
+/// accounts. A task without the settings is executed alone.
+fn independent_task_groups(tasks: &[Task]) -> Vec<Vec<usize>> {
+    let mut groups: Vec<Vec<usize>> = vec![];
+    let mut group_accounts = BTreeSet::new();
+    for (i, task) in tasks.iter().enumerate() {
+        match (task.accounts(), groups.last_mut()) {
+            (Some(accounts), Some(group))
+                if !group_accounts.is_empty() && group_accounts.is_disjoint(&accounts) =>
+            {
+                group.push(i);
+                group_accounts.extend(accounts);
+            }
+            (accounts, _) => {
+                groups.push(vec![i]);
+                group_accounts = accounts.unwrap_or_default();
+            }
+        }
+    }
+    groups
+}
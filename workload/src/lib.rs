@@ -5,6 +5,8 @@ pub mod constants;
 pub mod context;
 pub mod error;
 pub mod executor;
+pub mod replay;
+pub mod scheduler;
 pub mod state;
 pub mod step;
 pub mod task;
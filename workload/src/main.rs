@@ -2,18 +2,30 @@ use std::{env, time::Duration};
 
 use antithesis_sdk::antithesis_init;
 use clap::Parser;
+use namada_chain_workload::check::Check;
 use namada_chain_workload::code::Code;
-use namada_chain_workload::config::{AppConfig, Args};
+use namada_chain_workload::config::{AppConfig, Args, Command};
 use namada_chain_workload::context::Ctx;
-use namada_chain_workload::error::CheckError;
+use namada_chain_workload::error::{CheckError, TaskError};
 use namada_chain_workload::executor::WorkloadExecutor;
+use namada_chain_workload::replay::StepRecord;
+use namada_chain_workload::scheduler::StepScheduler;
 use namada_chain_workload::state::{State, StateError};
-use namada_chain_workload::utils::base_dir;
+use namada_chain_workload::step::StepType;
+use namada_chain_workload::task::Task;
+use namada_chain_workload::types::Height;
+use namada_chain_workload::utils::{base_dir, seed_rng};
 use serde_json::json;
 use tokio::time::sleep;
 use tracing::level_filters::LevelFilter;
 use tracing_subscriber::EnvFilter;
 
+enum RunMode {
+    Single(StepType),
+    Run(StepScheduler, Option<u64>),
+    Replay(Vec<StepRecord>),
+}
+
 #[tokio::main]
 async fn main() {
     let args = Args::parse();
@@ -58,6 +70,7 @@ async fn inner_main(args: Args) -> Code {
 
     let (state, locked_file) = match State::load(config.id) {
         Ok(result) => result,
+        // The empty state file without the journal is for a new state
         Err(StateError::EmptyFile) => {
             tracing::warn!("State file is empty, creating new one");
             match State::create_new(config.id) {
@@ -68,9 +81,7 @@ async fn inner_main(args: Args) -> Code {
         Err(e) => return Code::StateFatal(e),
     };
 
-    let next_step = args.step_type;
     tracing::info!("Using config: {config:#?}");
-    tracing::info!("StepType: {next_step}");
 
     // just to report the workload version
     antithesis_sdk::assert_always!(
@@ -82,6 +93,31 @@ async fn inner_main(args: Args) -> Code {
         })
     );
 
+    let run_mode = match &args.command {
+        Some(Command::Run { weights, max_steps }) => {
+            let mut step_weights = config.step_weights.clone();
+            step_weights.extend(weights.iter().cloned());
+            match StepScheduler::new(&step_weights) {
+                Ok(scheduler) => RunMode::Run(scheduler, *max_steps),
+                Err(e) => return Code::ConfigFatal(e),
+            }
+        }
+        Some(Command::Replay { log }) => {
+            // The recorded steps are reproducible only from the beginning
+            if !state.stats.is_empty() {
+                return Code::ConfigFatal(format!(
+                    "Replay requires a fresh state, remove {}",
+                    State::state_file_path(config.id).display()
+                ));
+            }
+            match StepRecord::load_all(log) {
+                Ok(records) => RunMode::Replay(records),
+                Err(e) => return Code::StateFatal(e),
+            }
+        }
+        None => RunMode::Single(args.step_type.clone().expect("Step type should be given")),
+    };
+
     let ctx = loop {
         match Ctx::new(&config).await {
             Ok(ctx) => break ctx,
@@ -92,11 +128,194 @@ async fn inner_main(args: Args) -> Code {
         }
     };
 
-    let mut workload_executor = WorkloadExecutor::new(ctx, state);
+    let mut workload_executor = WorkloadExecutor::new(ctx, state, args.concurrent);
     if let Err(e) = workload_executor.init().await {
         return Code::InitFatal(e);
     }
 
+    let code = match run_mode {
+        RunMode::Single(next_step) => {
+            tracing::info!("StepType: {next_step}");
+            let seed = next_seed(args.seed, 0);
+            let (code, _) =
+                record_step(&mut workload_executor, next_step, seed, args.no_check).await;
+            code
+        }
+        RunMode::Run(scheduler, max_steps) => {
+            run_steps(
+                &mut workload_executor,
+                &scheduler,
+                max_steps,
+                args.seed,
+                args.no_check,
+            )
+            .await
+        }
+        RunMode::Replay(records) => {
+            replay_steps(&mut workload_executor, records, args.no_check).await
+        }
+    };
+
+    if let Err(e) = State::unlock(locked_file) {
+        return Code::StateFatal(e);
+    }
+
+    code
+}
+
+/// Execute the steps chosen by the scheduler until a fatal failure or the
+/// max number of steps. The code of the last step is returned to the caller.
+async fn run_steps(
+    workload_executor: &mut WorkloadExecutor,
+    scheduler: &StepScheduler,
+    max_steps: Option<u64>,
+    seed: Option<u64>,
+    no_check: bool,
+) -> Code {
+    if let Some(seed) = seed {
+        seed_rng(seed);
+    }
+
+    let mut executed_steps = 0u64;
+    loop {
+        let next_step = scheduler.next_step();
+        tracing::info!("StepType: {next_step}");
+
+        let step_seed = next_seed(seed, executed_steps);
+        let (code, _) = record_step(workload_executor, next_step, step_seed, no_check).await;
+        executed_steps += 1;
+
+        if code.code() != 0 || max_steps.is_some_and(|max_steps| executed_steps >= max_steps) {
+            return code;
+        }
+
+        code.output_logs();
+        code.assert();
+    }
+}
+
+/// Execute the steps recorded in the step log with the recorded seeds. The
+/// replay stops at the first fatal failure.
+async fn replay_steps(
+    workload_executor: &mut WorkloadExecutor,
+    records: Vec<StepRecord>,
+    no_check: bool,
+) -> Code {
+    let total_steps = records.len();
+    let mut code = Code::ConfigFatal("No step to replay".to_string());
+    for (i, record) in records.into_iter().enumerate() {
+        let next_step: StepType = match record.step.parse() {
+            Ok(step) => step,
+            Err(e) => return Code::ConfigFatal(e),
+        };
+        tracing::info!("Replaying step {}/{total_steps}: {next_step}", i + 1);
+
+        let (step_code, tasks) =
+            execute_step(workload_executor, next_step, record.seed, no_check).await;
+        // the later steps can't be reproduced after the divergence
+        if tasks != record.tasks {
+            return Code::ConfigFatal(format!(
+                "Replay diverged at step {}: recorded tasks {:?}, built tasks {tasks:?}",
+                i + 1,
+                record.tasks
+            ));
+        }
+
+        if step_code.code() != 0 {
+            return step_code;
+        }
+
+        step_code.output_logs();
+        step_code.assert();
+        code = step_code;
+    }
+
+    code
+}
+
+/// Seed for the next step. Without the given seed, the seed is drawn from
+/// Antithesis to record it in the step log.
+fn next_seed(seed: Option<u64>, executed_steps: u64) -> u64 {
+    match seed {
+        Some(seed) => seed.wrapping_add(executed_steps),
+        None => antithesis_sdk::random::get_random(),
+    }
+}
+
+/// Execute the step and append it to the step log
+async fn record_step(
+    workload_executor: &mut WorkloadExecutor,
+    next_step: StepType,
+    seed: u64,
+    no_check: bool,
+) -> (Code, Vec<String>) {
+    let step = next_step.to_string();
+    let (code, tasks) = execute_step(workload_executor, next_step, seed, no_check).await;
+
+    let record = StepRecord {
+        step,
+        seed,
+        tasks: tasks.clone(),
+    };
+    if let Err(e) = record.append(workload_executor.state().id) {
+        tracing::warn!("Failed to record the step: {e}");
+    }
+
+    (code, tasks)
+}
+
+/// Execute the step and persist the updated state. The state is restored
+/// when the step failed before updating it. The summaries of the built tasks
+/// are returned with the code.
+async fn execute_step(
+    workload_executor: &mut WorkloadExecutor,
+    next_step: StepType,
+    seed: u64,
+    no_check: bool,
+) -> (Code, Vec<String>) {
+    seed_rng(seed);
+
+    let pre_state = workload_executor.state().clone();
+    let mut tasks = vec![];
+    let code = match try_execute_step(workload_executor, next_step, no_check, &mut tasks).await {
+        Ok(code) => match workload_executor.state().save(None) {
+            Ok(_) => code,
+            Err(e) => Code::StateFatal(e),
+        },
+        Err(code) => {
+            workload_executor.restore_state(pre_state);
+            code
+        }
+    };
+
+    (code, tasks)
+}
+
+/// Returns `Err` when the state shouldn't be persisted
+async fn try_execute_step(
+    workload_executor: &mut WorkloadExecutor,
+    next_step: StepType,
+    no_check: bool,
+    task_summaries: &mut Vec<String>,
+) -> Result<Code, Code> {
+    if let Err(e) = workload_executor.sync_slashes().await {
+        return Err(Code::StepFailure(next_step, e));
+    }
+
+    if let Err(e) = workload_executor.sync_stewards().await {
+        return Err(Code::StepFailure(next_step, e));
+    }
+
+    if let Err(e) = workload_executor.sync_masp_rewards().await {
+        return Err(Code::StepFailure(next_step, e));
+    }
+
+    match workload_executor.check_activated_proposals().await {
+        Ok(_) => {}
+        Err(e) if matches!(e, CheckError::State(_)) => return Err(Code::Fatal(next_step, e)),
+        Err(e) => return Err(Code::CheckFailure(next_step, e)),
+    }
+
     match workload_executor.is_valid(&next_step).await {
         Ok(true) => {}
         _ => {
@@ -104,69 +323,100 @@ async fn inner_main(args: Args) -> Code {
                 "Invalid step: {next_step} -> {:>?}",
                 workload_executor.state()
             );
-            return Code::Skip(next_step);
+            return Err(Code::Skip(next_step));
         }
     }
 
     tracing::info!("Step is: {next_step}...");
     let tasks = match workload_executor.build_tasks(&next_step).await {
         Ok(tasks) if tasks.is_empty() => {
-            return Code::NoTask(next_step);
+            return Err(Code::NoTask(next_step));
         }
         Ok(tasks) => tasks,
         Err(e) => {
-            return Code::StepFailure(next_step, e);
+            return Err(Code::StepFailure(next_step, e));
         }
     };
+    task_summaries.extend(tasks.iter().map(|task| task.to_string()));
     tracing::info!("Built tasks for {next_step}");
 
-    let checks = if args.no_check {
-        vec![]
+    // The pre balances of the checks include the PGF payments made until now
+    if let Err(e) = workload_executor.sync_pgf_payments().await {
+        return Err(Code::StepFailure(next_step, e));
+    }
+
+    let checks = if no_check {
+        tasks.iter().map(|_| vec![]).collect()
     } else {
         match workload_executor.build_check(&tasks).await {
             Ok(checks) => checks,
-            Err(e) => return Code::TaskFailure(next_step, e),
+            Err(e) => return Err(Code::TaskFailure(next_step, e)),
         }
     };
     tracing::info!("Built checks for {next_step}");
 
-    let (result, fees) = workload_executor.execute(&tasks).await;
+    let (results, fees) = workload_executor.execute(&tasks).await;
     workload_executor.apply_fee_payments(&fees);
 
-    let execution_height = match result {
-        Ok(height) => height,
-        Err(e) => {
-            // Update the state file for the fee payment of the failure transactions
-            if let Err(e) = workload_executor.state().save(Some(locked_file)) {
-                return Code::StateFatal(e);
-            }
-
-            return Code::TaskFailure(next_step, e);
-        }
-    };
-
-    tracing::info!("Execution were successful, updating state...");
+    // The state is updated for the succeeded tasks even when another task failed
+    let (executed_tasks, execution_heights): (Vec<Task>, Vec<Height>) = tasks
+        .iter()
+        .zip(&results)
+        .filter_map(|(task, result)| result.as_ref().ok().map(|height| (task.clone(), *height)))
+        .unzip();
+    tracing::info!(
+        "Updating state for {} executed tasks...",
+        executed_tasks.len()
+    );
     if let Err(e) = workload_executor
-        .post_execute(&tasks, execution_height)
+        .post_execute(&executed_tasks, &execution_heights)
         .await
     {
-        return Code::TaskFailure(next_step, e);
+        return Err(Code::TaskFailure(next_step, e));
+    }
+    workload_executor.post_execute_failures(&tasks, &results);
+
+    // The state is persisted for the fee payment of the failure transactions.
+    // The rejected tx is checked not to change the balances except for the fee.
+    let mut rejection = None;
+    let mut executed_checks = vec![];
+    let mut check_heights = vec![];
+    for (task_checks, result) in checks.into_iter().zip(results) {
+        match result {
+            Ok(height) => {
+                executed_checks.push(task_checks);
+                check_heights.push(height);
+            }
+            Err(
+                e @ (TaskError::InsufficientSignatures { height, .. }
+                | TaskError::InsufficientShieldedFee { height, .. }),
+            ) => {
+                executed_checks.push(
+                    task_checks
+                        .into_iter()
+                        .filter_map(Check::into_rejected)
+                        .collect(),
+                );
+                check_heights.push(height);
+                rejection.get_or_insert(e);
+            }
+            Err(e) => return Ok(Code::TaskFailure(next_step, e)),
+        }
     }
 
     let exit_code = match workload_executor
-        .checks(checks, execution_height, &fees)
+        .checks(executed_checks, &check_heights, &fees)
         .await
     {
-        Ok(_) => Code::Success(next_step),
+        Ok(_) => match rejection {
+            Some(e) => Code::Rejected(next_step, e),
+            None => Code::Success(next_step),
+        },
         Err(e) if matches!(e, CheckError::State(_)) => Code::Fatal(next_step, e),
         Err(e) => Code::CheckFailure(next_step, e),
     };
 
     tracing::info!("Statistics: {:>?}", workload_executor.state().stats);
 
-    if let Err(e) = workload_executor.state().save(Some(locked_file)) {
-        return Code::StateFatal(e);
-    }
-
-    exit_code
+    Ok(exit_code)
 }
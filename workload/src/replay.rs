@@ -0,0 +1,51 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::state::StateError;
+
+/// An executed step recorded in the step log to replay the run
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepRecord {
+    pub step: String,
+    /// Seed of the RNG used while building the tasks
+    pub seed: u64,
+    /// Summaries of the built tasks
+    pub tasks: Vec<String>,
+}
+
+impl StepRecord {
+    pub fn log_file_path(id: u64) -> PathBuf {
+        std::env::current_dir()
+            .expect("current directory")
+            .join(format!("steps-{id}.jsonl"))
+    }
+
+    pub fn append(&self, id: u64) -> Result<(), StateError> {
+        let mut line = serde_json::to_string(self).map_err(StateError::Serde)?;
+        line.push('\n');
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(Self::log_file_path(id))
+            .map_err(StateError::File)?;
+        file.write_all(line.as_bytes()).map_err(StateError::File)
+    }
+
+    pub fn load_all(path: &Path) -> Result<Vec<Self>, StateError> {
+        let data = fs::read_to_string(path).map_err(StateError::File)?;
+        let records: Vec<Self> = data
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(StateError::Serde))
+            .collect::<Result<_, _>>()?;
+        if records.is_empty() {
+            return Err(StateError::EmptyFile);
+        }
+
+        Ok(records)
+    }
+}
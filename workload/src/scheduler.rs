@@ -0,0 +1,35 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use rand::distributions::{Distribution, WeightedIndex};
+
+use crate::step::StepType;
+use crate::utils::WorkloadRng;
+
+/// Chooses the next step from the weighted distribution of the step types
+pub struct StepScheduler {
+    steps: Vec<StepType>,
+    distribution: WeightedIndex<u64>,
+}
+
+impl StepScheduler {
+    pub fn new(weights: &BTreeMap<String, u64>) -> Result<Self, String> {
+        let mut steps = vec![];
+        let mut step_weights = vec![];
+        for (step, weight) in weights.iter().filter(|(_, weight)| **weight > 0) {
+            steps.push(StepType::from_str(step)?);
+            step_weights.push(*weight);
+        }
+        let distribution = WeightedIndex::new(step_weights)
+            .map_err(|e| format!("Invalid step weights {weights:?}: {e}"))?;
+
+        Ok(Self {
+            steps,
+            distribution,
+        })
+    }
+
+    pub fn next_step(&self) -> StepType {
+        self.steps[self.distribution.sample(&mut WorkloadRng)].clone()
+    }
+}
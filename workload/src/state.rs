@@ -1,15 +1,23 @@
-use std::collections::{BTreeSet, HashMap, HashSet};
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::{env, fs};
 
-use antithesis_sdk::random::AntithesisRng;
 use fs2::FileExt;
 use rand::seq::IteratorRandom;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use thiserror::Error;
 
-use crate::constants::{MAX_BATCH_TX_NUM, MIN_TRANSFER_BALANCE, PIPELINE_LEN};
-use crate::types::{Alias, Epoch, ProposalId};
+use crate::constants::{
+    MAX_BATCH_TX_NUM, MIN_TRANSFER_BALANCE, PIPELINE_LEN, STATE_JOURNAL_LEN, WITHDRAW_EPOCH_OFFSET,
+};
+use crate::types::{Alias, Epoch, MaspEpoch, ProposalId, ProposalVote};
+use crate::utils::WorkloadRng;
+
+mod migration;
+
+pub use migration::STATE_VERSION;
 
 #[derive(Error, Debug)]
 pub enum StateError {
@@ -19,6 +27,31 @@ pub enum StateError {
     Serde(serde_json::Error),
     #[error("State file is empty")]
     EmptyFile,
+    #[error("Unknown state version: `{0}` (current version: `{}`)", STATE_VERSION)]
+    UnknownVersion(u64),
+    #[error("Migration from state version `{0}` failed: `{1}`")]
+    Migration(u64, String),
+    #[error("State can't be recovered from the journal: `{0}`")]
+    Unrecoverable(Box<StateError>),
+}
+
+impl StateError {
+    /// Whether the state file was partially written, e.g. the process was
+    /// killed while writing it
+    fn is_truncated(&self) -> bool {
+        match self {
+            StateError::EmptyFile => true,
+            StateError::Serde(e) => e.is_eof(),
+            _ => false,
+        }
+    }
+}
+
+/// The state file format with the schema version
+#[derive(Serialize, Deserialize)]
+struct VersionedState<T> {
+    version: u64,
+    state: T,
 }
 
 #[derive(Clone, Debug, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
@@ -61,43 +94,107 @@ pub struct Bond {
     pub amount: u64,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProposalPayload {
+    Default,
+    PgfFunding {
+        target: Alias,
+        amount: u64,
+        is_continuous: bool,
+    },
+    PgfSteward {
+        steward: Alias,
+        is_add: bool,
+    },
+    /// Proposal code writing the storage key
+    Wasm {
+        key: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteRecord {
+    pub vote: ProposalVote,
+    /// Bonded stake of the voter to each validator at the end epoch
+    pub stakes: BTreeMap<String, u64>,
+    /// The stake can't be predicted when a bond change or a slash could land at the end epoch
+    pub is_stake_known: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContinuousFunding {
+    pub target: Alias,
+    /// Amount paid to the target at every epoch
+    pub amount: u64,
+    /// Epoch up to which the payments are credited to the target balance
+    pub paid_epoch: Epoch,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingProposal {
+    pub author: Alias,
+    pub activation_epoch: Epoch,
+    pub payload: ProposalPayload,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct State {
-    pub accounts: HashMap<Alias, Account>,
-    pub balances: HashMap<Alias, u64>,
-    pub masp_balances: HashMap<Alias, u64>,
-    pub ibc_balances: HashMap<Alias, HashMap<String, u64>>,
-    pub ibc_masp_balances: HashMap<Alias, HashMap<String, u64>>,
-    pub foreign_balances: HashMap<Alias, u64>,
-    pub bonds: HashMap<Alias, HashMap<String, (u64, Epoch)>>,
-    pub unbonds: HashMap<Alias, HashMap<String, u64>>,
-    pub redelegations: HashMap<Alias, HashMap<String, u64>>,
-    pub claimed_epochs: HashMap<Alias, Epoch>,
-    pub validators: HashMap<Alias, Account>,
-    pub deactivated_validators: HashMap<Alias, (Account, Epoch)>,
-    pub proposals: HashMap<u64, (u64, u64)>,
+    pub accounts: BTreeMap<Alias, Account>,
+    /// Transparent balances of each token (denom) held by an account
+    pub balances: BTreeMap<Alias, BTreeMap<String, u64>>,
+    /// Shielded balances of each token (denom) held by a MASP account
+    pub masp_balances: BTreeMap<Alias, BTreeMap<String, u64>>,
+    /// MASP epoch up to which the shielded rewards of a MASP account are accounted
+    pub masp_epochs: BTreeMap<Alias, MaspEpoch>,
+    pub foreign_balances: BTreeMap<Alias, u64>,
+    /// Balances of each multi-hop IBC token (full trace) held by an account on Cosmos
+    pub ibc_balances: BTreeMap<Alias, BTreeMap<String, u64>>,
+    /// NFT traces owned by each account
+    pub nfts: BTreeMap<Alias, BTreeSet<String>>,
+    /// NFT token IDs owned by each account on Cosmos
+    pub foreign_nfts: BTreeMap<Alias, BTreeSet<String>>,
+    pub bonds: BTreeMap<Alias, BTreeMap<String, (u64, Epoch)>>,
+    pub unbonds: BTreeMap<Alias, BTreeMap<String, BTreeMap<Epoch, u64>>>,
+    pub redelegations: BTreeMap<Alias, BTreeMap<String, u64>>,
+    pub slashes: BTreeMap<String, u64>,
+    pub claimed_epochs: BTreeMap<Alias, Epoch>,
+    pub validators: BTreeMap<Alias, Account>,
+    pub deactivated_validators: BTreeMap<Alias, (Account, Epoch)>,
+    pub proposals: BTreeMap<u64, (u64, u64)>,
+    pub pending_proposals: BTreeMap<ProposalId, PendingProposal>,
+    pub votes: BTreeMap<ProposalId, BTreeMap<Alias, VoteRecord>>,
+    /// Continuous PGF fundings of the passed proposals
+    pub pgf_fundings: BTreeMap<ProposalId, ContinuousFunding>,
+    pub stewards: BTreeSet<Alias>,
     pub id: u64,
-    pub stats: HashMap<String, u64>,
+    pub stats: BTreeMap<String, u64>,
 }
 
 impl State {
     pub fn new(id: u64) -> Self {
         Self {
-            accounts: HashMap::default(),
-            balances: HashMap::default(),
-            masp_balances: HashMap::default(),
-            ibc_balances: HashMap::default(),
-            ibc_masp_balances: HashMap::default(),
-            foreign_balances: HashMap::default(),
-            bonds: HashMap::default(),
-            unbonds: HashMap::default(),
-            redelegations: HashMap::default(),
-            claimed_epochs: HashMap::default(),
-            validators: HashMap::default(),
-            deactivated_validators: HashMap::default(),
-            proposals: HashMap::default(),
+            accounts: BTreeMap::default(),
+            balances: BTreeMap::default(),
+            masp_balances: BTreeMap::default(),
+            masp_epochs: BTreeMap::default(),
+            foreign_balances: BTreeMap::default(),
+            ibc_balances: BTreeMap::default(),
+            nfts: BTreeMap::default(),
+            foreign_nfts: BTreeMap::default(),
+            bonds: BTreeMap::default(),
+            unbonds: BTreeMap::default(),
+            redelegations: BTreeMap::default(),
+            slashes: BTreeMap::default(),
+            claimed_epochs: BTreeMap::default(),
+            validators: BTreeMap::default(),
+            deactivated_validators: BTreeMap::default(),
+            proposals: BTreeMap::default(),
+            pending_proposals: BTreeMap::default(),
+            votes: BTreeMap::default(),
+            pgf_fundings: BTreeMap::default(),
+            stewards: BTreeSet::default(),
             id,
-            stats: HashMap::default(),
+            stats: BTreeMap::default(),
         }
     }
 
@@ -109,18 +206,65 @@ impl State {
             .join(format!("state-{id}.json"))
     }
 
+    fn journal_file_path(id: u64, index: usize) -> PathBuf {
+        Self::state_file_path(id).with_extension(format!("json.{index}"))
+    }
+
     pub fn save(&self, locked_file: Option<fs::File>) -> Result<(), StateError> {
         let path = Self::state_file_path(self.id);
-        let state_json = serde_json::to_string_pretty(&self).map_err(StateError::Serde)?;
-        fs::write(path, state_json).map_err(StateError::File)?;
+        let versioned_state = VersionedState {
+            version: STATE_VERSION,
+            state: self,
+        };
+        let state_json =
+            serde_json::to_string_pretty(&versioned_state).map_err(StateError::Serde)?;
+
+        // Write to a temporary file and replace the state file with it so that
+        // the state file is never partially written even if the process is killed
+        let tmp_path = path.with_extension("json.tmp");
+        let mut tmp_file = fs::File::create(&tmp_path).map_err(StateError::File)?;
+        tmp_file
+            .write_all(state_json.as_bytes())
+            .map_err(StateError::File)?;
+        tmp_file.sync_all().map_err(StateError::File)?;
+
+        Self::rotate_journal(self.id, &path)?;
+        fs::rename(&tmp_path, &path).map_err(StateError::File)?;
+        if let Some(dir) = path.parent() {
+            fs::File::open(dir)
+                .and_then(|dir| dir.sync_all())
+                .map_err(StateError::File)?;
+        }
 
         if let Some(file) = locked_file {
-            fs2::FileExt::unlock(&file).map_err(StateError::File)?;
+            Self::unlock(file)?;
         }
 
         Ok(())
     }
 
+    /// Keep the current state file as the latest journal entry and drop the
+    /// oldest one
+    fn rotate_journal(id: u64, path: &Path) -> Result<(), StateError> {
+        // the empty state file is for a new state
+        if fs::metadata(path).map_or(true, |metadata| metadata.len() == 0) {
+            return Ok(());
+        }
+
+        for index in (1..STATE_JOURNAL_LEN).rev() {
+            let from = Self::journal_file_path(id, index);
+            if from.exists() {
+                fs::rename(&from, Self::journal_file_path(id, index + 1))
+                    .map_err(StateError::File)?;
+            }
+        }
+        fs::hard_link(path, Self::journal_file_path(id, 1)).map_err(StateError::File)
+    }
+
+    pub fn unlock(locked_file: fs::File) -> Result<(), StateError> {
+        fs2::FileExt::unlock(&locked_file).map_err(StateError::File)
+    }
+
     pub fn create_new(id: u64) -> Result<(Self, fs::File), StateError> {
         // Lock the state file before writing the new
         let file = Self::lock_state_file(id)?;
@@ -137,20 +281,84 @@ impl State {
         // Lock the state file before loading
         let file = Self::lock_state_file(id)?;
 
+        let state = match Self::read_state_file(&path) {
+            Ok(state) => state,
+            // The decode and migration errors of the complete state aren't recovered
+            Err(e) if e.is_truncated() => {
+                // No journal entry means that the state is new
+                let has_journal = (1..=STATE_JOURNAL_LEN)
+                    .any(|index| Self::journal_file_path(id, index).exists());
+                if !has_journal {
+                    return Err(e);
+                }
+
+                let recovered = Self::recover(id);
+                let details = json!({
+                    "id": id,
+                    "error": e.to_string(),
+                    "journal_index": recovered.as_ref().map(|(_, index)| index),
+                });
+                antithesis_sdk::assert_unreachable!("State file was truncated", &details);
+                let Some((state, _)) = recovered else {
+                    tracing::error!("State wasn't recovered from the journal: {details}");
+                    return Err(StateError::Unrecoverable(Box::new(e)));
+                };
+                tracing::warn!("State was recovered from the journal: {details}");
+                state
+            }
+            Err(e) => return Err(e),
+        };
+
+        // Returns the file to be unlocked later
+        Ok((state, file))
+    }
+
+    /// Returns the latest valid state in the journal with its index
+    fn recover(id: u64) -> Option<(Self, usize)> {
+        (1..=STATE_JOURNAL_LEN).find_map(|index| {
+            let path = Self::journal_file_path(id, index);
+            match Self::read_state_file(&path) {
+                Ok(state) => Some((state, index)),
+                Err(e) => {
+                    if path.exists() {
+                        tracing::warn!("Invalid journal entry {}: {e}", path.display());
+                    }
+                    None
+                }
+            }
+        })
+    }
+
+    fn read_state_file(path: &Path) -> Result<Self, StateError> {
         let data = fs::read_to_string(path).map_err(StateError::File)?;
         if data.trim().is_empty() {
             return Err(StateError::EmptyFile);
         }
-        let state = serde_json::from_str(&data).map_err(StateError::Serde)?;
+        Self::decode(&data)
+    }
 
-        // Returns the file to be unlocked later
-        Ok((state, file))
+    fn decode(data: &str) -> Result<Self, StateError> {
+        let value: serde_json::Value = serde_json::from_str(data).map_err(StateError::Serde)?;
+        // The state without the envelope is the version 0
+        let (state, version) = match serde_json::from_value::<VersionedState<_>>(value.clone()) {
+            Ok(VersionedState { version, state }) => (state, version),
+            Err(_) => (value, 0),
+        };
+        let state = migration::migrate(state, version)?;
+
+        serde_json::from_value(state).map_err(StateError::Serde)
     }
 
     fn lock_state_file(id: u64) -> Result<fs::File, StateError> {
-        let path = Self::state_file_path(id);
-
-        let file = fs::File::open(&path).map_err(StateError::File)?;
+        // The state file is replaced on every save, so the lock is held on another file
+        let path = Self::state_file_path(id).with_extension("lock");
+
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .map_err(StateError::File)?;
         file.lock_exclusive().map_err(StateError::File)?;
 
         Ok(file)
@@ -174,36 +382,45 @@ impl State {
             >= sample as usize
     }
 
-    pub fn at_least_masp_account_with_minimal_balance(
-        &self,
-        number_of_accounts: usize,
-        min_balance: u64,
-    ) -> bool {
+    pub fn any_masp_account_can_make_transfer(&self) -> bool {
         self.masp_balances
-            .iter()
-            .filter(|(_, balance)| **balance >= min_balance)
-            .count()
-            >= number_of_accounts
+            .values()
+            .any(|balances| Self::any_transferable_token(balances))
+    }
+
+    pub fn any_masp_account_with_min_balance(&self, min_balance: u64) -> bool {
+        let denom = Alias::nam().name;
+        self.masp_balances
+            .values()
+            .any(|balances| balances.get(&denom).cloned().unwrap_or_default() >= min_balance)
     }
 
     pub fn any_account_with_min_balance(&self, min_balance: u64) -> bool {
+        let denom = Alias::nam().name;
         self.balances
-            .iter()
-            .any(|(_, balance)| balance >= &min_balance)
+            .values()
+            .any(|balances| balances.get(&denom).cloned().unwrap_or_default() >= min_balance)
     }
 
     pub fn min_n_account_with_min_balance(&self, sample: usize, min_balance: u64) -> bool {
+        let denom = Alias::nam().name;
         self.balances
-            .iter()
-            .filter(|(_, balance)| **balance >= min_balance)
+            .values()
+            .filter(|balances| balances.get(&denom).cloned().unwrap_or_default() >= min_balance)
             .count()
             >= sample
     }
 
     pub fn any_account_can_make_transfer(&self) -> bool {
         self.balances
+            .values()
+            .any(|balances| Self::any_transferable_token(balances))
+    }
+
+    fn any_transferable_token(balances: &BTreeMap<String, u64>) -> bool {
+        balances
             .iter()
-            .any(|(_, balance)| balance >= &MIN_TRANSFER_BALANCE)
+            .any(|(denom, balance)| *balance >= min_transfer_balance(denom))
     }
 
     pub fn min_n_implicit_accounts(&self, sample_size: usize) -> bool {
@@ -235,6 +452,14 @@ impl State {
             >= sample
     }
 
+    pub fn any_unbond(&self) -> bool {
+        self.unbonds
+            .values()
+            .flat_map(|data| data.values())
+            .flat_map(|chunks| chunks.values())
+            .any(|amount| *amount > 0)
+    }
+
     pub fn min_n_validators(&self, sample: usize) -> bool {
         self.validators.len() >= sample
     }
@@ -243,6 +468,14 @@ impl State {
         self.deactivated_validators.len() >= sample
     }
 
+    pub fn any_nft(&self) -> bool {
+        self.nfts.values().any(|nfts| !nfts.is_empty())
+    }
+
+    pub fn any_steward(&self) -> bool {
+        !self.stewards.is_empty()
+    }
+
     pub fn any_votable_proposal(&self, current_epoch: u64) -> bool {
         self.proposals.iter().any(|(_, (start_epoch, end_epoch))| {
             current_epoch >= *start_epoch && current_epoch < *end_epoch
@@ -255,7 +488,7 @@ impl State {
         self.accounts
             .iter()
             .filter(|(alias, _)| !blacklist.contains(alias))
-            .choose(&mut AntithesisRng)
+            .choose(&mut WorkloadRng)
             .map(|(_, account)| account.clone())
     }
 
@@ -264,37 +497,64 @@ impl State {
         blacklist: Vec<Alias>,
         min_value: u64,
     ) -> Option<Account> {
+        let denom = Alias::nam().name;
         self.masp_balances
             .iter()
-            .filter(|(alias, balance)| !blacklist.contains(alias) && **balance >= min_value)
+            .filter(|(alias, balances)| {
+                !blacklist.contains(alias)
+                    && balances.get(&denom).cloned().unwrap_or_default() >= min_value
+            })
             .filter_map(|(alias, _)| self.accounts.get(alias).cloned())
-            .choose(&mut AntithesisRng)
+            .choose(&mut WorkloadRng)
     }
 
-    pub fn random_account_with_ibc_balance(&self, blacklist: Vec<Alias>) -> Option<Account> {
-        self.accounts
+    /// A random account and a token (denom) which the account can transfer
+    pub fn random_account_with_token(&self, blacklist: Vec<Alias>) -> Option<(Account, String)> {
+        Self::random_token_holder(&self.balances, blacklist)
+            .and_then(|(alias, denom)| Some((self.accounts.get(&alias)?.clone(), denom)))
+    }
+
+    /// A random MASP account and a token (denom) which the account can transfer
+    pub fn random_masp_account_with_token(
+        &self,
+        blacklist: Vec<Alias>,
+    ) -> Option<(Account, String)> {
+        Self::random_token_holder(&self.masp_balances, blacklist)
+            .and_then(|(alias, denom)| Some((self.accounts.get(&alias)?.clone(), denom)))
+    }
+
+    fn random_token_holder(
+        balances: &BTreeMap<Alias, BTreeMap<String, u64>>,
+        blacklist: Vec<Alias>,
+    ) -> Option<(Alias, String)> {
+        balances
             .iter()
             .filter(|(alias, _)| !blacklist.contains(alias))
-            .filter(|(alias, _)| {
-                self.ibc_balances
-                    .get(alias)
-                    .is_some_and(|balances| balances.iter().any(|(_, b)| *b > MAX_BATCH_TX_NUM))
+            .flat_map(|(alias, balances)| {
+                balances
+                    .iter()
+                    .filter(|(denom, balance)| **balance >= min_transfer_balance(denom))
+                    .map(|(denom, _)| (alias.clone(), denom.clone()))
             })
-            .map(|(_, account)| account.clone())
-            .choose(&mut AntithesisRng)
+            .choose(&mut WorkloadRng)
     }
 
-    pub fn random_masp_account_with_ibc_balance(&self, blacklist: Vec<Alias>) -> Option<Account> {
-        self.accounts
+    /// A random account and an NFT trace owned by the account
+    pub fn random_nft(&self, blacklist: Vec<Alias>) -> Option<(Account, String)> {
+        self.nfts
             .iter()
             .filter(|(alias, _)| !blacklist.contains(alias))
-            .filter(|(alias, _)| {
-                self.ibc_masp_balances
-                    .get(alias)
-                    .is_some_and(|balances| balances.iter().any(|(_, b)| *b > MAX_BATCH_TX_NUM))
-            })
-            .map(|(_, account)| account.clone())
-            .choose(&mut AntithesisRng)
+            .flat_map(|(alias, nfts)| nfts.iter().map(move |trace| (alias, trace)))
+            .filter_map(|(alias, trace)| Some((self.accounts.get(alias)?.clone(), trace.clone())))
+            .choose(&mut WorkloadRng)
+    }
+
+    pub fn random_foreign_nft(&self, owner: &Alias) -> Option<String> {
+        self.foreign_nfts
+            .get(owner)?
+            .iter()
+            .choose(&mut WorkloadRng)
+            .cloned()
     }
 
     pub fn random_payment_address(&self, blacklist: Vec<Alias>) -> Option<Account> {
@@ -309,7 +569,7 @@ impl State {
         self.accounts
             .iter()
             .filter(|(alias, account)| account.is_implicit() && !blacklist.contains(alias))
-            .choose_multiple(&mut AntithesisRng, sample_size)
+            .choose_multiple(&mut WorkloadRng, sample_size)
             .into_iter()
             .map(|(_, account)| account.clone())
             .collect()
@@ -324,7 +584,7 @@ impl State {
             .iter()
             .filter(|(alias, _)| !blacklist.contains(alias))
             .filter(|(_, account)| account.is_established())
-            .choose_multiple(&mut AntithesisRng, sample_size)
+            .choose_multiple(&mut WorkloadRng, sample_size)
             .into_iter()
             .map(|(_, account)| account.clone())
             .collect()
@@ -335,7 +595,7 @@ impl State {
             .iter()
             .filter(|(alias, _)| !blacklist.contains(alias))
             .filter(|(_, account)| account.is_established())
-            .choose_multiple(&mut AntithesisRng, sample_size)
+            .choose_multiple(&mut WorkloadRng, sample_size)
             .into_iter()
             .map(|(_, account)| account.clone())
             .collect()
@@ -354,7 +614,7 @@ impl State {
                     && account.is_established()
                     && current_epoch > epoch + PIPELINE_LEN
             })
-            .choose_multiple(&mut AntithesisRng, sample_size)
+            .choose_multiple(&mut WorkloadRng, sample_size)
             .into_iter()
             .map(|(_, (account, _))| account.clone())
             .collect()
@@ -378,7 +638,35 @@ impl State {
                     }
                 })
             })
-            .choose(&mut AntithesisRng)
+            .choose(&mut WorkloadRng)
+    }
+
+    pub fn random_withdrawable_unbond(&self, current_epoch: Epoch) -> Option<Bond> {
+        self.unbonds
+            .iter()
+            .flat_map(|(source, unbonds)| {
+                unbonds.iter().filter_map(|(validator, chunks)| {
+                    // the withdrawal will be requested at the current epoch,
+                    // but the execution could be at the next epoch
+                    if chunks.contains_key(&(current_epoch + 1)) {
+                        return None;
+                    }
+                    let amount = chunks
+                        .range(..=current_epoch)
+                        .map(|(_, amount)| amount)
+                        .sum::<u64>();
+                    if amount > 0 {
+                        Some(Bond {
+                            alias: source.to_owned(),
+                            validator: validator.to_owned(),
+                            amount,
+                        })
+                    } else {
+                        None
+                    }
+                })
+            })
+            .choose(&mut WorkloadRng)
     }
 
     pub fn random_account_with_min_balance(
@@ -386,19 +674,34 @@ impl State {
         blacklist: Vec<Alias>,
         min_balance: u64,
     ) -> Option<Account> {
+        let denom = Alias::nam().name;
         self.balances
             .iter()
-            .filter_map(|(alias, balance)| {
+            .filter_map(|(alias, balances)| {
                 if blacklist.contains(alias) {
                     return None;
                 }
-                if balance >= &min_balance {
+                if balances.get(&denom).cloned().unwrap_or_default() >= min_balance {
                     Some(self.accounts.get(alias).unwrap().clone())
                 } else {
                     None
                 }
             })
-            .choose(&mut AntithesisRng)
+            .choose(&mut WorkloadRng)
+    }
+
+    /// The continuous PGF payments to each target made until the epoch, which
+    /// aren't credited yet
+    pub fn get_pgf_payments(&self, epoch: Epoch) -> BTreeMap<Alias, u64> {
+        let mut payments = BTreeMap::<Alias, u64>::new();
+        for funding in self.pgf_fundings.values() {
+            let num_payments = epoch.saturating_sub(funding.paid_epoch);
+            if num_payments > 0 {
+                *payments.entry(funding.target.clone()).or_default() +=
+                    funding.amount * num_payments;
+            }
+        }
+        payments
     }
 
     pub fn get_account_by_alias(&self, alias: &Alias) -> Account {
@@ -409,27 +712,24 @@ impl State {
         self.claimed_epochs.get(alias).cloned()
     }
 
-    pub fn get_balance_for(&self, alias: &Alias) -> u64 {
-        self.balances.get(alias).cloned().unwrap_or_default()
+    pub fn get_masp_epoch(&self, alias: &Alias) -> Option<MaspEpoch> {
+        self.masp_epochs.get(&alias.base()).cloned()
     }
 
-    pub fn get_shielded_balance_for(&self, alias: &Alias) -> u64 {
-        self.masp_balances
-            .get(&alias.base())
+    pub fn get_balance_for(&self, alias: &Alias, denom: &str) -> u64 {
+        self.balances
+            .get(alias)
+            .and_then(|balances| balances.get(denom))
             .cloned()
             .unwrap_or_default()
     }
 
-    pub fn get_ibc_balance_for(&self, alias: &Alias, denom: &str) -> u64 {
-        let balances = if alias.is_spending_key() || alias.is_payment_address() {
-            self.ibc_masp_balances.get(&alias.base())
-        } else {
-            self.ibc_balances.get(alias)
-        };
-        let Some(balances) = balances else {
-            return 0;
-        };
-        balances.get(denom).cloned().unwrap_or_default()
+    pub fn get_shielded_balance_for(&self, alias: &Alias, denom: &str) -> u64 {
+        self.masp_balances
+            .get(&alias.base())
+            .and_then(|balances| balances.get(denom))
+            .cloned()
+            .unwrap_or_default()
     }
 
     pub fn get_foreign_balance_for(&self, alias: &Alias) -> u64 {
@@ -439,13 +739,105 @@ impl State {
             .unwrap_or_default()
     }
 
-    pub fn get_redelegations_targets_for(&self, alias: &Alias) -> HashSet<String> {
+    pub fn get_ibc_balance_for(&self, alias: &Alias, trace: &str) -> u64 {
+        self.ibc_balances
+            .get(alias)
+            .and_then(|balances| balances.get(trace))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn get_unbonds_for(&self, alias: &Alias, validator: &str) -> BTreeMap<Epoch, u64> {
+        self.unbonds
+            .get(alias)
+            .and_then(|unbonds| unbonds.get(validator))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn get_redelegations_targets_for(&self, alias: &Alias) -> BTreeSet<String> {
         self.redelegations
             .get(alias)
-            .map(|data| data.keys().cloned().collect::<HashSet<String>>())
+            .map(|data| data.keys().cloned().collect::<BTreeSet<String>>())
             .unwrap_or_default()
     }
 
+    /// The own bond and the bond redelegated from other validators. The
+    /// on-chain bond is the sum of them.
+    pub fn get_bond_parts(&self, source: &Alias, validator: &str) -> (u64, u64) {
+        let own_bond = self
+            .bonds
+            .get(source)
+            .and_then(|bonds| bonds.get(validator))
+            .map(|(amount, _)| *amount)
+            .unwrap_or_default();
+        let redelegated_bond = self
+            .redelegations
+            .get(source)
+            .and_then(|redelegations| redelegations.get(validator))
+            .cloned()
+            .unwrap_or_default();
+        (own_bond, redelegated_bond)
+    }
+
+    pub fn get_bonded_validators(&self) -> BTreeSet<String> {
+        self.bonds
+            .values()
+            .flat_map(|bonds| bonds.keys())
+            .chain(self.unbonds.values().flat_map(|unbonds| unbonds.keys()))
+            .chain(
+                self.redelegations
+                    .values()
+                    .flat_map(|redelegations| redelegations.keys()),
+            )
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_delegators_of(&self, validator: &str) -> BTreeSet<Alias> {
+        self.bonds
+            .iter()
+            .filter(|(_, bonds)| bonds.contains_key(validator))
+            .map(|(source, _)| source)
+            .chain(
+                self.unbonds
+                    .iter()
+                    .filter(|(_, unbonds)| unbonds.contains_key(validator))
+                    .map(|(source, _)| source),
+            )
+            .chain(
+                self.redelegations
+                    .iter()
+                    .filter(|(_, redelegations)| redelegations.contains_key(validator))
+                    .map(|(source, _)| source),
+            )
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_processed_slashes(&self, validator: &str) -> u64 {
+        self.slashes.get(validator).cloned().unwrap_or_default()
+    }
+
+    pub fn get_activated_proposals(
+        &self,
+        current_epoch: Epoch,
+    ) -> Vec<(ProposalId, PendingProposal)> {
+        self.pending_proposals
+            .iter()
+            // the proposal is executed at the beginning of the activation epoch
+            .filter(|(_, proposal)| current_epoch >= proposal.activation_epoch)
+            .map(|(proposal_id, proposal)| (*proposal_id, proposal.clone()))
+            .collect()
+    }
+
+    pub fn random_steward(&self) -> Option<Account> {
+        self.stewards
+            .iter()
+            .choose(&mut WorkloadRng)
+            .map(|alias| self.get_account_by_alias(alias))
+    }
+
     pub fn random_votable_proposal(&self, current_epoch: u64) -> Option<u64> {
         self.proposals
             .iter()
@@ -458,7 +850,7 @@ impl State {
                     None
                 }
             })
-            .choose(&mut AntithesisRng)
+            .choose(&mut WorkloadRng)
     }
 
     // UPDATE
@@ -473,8 +865,9 @@ impl State {
                 address_type: AddressType::Implicit,
             },
         );
-        self.balances.insert(alias.clone(), 0);
-        self.masp_balances.insert(alias.clone(), 0);
+        self.balances.insert(alias.clone(), BTreeMap::default());
+        self.masp_balances
+            .insert(alias.clone(), BTreeMap::default());
     }
 
     pub fn add_established_account(
@@ -492,7 +885,7 @@ impl State {
                 address_type: AddressType::Established,
             },
         );
-        self.balances.insert(alias.clone(), 0);
+        self.balances.insert(alias.clone(), BTreeMap::default());
     }
 
     pub fn modify_established_account(
@@ -507,86 +900,106 @@ impl State {
         });
     }
 
-    pub fn increase_balance(&mut self, target: &Alias, amount: u64) {
+    pub fn increase_balance(&mut self, target: &Alias, denom: &str, amount: u64) {
         if target.is_faucet() {
             return;
         }
-        *self.balances.get_mut(target).unwrap() += amount;
-    }
-
-    pub fn increase_masp_balance(&mut self, target: &Alias, amount: u64) {
-        *self.masp_balances.get_mut(&target.base()).unwrap() += amount;
+        *self
+            .balances
+            .entry(target.clone())
+            .or_default()
+            .entry(denom.to_string())
+            .or_insert(0) += amount;
     }
 
-    pub fn increase_ibc_balance(&mut self, target: &Alias, denom: &str, amount: u64) {
-        if target.is_faucet() {
-            return;
-        }
-        let default = HashMap::from_iter([(denom.to_string(), 0)]);
-        if target.is_spending_key() || target.is_payment_address() {
-            *self
-                .ibc_masp_balances
-                .entry(target.base())
-                .or_insert(default)
-                .entry(denom.to_string())
-                .or_insert(0) += amount;
-        } else {
-            *self
-                .ibc_balances
-                .entry(target.clone())
-                .or_insert(default)
-                .entry(denom.to_string())
-                .or_insert(0) += amount;
-        }
+    pub fn increase_masp_balance(&mut self, target: &Alias, denom: &str, amount: u64) {
+        *self
+            .masp_balances
+            .entry(target.base())
+            .or_default()
+            .entry(denom.to_string())
+            .or_insert(0) += amount;
     }
 
     pub fn increase_foreign_balance(&mut self, target: &Alias, amount: u64) {
         *self.foreign_balances.entry(target.clone()).or_insert(0) += amount;
     }
 
-    pub fn decrease_balance(&mut self, target: &Alias, amount: u64) {
+    pub fn increase_ibc_balance(&mut self, target: &Alias, trace: &str, amount: u64) {
+        *self
+            .ibc_balances
+            .entry(target.clone())
+            .or_default()
+            .entry(trace.to_string())
+            .or_insert(0) += amount;
+    }
+
+    pub fn decrease_balance(&mut self, target: &Alias, denom: &str, amount: u64) {
         if target.is_faucet() {
             return;
         }
-        *self.balances.get_mut(target).unwrap() -= amount;
+        let balance = self
+            .balances
+            .entry(target.clone())
+            .or_default()
+            .entry(denom.to_string())
+            .or_default();
+        decrease_checked(balance, target, denom, amount);
     }
 
-    pub fn decrease_masp_balance(&mut self, target: &Alias, amount: u64) {
-        *self.masp_balances.get_mut(&target.base()).unwrap() -= amount;
+    pub fn decrease_masp_balance(&mut self, target: &Alias, denom: &str, amount: u64) {
+        let balance = self
+            .masp_balances
+            .entry(target.base())
+            .or_default()
+            .entry(denom.to_string())
+            .or_default();
+        decrease_checked(balance, target, denom, amount);
     }
 
-    pub fn decrease_ibc_balance(&mut self, target: &Alias, denom: &str, amount: u64) {
-        if target.is_faucet() {
-            return;
-        }
-        if target.is_spending_key() || target.is_payment_address() {
-            self.ibc_masp_balances
-                .entry(target.base())
-                .and_modify(|balance| *balance.get_mut(denom).unwrap() -= amount);
-        } else {
-            self.ibc_balances
-                .entry(target.clone())
-                .and_modify(|balance| *balance.get_mut(denom).unwrap() -= amount);
+    pub fn decrease_foreign_balance(&mut self, target: &Alias, amount: u64) {
+        let balance = self.foreign_balances.entry(target.clone()).or_default();
+        decrease_checked(balance, target, "foreign", amount);
+    }
+
+    pub fn add_nft(&mut self, owner: &Alias, trace: &str) {
+        self.nfts
+            .entry(owner.clone())
+            .or_default()
+            .insert(trace.to_string());
+    }
+
+    pub fn remove_nft(&mut self, owner: &Alias, trace: &str) {
+        if let Some(nfts) = self.nfts.get_mut(owner) {
+            nfts.remove(trace);
         }
     }
 
-    pub fn decrease_foreign_balance(&mut self, target: &Alias, amount: u64) {
-        *self.foreign_balances.get_mut(target).unwrap() -= amount;
+    pub fn add_foreign_nft(&mut self, owner: &Alias, token_id: &str) {
+        self.foreign_nfts
+            .entry(owner.clone())
+            .or_default()
+            .insert(token_id.to_string());
+    }
+
+    pub fn remove_foreign_nft(&mut self, owner: &Alias, token_id: &str) {
+        if let Some(nfts) = self.foreign_nfts.get_mut(owner) {
+            nfts.remove(token_id);
+        }
     }
 
     pub fn modify_balance_fee(&mut self, source: &Alias, fee: u64) {
+        let denom = Alias::nam().name;
         if source.is_spending_key() {
-            *self.masp_balances.get_mut(&source.base()).unwrap() -= fee;
-        } else if !source.is_faucet() {
-            *self.balances.get_mut(source).unwrap() -= fee;
+            self.decrease_masp_balance(source, &denom, fee);
+        } else {
+            self.decrease_balance(source, &denom, fee);
         }
     }
 
     pub fn modify_bond(&mut self, source: &Alias, validator: &str, amount: u64, epoch: Epoch) {
-        if !source.is_faucet() {
-            *self.balances.get_mut(source).unwrap() -= amount;
-        }
-        let default = HashMap::from_iter([(validator.to_string(), (0u64, 0u64))]);
+        self.decrease_balance(source, &Alias::nam().name, amount);
+        let default = BTreeMap::from_iter([(validator.to_string(), (0u64, 0u64))]);
         let bond = self
             .bonds
             .entry(source.clone())
@@ -595,10 +1008,18 @@ impl State {
             .or_insert((0, 0));
         bond.0 += amount;
         bond.1 = epoch;
+        self.modify_vote_stakes(source, validator, amount as i128, epoch);
     }
 
-    pub fn modify_redelegate(&mut self, source: &Alias, from: &str, to: &str, amount: u64) {
-        let default = HashMap::from_iter([(to.to_string(), 0u64)]);
+    pub fn modify_redelegate(
+        &mut self,
+        source: &Alias,
+        from: &str,
+        to: &str,
+        amount: u64,
+        epoch: Epoch,
+    ) {
+        let default = BTreeMap::from_iter([(to.to_string(), 0u64)]);
         *self
             .redelegations
             .entry(source.clone())
@@ -608,34 +1029,129 @@ impl State {
         self.bonds
             .entry(source.clone())
             .and_modify(|bond| bond.get_mut(from).unwrap().0 -= amount);
+        self.modify_vote_stakes(source, from, -(amount as i128), epoch);
+        self.modify_vote_stakes(source, to, amount as i128, epoch);
     }
 
-    pub fn modify_unbond(&mut self, source: &Alias, validator: &str, amount: u64) {
-        let default = HashMap::from_iter([(validator.to_string(), 0u64)]);
+    pub fn modify_unbond(
+        &mut self,
+        source: &Alias,
+        validator: &str,
+        amount: u64,
+        withdraw_epoch: Epoch,
+    ) {
         *self
             .unbonds
             .entry(source.clone())
-            .or_insert(default)
+            .or_default()
             .entry(validator.to_string())
+            .or_default()
+            .entry(withdraw_epoch)
             .or_insert(0) += amount;
         self.bonds
             .entry(source.clone())
             .and_modify(|bond| bond.get_mut(validator).unwrap().0 -= amount);
+        self.modify_vote_stakes(
+            source,
+            validator,
+            -(amount as i128),
+            withdraw_epoch - WITHDRAW_EPOCH_OFFSET,
+        );
     }
 
-    pub fn modify_shielding(&mut self, source: &Alias, target: &Alias, amount: u64) {
-        *self.balances.get_mut(source).unwrap() -= amount;
-        *self.masp_balances.get_mut(&target.base()).unwrap() += amount;
+    pub fn modify_withdraw(&mut self, source: &Alias, validator: &str, epoch: Epoch) {
+        let Some(unbonds) = self.unbonds.get_mut(source) else {
+            return;
+        };
+        let Some(chunks) = unbonds.get_mut(validator) else {
+            return;
+        };
+        // all chunks whose withdraw epoch has been reached are withdrawn at once
+        let remaining = chunks.split_off(&(epoch + 1));
+        let withdrawn = std::mem::replace(chunks, remaining).into_values().sum();
+        if chunks.is_empty() {
+            unbonds.remove(validator);
+        }
+        if unbonds.is_empty() {
+            self.unbonds.remove(source);
+        }
+        self.increase_balance(source, &Alias::nam().name, withdrawn);
     }
 
-    pub fn modify_unshielding(&mut self, source: &Alias, target: &Alias, amount: u64) {
-        *self.masp_balances.get_mut(&source.base()).unwrap() -= amount;
-        *self.balances.get_mut(target).unwrap() += amount;
+    /// Rescale the bond and the unbonds with the slashed amounts on chain
+    pub fn modify_slashed_bond(
+        &mut self,
+        source: &Alias,
+        validator: &str,
+        slashed_bond: u64,
+        slashed_unbonds: BTreeMap<Epoch, u64>,
+    ) {
+        let (own_bond, redelegated_bond) = self.get_bond_parts(source, validator);
+        let total_bond = own_bond + redelegated_bond;
+        if total_bond > 0 {
+            let slashed_own_bond =
+                (own_bond as u128 * slashed_bond as u128 / total_bond as u128) as u64;
+            if let Some(bond) = self
+                .bonds
+                .get_mut(source)
+                .and_then(|bonds| bonds.get_mut(validator))
+            {
+                bond.0 = slashed_own_bond;
+            }
+            if let Some(redelegation) = self
+                .redelegations
+                .get_mut(source)
+                .and_then(|redelegations| redelegations.get_mut(validator))
+            {
+                *redelegation = slashed_bond.saturating_sub(slashed_own_bond);
+            }
+        }
+
+        // the slashed stake at the end epoch depends on when the slash is processed
+        for record in self
+            .votes
+            .values_mut()
+            .filter_map(|votes| votes.get_mut(source))
+            .filter(|record| record.stakes.contains_key(validator))
+        {
+            record.is_stake_known = false;
+        }
+
+        if let Some(unbonds) = self.unbonds.get_mut(source) {
+            if slashed_unbonds.is_empty() {
+                unbonds.remove(validator);
+            } else {
+                unbonds.insert(validator.to_string(), slashed_unbonds);
+            }
+            if unbonds.is_empty() {
+                self.unbonds.remove(source);
+            }
+        }
+    }
+
+    pub fn set_processed_slashes(&mut self, validator: &str, num_slashes: u64) {
+        self.slashes.insert(validator.to_string(), num_slashes);
     }
 
-    pub fn modify_shielded_transfer(&mut self, source: &Alias, target: &Alias, amount: u64) {
-        *self.masp_balances.get_mut(&target.base()).unwrap() += amount;
-        *self.masp_balances.get_mut(&source.base()).unwrap() -= amount;
+    pub fn modify_shielding(&mut self, source: &Alias, target: &Alias, denom: &str, amount: u64) {
+        self.decrease_balance(source, denom, amount);
+        self.increase_masp_balance(target, denom, amount);
+    }
+
+    pub fn modify_unshielding(&mut self, source: &Alias, target: &Alias, denom: &str, amount: u64) {
+        self.decrease_masp_balance(source, denom, amount);
+        self.increase_balance(target, denom, amount);
+    }
+
+    pub fn modify_shielded_transfer(
+        &mut self,
+        source: &Alias,
+        target: &Alias,
+        denom: &str,
+        amount: u64,
+    ) {
+        self.increase_masp_balance(target, denom, amount);
+        self.decrease_masp_balance(source, denom, amount);
     }
 
     pub fn set_established_as_validator(&mut self, alias: &Alias) {
@@ -659,6 +1175,97 @@ impl State {
         self.proposals.extend(new_proposals);
     }
 
+    pub fn add_pending_proposal(&mut self, proposal_id: ProposalId, proposal: PendingProposal) {
+        self.pending_proposals.insert(proposal_id, proposal);
+    }
+
+    pub fn remove_pending_proposal(&mut self, proposal_id: ProposalId) {
+        self.pending_proposals.remove(&proposal_id);
+    }
+
+    pub fn add_vote(
+        &mut self,
+        proposal_id: ProposalId,
+        voter: &Alias,
+        vote: ProposalVote,
+        stakes: BTreeMap<String, u64>,
+    ) {
+        // the latest vote overwrites the previous one
+        self.votes.entry(proposal_id).or_default().insert(
+            voter.clone(),
+            VoteRecord {
+                vote,
+                stakes,
+                is_stake_known: true,
+            },
+        );
+    }
+
+    /// Apply the bond change requested at the epoch to the stakes of the voter.
+    /// The change lands at the pipeline epoch, or one epoch later when the
+    /// execution is at the next epoch.
+    fn modify_vote_stakes(&mut self, voter: &Alias, validator: &str, amount: i128, epoch: Epoch) {
+        let landing_epoch = epoch + PIPELINE_LEN;
+        for (proposal_id, votes) in self.votes.iter_mut() {
+            let Some(record) = votes.get_mut(voter) else {
+                continue;
+            };
+            let Some((_, end_epoch)) = self.proposals.get(proposal_id) else {
+                continue;
+            };
+            if landing_epoch < *end_epoch {
+                let stake = record.stakes.entry(validator.to_string()).or_default();
+                *stake = (*stake as i128 + amount).max(0) as u64;
+            } else if landing_epoch == *end_epoch {
+                record.is_stake_known = false;
+            }
+        }
+    }
+
+    pub fn remove_votes(&mut self, proposal_id: ProposalId) {
+        self.votes.remove(&proposal_id);
+    }
+
+    pub fn set_stewards(&mut self, stewards: BTreeSet<Alias>) {
+        self.stewards = stewards;
+    }
+
+    pub fn remove_steward(&mut self, steward: &Alias) {
+        self.stewards.remove(steward);
+    }
+
+    /// The continuous funding is paid from the activation epoch
+    pub fn add_pgf_funding(
+        &mut self,
+        proposal_id: ProposalId,
+        target: &Alias,
+        amount: u64,
+        activation_epoch: Epoch,
+    ) {
+        self.pgf_fundings.insert(
+            proposal_id,
+            ContinuousFunding {
+                target: target.clone(),
+                amount,
+                paid_epoch: activation_epoch.saturating_sub(1),
+            },
+        );
+    }
+
+    /// Credit the continuous PGF payments made until the epoch
+    pub fn credit_pgf_payments(&mut self, epoch: Epoch) {
+        for (target, amount) in self.get_pgf_payments(epoch) {
+            self.increase_balance(&target, &Alias::nam().name, amount);
+        }
+        for funding in self.pgf_fundings.values_mut() {
+            funding.paid_epoch = funding.paid_epoch.max(epoch);
+        }
+    }
+
+    pub fn set_masp_epoch(&mut self, alias: &Alias, masp_epoch: MaspEpoch) {
+        self.masp_epochs.insert(alias.base(), masp_epoch);
+    }
+
     pub fn set_claimed_epoch(&mut self, source: &Alias, epoch: Epoch) {
         let claimed_epoch = self.claimed_epochs.entry(source.clone()).or_insert(0);
         if epoch > *claimed_epoch {
@@ -666,7 +1273,135 @@ impl State {
         }
     }
 
-    pub fn overwrite_balance(&mut self, source: &Alias, balance: u64) {
-        *self.balances.get_mut(source).unwrap() = balance;
+    pub fn overwrite_balance(&mut self, source: &Alias, denom: &str, balance: u64) {
+        self.balances
+            .entry(source.clone())
+            .or_default()
+            .insert(denom.to_string(), balance);
+    }
+}
+
+/// The minimum balance to make transfers in a batch.
+/// Only NAM needs the fee in addition to the transfer amounts.
+fn min_transfer_balance(denom: &str) -> u64 {
+    if denom == Alias::nam().name {
+        MIN_TRANSFER_BALANCE
+    } else {
+        MAX_BATCH_TX_NUM
+    }
+}
+
+/// Decrease the balance in the state. The underflow means that the state
+/// drifted from the chain, so it's reported and the balance is zeroed.
+fn decrease_checked(balance: &mut u64, alias: &Alias, denom: &str, amount: u64) {
+    match balance.checked_sub(amount) {
+        Some(decreased) => *balance = decreased,
+        None => {
+            let details = json!({
+                "alias": alias,
+                "denom": denom,
+                "balance": *balance,
+                "amount": amount,
+            });
+            antithesis_sdk::assert_unreachable!("State balance underflowed", &details);
+            tracing::error!("State balance underflowed: {details}");
+            *balance = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_json() -> String {
+        let versioned_state = VersionedState {
+            version: STATE_VERSION,
+            state: State::new(1),
+        };
+        serde_json::to_string_pretty(&versioned_state).unwrap()
+    }
+
+    #[test]
+    fn test_decrease_balance() {
+        let mut state = State::new(1);
+        let alice = Alias::from("alice");
+        let nam = Alias::nam().name;
+        state.increase_balance(&alice, &nam, 100);
+
+        state.decrease_balance(&alice, &nam, 40);
+        assert_eq!(state.get_balance_for(&alice, &nam), 60);
+
+        // the underflow doesn't wrap
+        state.decrease_balance(&alice, &nam, 61);
+        assert_eq!(state.get_balance_for(&alice, &nam), 0);
+        state.decrease_masp_balance(&alice, &nam, 1);
+        assert_eq!(state.masp_balances[&alice][&nam], 0);
+        state.decrease_foreign_balance(&alice, 1);
+        assert_eq!(state.foreign_balances[&alice], 0);
+    }
+
+    #[test]
+    fn test_decode_current_state() {
+        let state = State::decode(&state_json()).unwrap();
+
+        assert_eq!(state.id, 1);
+    }
+
+    #[test]
+    fn test_truncated_state_is_recoverable() {
+        let data = state_json();
+        let err = State::decode(&data[..data.len() / 2]).unwrap_err();
+
+        assert!(err.is_truncated());
+        assert!(StateError::EmptyFile.is_truncated());
+    }
+
+    #[test]
+    fn test_pgf_payments() {
+        let mut state = State::new(1);
+        let alice = Alias::from("alice");
+        let nam = Alias::nam().name;
+        state.add_pgf_funding(1, &alice, 10, 5);
+        state.add_pgf_funding(2, &alice, 3, 7);
+
+        assert!(state.get_pgf_payments(4).is_empty());
+        // paid at the activation epoch
+        assert_eq!(
+            state.get_pgf_payments(5),
+            BTreeMap::from([(alice.clone(), 10)])
+        );
+        assert_eq!(
+            state.get_pgf_payments(7),
+            BTreeMap::from([(alice.clone(), 33)])
+        );
+
+        state.credit_pgf_payments(6);
+        assert_eq!(state.get_balance_for(&alice, &nam), 20);
+        assert_eq!(state.get_pgf_payments(6), BTreeMap::new());
+        assert_eq!(
+            state.get_pgf_payments(8),
+            BTreeMap::from([(alice.clone(), 26)])
+        );
+
+        // the funding found after the activation is paid from the activation epoch
+        state.add_pgf_funding(3, &alice, 100, 2);
+        state.credit_pgf_payments(8);
+        assert_eq!(state.get_balance_for(&alice, &nam), 20 + 26 + 700);
+    }
+
+    #[test]
+    fn test_invalid_state_isnt_recoverable() {
+        let err = State::decode(r#"{"version": 1, "state": {"id": "one"}}"#).unwrap_err();
+        assert!(matches!(err, StateError::Serde(_)));
+        assert!(!err.is_truncated());
+
+        let err = State::decode(r#"{"version": 99, "state": {}}"#).unwrap_err();
+        assert!(matches!(err, StateError::UnknownVersion(99)));
+        assert!(!err.is_truncated());
+
+        let err = State::decode(r#"{"accounts": {}}"#).unwrap_err();
+        assert!(matches!(err, StateError::Migration(0, _)));
+        assert!(!err.is_truncated());
     }
 }
@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use super::{State, StateError};
+use crate::types::{Alias, Epoch};
+
+type Migration = fn(Value) -> Result<Value, StateError>;
+
+/// Migrations of the state format. The migration at index `i` converts the
+/// state of version `i` to version `i + 1`.
+const MIGRATIONS: &[Migration] = &[migrate_v0_to_v1, migrate_v1_to_v2];
+
+/// The current version of the state format. Add a migration to bump it when
+/// the format of `State` changes.
+pub const STATE_VERSION: u64 = MIGRATIONS.len() as u64;
+
+/// Convert the state of the given version to the current version
+pub fn migrate(mut state: Value, version: u64) -> Result<Value, StateError> {
+    if version > STATE_VERSION {
+        return Err(StateError::UnknownVersion(version));
+    }
+
+    for (from, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        tracing::info!("Migrating the state from version {from} to {}", from + 1);
+        state = migration(state)?;
+    }
+
+    Ok(state)
+}
+
+/// Version 0 is the state without the envelope.
+/// - The NAM balances are nested under the NAM denom, and the IBC token
+///   balances are folded into the same per-denom balances.
+/// - The unbonds are split into the chunks by the withdraw epoch. The legacy
+///   state didn't record the epoch, so the unbond is put at epoch 0 to be
+///   withdrawable.
+/// - The fields added over time are filled with the empty default.
+fn migrate_v0_to_v1(mut state: Value) -> Result<Value, StateError> {
+    let id = state
+        .get("id")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| StateError::Migration(0, "the state ID is missing".to_string()))?;
+    let Some(fields) = state.as_object_mut() else {
+        return Err(StateError::Migration(
+            0,
+            "the state is not an object".to_string(),
+        ));
+    };
+
+    for (key, ibc_key) in [
+        ("balances", "ibc_balances"),
+        ("masp_balances", "ibc_masp_balances"),
+    ] {
+        let nam_balances: BTreeMap<Alias, u64> = take_field(fields, key, 0)?;
+        let ibc_balances: BTreeMap<Alias, BTreeMap<String, u64>> = take_field(fields, ibc_key, 0)?;
+
+        let mut balances: BTreeMap<Alias, BTreeMap<String, u64>> = nam_balances
+            .into_iter()
+            .map(|(alias, amount)| (alias, BTreeMap::from([(Alias::nam().name, amount)])))
+            .collect();
+        for (alias, denoms) in ibc_balances {
+            balances.entry(alias).or_default().extend(denoms);
+        }
+        fields.insert(key.to_string(), to_value(balances)?);
+    }
+
+    let unbonds: BTreeMap<Alias, BTreeMap<String, u64>> = take_field(fields, "unbonds", 0)?;
+    let unbonds: BTreeMap<Alias, BTreeMap<String, BTreeMap<Epoch, u64>>> = unbonds
+        .into_iter()
+        .filter_map(|(alias, unbonds)| {
+            let chunks: BTreeMap<_, _> = unbonds
+                .into_iter()
+                .filter(|(_, amount)| *amount > 0)
+                .map(|(validator, amount)| (validator, BTreeMap::from([(0, amount)])))
+                .collect();
+            (!chunks.is_empty()).then_some((alias, chunks))
+        })
+        .collect();
+    fields.insert("unbonds".to_string(), to_value(unbonds)?);
+
+    let Value::Object(default) = to_value(State::new(id))? else {
+        unreachable!("State should be an object")
+    };
+    for (key, value) in default {
+        fields.entry(key).or_insert(value);
+    }
+
+    Ok(state)
+}
+
+/// Version 1 tracked only the continuously funded accounts, whose balances
+/// were overwritten from the chain. The funding amounts can't be recovered, so
+/// such a state can't be migrated.
+fn migrate_v1_to_v2(mut state: Value) -> Result<Value, StateError> {
+    let Some(fields) = state.as_object_mut() else {
+        return Err(StateError::Migration(
+            1,
+            "the state is not an object".to_string(),
+        ));
+    };
+
+    let funded_accounts: Vec<Alias> = take_field(fields, "pgf_funded_accounts", 1)?;
+    if !funded_accounts.is_empty() {
+        return Err(StateError::Migration(
+            1,
+            "the amounts of the continuous PGF fundings are unknown".to_string(),
+        ));
+    }
+    fields
+        .entry("pgf_fundings")
+        .or_insert_with(|| Value::Object(Map::new()));
+
+    Ok(state)
+}
+
+/// Remove the field from the state of the version. The missing field is empty.
+fn take_field<T: DeserializeOwned + Default>(
+    fields: &mut Map<String, Value>,
+    key: &str,
+    version: u64,
+) -> Result<T, StateError> {
+    match fields.remove(key) {
+        Some(value) => serde_json::from_value(value)
+            .map_err(|e| StateError::Migration(version, format!("invalid `{key}`: {e}"))),
+        None => Ok(T::default()),
+    }
+}
+
+fn to_value<T: serde::Serialize>(value: T) -> Result<Value, StateError> {
+    serde_json::to_value(value).map_err(StateError::Serde)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATE_V0: &str = include_str!("../../tests/fixtures/state-v0.json");
+
+    fn migrated_v0() -> State {
+        let state = serde_json::from_str(STATE_V0).expect("fixture should be JSON");
+        let state = migrate(state, 0).expect("migration should succeed");
+        serde_json::from_value(state).expect("migrated state should be decoded")
+    }
+
+    #[test]
+    fn test_migrate_v0_balances() {
+        let state = migrated_v0();
+        let nam = Alias::nam().name;
+        let ibc_denom = "transfer/channel-0/samoleans".to_string();
+
+        assert_eq!(
+            state.balances[&Alias::from("alice")],
+            BTreeMap::from([(nam.clone(), 1_000_000), (ibc_denom.clone(), 30)])
+        );
+        assert_eq!(
+            state.balances[&Alias::from("bob")],
+            BTreeMap::from([(nam.clone(), 2_000)])
+        );
+        // the IBC token balance without the NAM balance
+        assert_eq!(
+            state.balances[&Alias::from("carol")],
+            BTreeMap::from([(ibc_denom.clone(), 5)])
+        );
+        assert_eq!(
+            state.masp_balances[&Alias::from("alice")],
+            BTreeMap::from([(nam, 500), (ibc_denom, 7)])
+        );
+        // the legacy IBC balances aren't the balances on Cosmos
+        assert!(state.ibc_balances.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_v0_unbonds() {
+        let state = migrated_v0();
+
+        assert_eq!(
+            state.unbonds[&Alias::from("bob")],
+            BTreeMap::from([("tnam1validator".to_string(), BTreeMap::from([(0, 300)]))])
+        );
+        // the empty unbond isn't a chunk
+        assert!(!state.unbonds.contains_key(&Alias::from("alice")));
+        assert_eq!(state.bonds[&Alias::from("bob")]["tnam1validator"], (700, 3));
+    }
+
+    #[test]
+    fn test_migrate_v0_fills_new_fields() {
+        let state = migrated_v0();
+
+        assert_eq!(state.id, 7);
+        assert_eq!(state.accounts.len(), 3);
+        assert_eq!(state.proposals[&1], (10, 20));
+        assert_eq!(state.stats["bond"], 4);
+        assert!(state.votes.is_empty());
+        assert!(state.pending_proposals.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_current_version() {
+        let state = to_value(State::new(1)).unwrap();
+
+        assert_eq!(migrate(state.clone(), STATE_VERSION).unwrap(), state);
+        assert!(matches!(
+            migrate(state, STATE_VERSION + 1),
+            Err(StateError::UnknownVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_migrate_v1_pgf_fundings() {
+        let mut state = to_value(State::new(1)).unwrap();
+        let fields = state.as_object_mut().unwrap();
+        fields.remove("pgf_fundings");
+        fields.insert("pgf_funded_accounts".to_string(), Value::Array(vec![]));
+
+        let migrated = migrate(state.clone(), 1).unwrap();
+        assert!(migrated.get("pgf_funded_accounts").is_none());
+        let migrated: State = serde_json::from_value(migrated).unwrap();
+        assert!(migrated.pgf_fundings.is_empty());
+
+        // the funding amounts of the funded accounts are unknown
+        state["pgf_funded_accounts"] = serde_json::json!(["alice"]);
+        assert!(matches!(
+            migrate(state, 1),
+            Err(StateError::Migration(1, _))
+        ));
+    }
+
+    #[test]
+    fn test_migrate_v0_invalid_field() {
+        let mut state: Value = serde_json::from_str(STATE_V0).unwrap();
+        state["balances"] = Value::String("invalid".to_string());
+
+        assert!(matches!(
+            migrate(state, 0),
+            Err(StateError::Migration(0, _))
+        ));
+    }
+}
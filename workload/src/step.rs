@@ -12,27 +12,37 @@ use crate::task::Task;
 mod batch;
 mod become_validator;
 mod bond;
+mod change_commission;
 mod change_consensus_key;
 mod change_metadata;
+mod claim_masp_rewards;
 mod claim_rewards;
+mod concurrent;
 mod deactivate_validator;
 mod default_proposal;
 mod faucet_transfer;
 mod fund_all;
+mod ibc_nft_transfer;
 mod ibc_transfer;
 mod init_account;
 mod initialize;
 mod new_wallet_keypair;
+mod pgf_proposal;
 mod reactivate_validator;
 mod redelegate;
+mod resign_steward;
+mod shielded_fee_payment;
 mod shielded_transfer;
 mod shielding;
 mod transparent_transfer;
 mod unbond;
+mod unjail_validator;
 mod unshielding;
 mod update_account;
+mod update_steward_commission;
 mod utils;
 mod vote;
+mod withdraw;
 
 #[enum_dispatch]
 #[derive(Clone, Debug)]
@@ -44,26 +54,43 @@ pub enum StepType {
     TransparentTransfer(transparent_transfer::TransparentTransfer),
     Shielding(shielding::Shielding),
     Shielded(shielded_transfer::ShieldedTransfer),
+    ShieldedFeePayment(shielded_fee_payment::ShieldedFeePayment),
     Unshielding(unshielding::Unshielding),
     IbcTransferSend(ibc_transfer::IbcTransferSend),
     IbcTransferRecv(ibc_transfer::IbcTransferRecv),
     IbcShieldingTransfer(ibc_transfer::IbcShieldingTransfer),
     IbcUnshieldingTransfer(ibc_transfer::IbcUnshieldingTransfer),
+    IbcTransferTimeout(ibc_transfer::IbcTransferTimeout),
+    IbcShieldedRoundTrip(ibc_transfer::IbcShieldedRoundTrip),
+    IbcTransferOverLimit(ibc_transfer::IbcTransferOverLimit),
+    IbcTransferForward(ibc_transfer::IbcTransferForward),
+    IbcNftTransferSend(ibc_nft_transfer::IbcNftTransferSend),
+    IbcNftTransferRecv(ibc_nft_transfer::IbcNftTransferRecv),
     Bond(bond::Bond),
     Unbond(unbond::Unbond),
+    Withdraw(withdraw::Withdraw),
     Redelegate(redelegate::Redelegate),
     ClaimRewards(claim_rewards::ClaimRewards),
+    ClaimMaspRewards(claim_masp_rewards::ClaimMaspRewards),
     InitAccount(init_account::InitAccount),
     UpdateAccount(update_account::UpdateAccount),
+    UpdateStewardCommission(update_steward_commission::UpdateStewardCommission),
+    ResignSteward(resign_steward::ResignSteward),
     BecomeValidator(become_validator::BecomeValidator),
     DeactivateValidator(deactivate_validator::DeactivateValidator),
     ReactivateValidator(reactivate_validator::ReactivateValidator),
+    UnjailValidator(unjail_validator::UnjailValidator),
     ChangeMetadata(change_metadata::ChangeMetadata),
+    ChangeCommission(change_commission::ChangeCommission),
     ChangeConsensusKey(change_consensus_key::ChangeConsensusKey),
     DefaultProposal(default_proposal::DefaultProposal),
+    WasmProposal(default_proposal::WasmProposal),
+    PgfFundingProposal(pgf_proposal::PgfFundingProposal),
+    PgfStewardProposal(pgf_proposal::PgfStewardProposal),
     Vote(vote::Vote),
     BatchBond(batch::BatchBond),
     BatchRandom(batch::BatchRandom),
+    ConcurrentRandom(concurrent::ConcurrentRandom),
 }
 
 impl FromStr for StepType {
@@ -78,26 +105,43 @@ impl FromStr for StepType {
             "transparent-transfer" => Self::TransparentTransfer(Default::default()),
             "shielding" => Self::Shielding(Default::default()),
             "shielded" => Self::Shielded(Default::default()),
+            "shielded-fee-payment" => Self::ShieldedFeePayment(Default::default()),
             "unshielding" => Self::Unshielding(Default::default()),
             "ibc-transfer-send" => Self::IbcTransferSend(Default::default()),
             "ibc-transfer-recv" => Self::IbcTransferRecv(Default::default()),
             "ibc-shielding-transfer" => Self::IbcShieldingTransfer(Default::default()),
             "ibc-unshielding-transfer" => Self::IbcUnshieldingTransfer(Default::default()),
+            "ibc-transfer-timeout" => Self::IbcTransferTimeout(Default::default()),
+            "ibc-shielded-round-trip" => Self::IbcShieldedRoundTrip(Default::default()),
+            "ibc-transfer-over-limit" => Self::IbcTransferOverLimit(Default::default()),
+            "ibc-transfer-forward" => Self::IbcTransferForward(Default::default()),
+            "ibc-nft-transfer-send" => Self::IbcNftTransferSend(Default::default()),
+            "ibc-nft-transfer-recv" => Self::IbcNftTransferRecv(Default::default()),
             "bond" => Self::Bond(Default::default()),
             "unbond" => Self::Unbond(Default::default()),
+            "withdraw" => Self::Withdraw(Default::default()),
             "redelegate" => Self::Redelegate(Default::default()),
             "claim-rewards" => Self::ClaimRewards(Default::default()),
+            "claim-masp-rewards" => Self::ClaimMaspRewards(Default::default()),
             "init-account" => Self::InitAccount(Default::default()),
             "update-account" => Self::UpdateAccount(Default::default()),
+            "update-steward-commission" => Self::UpdateStewardCommission(Default::default()),
+            "resign-steward" => Self::ResignSteward(Default::default()),
             "become-validator" => Self::BecomeValidator(Default::default()),
             "deactivate-validator" => Self::DeactivateValidator(Default::default()),
             "reactivate-validator" => Self::ReactivateValidator(Default::default()),
+            "unjail-validator" => Self::UnjailValidator(Default::default()),
             "change-metadata" => Self::ChangeMetadata(Default::default()),
+            "change-commission" => Self::ChangeCommission(Default::default()),
             "change-consensus-key" => Self::ChangeConsensusKey(Default::default()),
             "default-proposal" => Self::DefaultProposal(Default::default()),
+            "wasm-proposal" => Self::WasmProposal(Default::default()),
+            "pgf-funding-proposal" => Self::PgfFundingProposal(Default::default()),
+            "pgf-steward-proposal" => Self::PgfStewardProposal(Default::default()),
             "vote" => Self::Vote(Default::default()),
             "batch-bond" => Self::BatchBond(Default::default()),
             "batch-random" => Self::BatchRandom(Default::default()),
+            "concurrent-random" => Self::ConcurrentRandom(Default::default()),
             _ => return Err(format!("Invalid step type was given: {step}")),
         };
 
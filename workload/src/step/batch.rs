@@ -1,6 +1,5 @@
 use std::collections::HashSet;
 
-use antithesis_sdk::random::AntithesisRng;
 use rand::seq::SliceRandom;
 
 use crate::code::{Code, CodeType};
@@ -10,7 +9,8 @@ use crate::context::Ctx;
 use crate::error::{StepError, TaskError};
 use crate::state::State;
 use crate::step::{StepContext, StepType};
-use crate::task::{self, Task, TaskSettings};
+use crate::task::{self, Task, TaskContext, TaskSettings};
+use crate::utils::WorkloadRng;
 use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
 
 #[derive(Clone, Debug, Default)]
@@ -40,6 +40,7 @@ impl StepContext for BatchBond {
             CodeType::Success => assert_always_step!("Done BatchBond", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal BatchBond", code),
             CodeType::Skip => assert_sometimes_step!("Skipped BatchBond", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected BatchBond", code),
             CodeType::Failed => assert_unreachable_step!("Failed BatchBond", code),
         }
     }
@@ -79,6 +80,7 @@ impl StepContext for BatchRandom {
             CodeType::Success => assert_always_step!("Done BatchRandom", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal BatchRandom", code),
             CodeType::Skip => assert_sometimes_step!("Skipped BatchRandom", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected BatchRandom", code),
             CodeType::Failed
                 if matches!(
                     code,
@@ -101,7 +103,7 @@ async fn build_batch(
     let mut batch_tasks = vec![];
     for _ in 0..max_size {
         let step = possibilities
-            .choose(&mut AntithesisRng)
+            .choose(&mut WorkloadRng)
             .expect("at least one StepType should exist");
         let tasks = step.build_task(ctx, state).await.unwrap_or_default();
         if !tasks.is_empty() {
@@ -115,6 +117,13 @@ async fn build_batch(
     let batch_tasks: Vec<Task> = batch_tasks
         .into_iter()
         .filter(|task| {
+            // the rejection of a task would revert the whole batch
+            if task
+                .task_settings()
+                .is_some_and(|settings| settings.is_below_threshold)
+            {
+                return false;
+            }
             match task {
                 // if the shielded source has been already used,
                 // remove the task to avoid spending the same masp note
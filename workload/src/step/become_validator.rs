@@ -62,6 +62,7 @@ impl StepContext for BecomeValidator {
             CodeType::Success => assert_always_step!("Done BecomeValidator", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal BecomeValidator", code),
             CodeType::Skip => assert_sometimes_step!("Skipped BecomeValidator", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected BecomeValidator", code),
             CodeType::Failed => assert_unreachable_step!("Failed BecomeValidator", code),
         }
     }
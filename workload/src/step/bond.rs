@@ -1,4 +1,3 @@
-use antithesis_sdk::random::AntithesisRng;
 use rand::seq::IteratorRandom;
 
 use crate::code::{Code, CodeType};
@@ -8,7 +7,8 @@ use crate::error::StepError;
 use crate::state::State;
 use crate::step::StepContext;
 use crate::task::{self, Task, TaskSettings};
-use crate::utils::{get_epoch, get_validator_addresses, retry_config};
+use crate::types::Alias;
+use crate::utils::{get_epoch, get_validator_addresses, retry_config, WorkloadRng};
 use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
 
 use super::utils;
@@ -29,7 +29,7 @@ impl StepContext for Bond {
         let source_account = state
             .random_account_with_min_balance(vec![], MIN_TRANSFER_BALANCE)
             .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
-        let amount_account = state.get_balance_for(&source_account.alias);
+        let amount_account = state.get_balance_for(&source_account.alias, &Alias::nam().name);
         let amount = utils::random_between(1, amount_account / MAX_BATCH_TX_NUM);
 
         let current_epoch = get_epoch(ctx, retry_config()).await?;
@@ -37,11 +37,13 @@ impl StepContext for Bond {
 
         let validator = validators
             .iter()
-            .choose(&mut AntithesisRng)
+            .choose(&mut WorkloadRng)
             .expect("There is always at least a validator");
 
+        let (signers, is_below_threshold) = utils::get_random_signers(&source_account);
         let gas_payer = utils::get_gas_payer(source_account.public_keys.iter(), state);
-        let task_settings = TaskSettings::new(source_account.public_keys, gas_payer);
+        let mut task_settings = TaskSettings::new(signers, gas_payer);
+        task_settings.is_below_threshold = is_below_threshold;
 
         Ok(vec![Task::Bond(
             task::bond::Bond::builder()
@@ -59,6 +61,7 @@ impl StepContext for Bond {
             CodeType::Success => assert_always_step!("Done Bond", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal Bond", code),
             CodeType::Skip => assert_sometimes_step!("Skipped Bond", code),
+            CodeType::Rejected => assert_sometimes_step!("Rejected Bond", code),
             CodeType::Failed => assert_unreachable_step!("Failed Bond", code),
         }
     }
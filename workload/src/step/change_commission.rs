@@ -0,0 +1,96 @@
+use namada_sdk::dec::Dec;
+use rand::seq::IteratorRandom;
+
+use crate::code::{Code, CodeType};
+use crate::constants::PIPELINE_LEN;
+use crate::context::Ctx;
+use crate::error::StepError;
+use crate::state::State;
+use crate::step::StepContext;
+use crate::task::{self, Task, TaskSettings};
+use crate::utils::{get_epoch, get_validator_commission, retry_config, WorkloadRng};
+use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
+
+use super::utils;
+
+#[derive(Clone, Debug, Default)]
+pub struct ChangeCommission;
+
+impl StepContext for ChangeCommission {
+    fn name(&self) -> String {
+        "change-commission".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.min_n_validators(1))
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let Some(account) = state.random_validator(vec![], 1).pop() else {
+            return Ok(vec![]);
+        };
+
+        let epoch = get_epoch(ctx, retry_config()).await?;
+        // the change is validated against the rate just before the pipeline epoch
+        let (_, commission) = get_validator_commission(
+            ctx,
+            &account.alias,
+            epoch + PIPELINE_LEN - 1,
+            retry_config(),
+        )
+        .await?;
+        let (_, pipeline_commission) =
+            get_validator_commission(ctx, &account.alias, epoch + PIPELINE_LEN, retry_config())
+                .await?;
+        let (Some((rate, max_change)), Some((pipeline_rate, _))) =
+            (commission, pipeline_commission)
+        else {
+            return Ok(vec![]);
+        };
+        // the reference rate could move when the execution crosses the epoch boundary
+        // while another change is pending
+        if rate != pipeline_rate {
+            return Ok(vec![]);
+        }
+
+        let within_max_change = utils::coin_flip(0.5);
+        let change = if within_max_change {
+            let ratio = Dec::new(utils::random_between::<u64>(0, 100) as i128, 2).unwrap();
+            max_change * ratio
+        } else {
+            let excess = Dec::new(utils::random_between::<u64>(1, 10) as i128, 2).unwrap();
+            max_change + excess
+        };
+        let Some(new_rate) = [rate.checked_add(change), rate.checked_sub(change)]
+            .into_iter()
+            .flatten()
+            .filter(|new_rate| *new_rate >= Dec::zero() && *new_rate <= Dec::one())
+            .choose(&mut WorkloadRng)
+        else {
+            return Ok(vec![]);
+        };
+
+        let gas_payer = utils::get_gas_payer(account.public_keys.iter(), state);
+        let task_settings = TaskSettings::new(account.public_keys, gas_payer);
+
+        Ok(vec![Task::ChangeCommission(
+            task::change_commission::ChangeCommission::builder()
+                .source(account.alias)
+                .rate(new_rate)
+                .pre_rate(rate)
+                .within_max_change(within_max_change)
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done ChangeCommission", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal ChangeCommission", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped ChangeCommission", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected ChangeCommission", code),
+            CodeType::Failed => assert_unreachable_step!("Failed ChangeCommission", code),
+        }
+    }
+}
@@ -43,6 +43,7 @@ impl StepContext for ChangeConsensusKey {
             CodeType::Success => assert_always_step!("Done ChangeConsensusKey", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal ChangeConsensusKey", code),
             CodeType::Skip => assert_sometimes_step!("Skipped ChangeConsensusKey", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected ChangeConsensusKey", code),
             CodeType::Failed => assert_unreachable_step!("Failed ChangeConsensusKey", code),
         }
     }
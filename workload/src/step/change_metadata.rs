@@ -50,6 +50,7 @@ impl StepContext for ChangeMetadata {
             CodeType::Success => assert_always_step!("Done ChangeMetadata", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal ChangeMetadata", code),
             CodeType::Skip => assert_sometimes_step!("Skipped ChangeMetadata", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected ChangeMetadata", code),
             CodeType::Failed => assert_unreachable_step!("Failed ChangeMetadata", code),
         }
     }
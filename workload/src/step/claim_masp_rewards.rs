@@ -0,0 +1,77 @@
+use std::collections::BTreeSet;
+
+use crate::code::{Code, CodeType};
+use crate::constants::DEFAULT_FEE;
+use crate::context::Ctx;
+use crate::error::{StepError, TaskError};
+use crate::state::State;
+use crate::step::StepContext;
+use crate::task::{self, Task, TaskSettings};
+use crate::types::Alias;
+use crate::utils::{get_masp_epoch, retry_config};
+use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
+
+#[derive(Clone, Debug, Default)]
+pub struct ClaimMaspRewards;
+
+impl StepContext for ClaimMaspRewards {
+    fn name(&self) -> String {
+        "claim-masp-rewards".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.any_masp_account_with_min_balance(DEFAULT_FEE + 1))
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let source_account = state
+            .random_masp_account_with_min_balance(vec![], DEFAULT_FEE + 1)
+            .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
+
+        let epoch = get_masp_epoch(ctx, retry_config()).await?;
+
+        let native_denom = Alias::nam().name;
+        let transparent_source_balance =
+            state.get_balance_for(&source_account.alias, &native_denom);
+        let shielded_source_balance =
+            state.get_shielded_balance_for(&source_account.alias, &native_denom);
+        // All the notes should be spent to apply the conversions
+        let (amount, gas_payer) = if transparent_source_balance >= DEFAULT_FEE {
+            (shielded_source_balance, source_account.alias.clone())
+        } else {
+            (
+                shielded_source_balance - DEFAULT_FEE,
+                source_account.alias.spending_key(),
+            )
+        };
+        let task_settings =
+            TaskSettings::new(BTreeSet::from([source_account.alias.clone()]), gas_payer);
+
+        Ok(vec![Task::ClaimMaspRewards(
+            task::claim_masp_rewards::ClaimMaspRewards::builder()
+                .source(source_account.alias)
+                .amount(amount)
+                .epoch(epoch)
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done ClaimMaspRewards", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal ClaimMaspRewards", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped ClaimMaspRewards", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected ClaimMaspRewards", code),
+            CodeType::Failed
+                if matches!(
+                    code,
+                    Code::TaskFailure(_, TaskError::InvalidShielded { .. })
+                ) =>
+            {
+                assert_sometimes_step!("Invalid ClaimMaspRewards", code)
+            }
+            _ => assert_unreachable_step!("Failed ClaimMaspRewards", code),
+        }
+    }
+}
@@ -68,6 +68,7 @@ impl StepContext for ClaimRewards {
             CodeType::Success => assert_always_step!("Done ClaimRewards", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal ClaimRewards", code),
             CodeType::Skip => assert_sometimes_step!("Skipped ClaimRewards", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected ClaimRewards", code),
             CodeType::Failed => assert_unreachable_step!("Failed ClaimRewards", code),
         }
     }
@@ -0,0 +1,85 @@
+use std::collections::BTreeSet;
+
+use rand::seq::SliceRandom;
+
+use crate::code::{Code, CodeType};
+use crate::constants::{MAX_CONCURRENT_TX_NUM, MIN_TRANSFER_BALANCE};
+use crate::context::Ctx;
+use crate::error::StepError;
+use crate::state::State;
+use crate::step::{StepContext, StepType};
+use crate::task::{Task, TaskContext};
+use crate::utils::WorkloadRng;
+use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
+
+/// Independent tasks which are submitted concurrently with `--concurrent`
+#[derive(Clone, Debug, Default)]
+pub struct ConcurrentRandom;
+
+impl StepContext for ConcurrentRandom {
+    fn name(&self) -> String {
+        "concurrent-random".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.min_n_account_with_min_balance(3, MIN_TRANSFER_BALANCE) && state.min_bonds(3))
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        // The tasks only change the state of their source
+        let possibilities = [
+            StepType::Bond(Default::default()),
+            StepType::Unbond(Default::default()),
+            StepType::Withdraw(Default::default()),
+            StepType::ClaimRewards(Default::default()),
+        ];
+
+        let mut used_accounts = BTreeSet::new();
+        let mut tasks = vec![];
+        for _ in 0..MAX_CONCURRENT_TX_NUM {
+            let step = possibilities
+                .choose(&mut WorkloadRng)
+                .expect("at least one StepType should exist");
+            for task in Box::pin(step.build_task(ctx, state))
+                .await
+                .unwrap_or_default()
+            {
+                // the rejection isn't the target of the concurrent execution
+                let Some(settings) = task.task_settings() else {
+                    continue;
+                };
+                if settings.is_below_threshold {
+                    continue;
+                }
+                let source = match &task {
+                    Task::Bond(inner) => inner.source(),
+                    Task::Unbond(inner) => inner.source(),
+                    Task::Withdraw(inner) => inner.source(),
+                    Task::ClaimRewards(inner) => inner.source(),
+                    _ => continue,
+                };
+                let mut accounts = task.accounts().unwrap_or_default();
+                accounts.insert(source.clone());
+                // the tasks should be independent to be executed concurrently
+                if !used_accounts.is_disjoint(&accounts) {
+                    continue;
+                }
+                tracing::info!("Added {step} to the concurrent tasks...");
+                used_accounts.extend(accounts);
+                tasks.push(task);
+            }
+        }
+
+        Ok(tasks)
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done ConcurrentRandom", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal ConcurrentRandom", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped ConcurrentRandom", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected ConcurrentRandom", code),
+            CodeType::Failed => assert_unreachable_step!("Failed ConcurrentRandom", code),
+        }
+    }
+}
@@ -43,6 +43,7 @@ impl StepContext for DeactivateValidator {
             CodeType::Success => assert_always_step!("Done DeactivateValidator", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal DeactivateValidator", code),
             CodeType::Skip => assert_sometimes_step!("Skipped DeactivateValidator", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected DeactivateValidator", code),
             CodeType::Failed => assert_unreachable_step!("Failed DeactivateValidator", code),
         }
     }
@@ -7,6 +7,7 @@ use crate::error::StepError;
 use crate::state::State;
 use crate::step::StepContext;
 use crate::task::{self, Task, TaskSettings};
+use crate::types::Epoch;
 use crate::utils::{get_epoch, retry_config};
 use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
 
@@ -25,27 +26,11 @@ impl StepContext for DefaultProposal {
     }
 
     async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
-        let client = &ctx.namada.client;
         let source_account = state
             .random_account_with_min_balance(vec![], PROPOSAL_DEPOSIT)
             .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
 
-        let current_epoch = get_epoch(ctx, retry_config()).await?;
-
-        let gov_prams = rpc::query_governance_parameters(client).await;
-
-        let start_epoch = utils::random_between(
-            current_epoch + 2,
-            current_epoch + gov_prams.max_proposal_latency,
-        );
-        let end_epoch = utils::random_between(
-            start_epoch + gov_prams.min_proposal_voting_period,
-            start_epoch + gov_prams.max_proposal_period - 5,
-        );
-        let grace_epoch = utils::random_between(
-            end_epoch + gov_prams.min_proposal_grace_epochs,
-            end_epoch + 5,
-        );
+        let (start_epoch, end_epoch, grace_epoch) = random_proposal_epochs(ctx).await?;
 
         let gas_payer = utils::get_gas_payer(source_account.public_keys.iter(), state);
         let task_settings = TaskSettings::new(source_account.public_keys, gas_payer);
@@ -66,7 +51,81 @@ impl StepContext for DefaultProposal {
             CodeType::Success => assert_always_step!("Done DefaultProposal", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal DefaultProposal", code),
             CodeType::Skip => assert_sometimes_step!("Skipped DefaultProposal", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected DefaultProposal", code),
             CodeType::Failed => assert_unreachable_step!("Failed DefaultProposal", code),
         }
     }
 }
+
+#[derive(Clone, Debug, Default)]
+pub struct WasmProposal;
+
+impl StepContext for WasmProposal {
+    fn name(&self) -> String {
+        "wasm-proposal".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.any_account_with_min_balance(PROPOSAL_DEPOSIT))
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let source_account = state
+            .random_account_with_min_balance(vec![], PROPOSAL_DEPOSIT)
+            .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
+
+        let (start_epoch, end_epoch, grace_epoch) = random_proposal_epochs(ctx).await?;
+
+        let gas_payer = utils::get_gas_payer(source_account.public_keys.iter(), state);
+        let mut task_settings = TaskSettings::new(source_account.public_keys, gas_payer);
+        // the proposal tx includes the wasm code
+        task_settings.gas_limit *= 2;
+
+        Ok(vec![Task::DefaultProposal(
+            task::default_proposal::DefaultProposal::builder()
+                .source(source_account.alias)
+                .start_epoch(start_epoch)
+                .end_epoch(end_epoch)
+                .grace_epoch(grace_epoch)
+                .wasm_key(Some(format!(
+                    "workload/proposal/{}",
+                    utils::get_random_string(16)
+                )))
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done WasmProposal", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal WasmProposal", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped WasmProposal", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected WasmProposal", code),
+            CodeType::Failed => assert_unreachable_step!("Failed WasmProposal", code),
+        }
+    }
+}
+
+/// Random voting start, voting end and activation epochs within the governance parameters
+pub(super) async fn random_proposal_epochs(ctx: &Ctx) -> Result<(Epoch, Epoch, Epoch), StepError> {
+    let client = &ctx.namada.client;
+    let current_epoch = get_epoch(ctx, retry_config()).await?;
+
+    let gov_prams = rpc::query_governance_parameters(client).await;
+
+    let start_epoch = utils::random_between(
+        current_epoch + 2,
+        current_epoch + gov_prams.max_proposal_latency,
+    );
+    let end_epoch = utils::random_between(
+        start_epoch + gov_prams.min_proposal_voting_period,
+        start_epoch + gov_prams.max_proposal_period - 5,
+    );
+    let grace_epoch = utils::random_between(
+        end_epoch + gov_prams.min_proposal_grace_epochs,
+        end_epoch + 5,
+    );
+
+    Ok((start_epoch, end_epoch, grace_epoch))
+}
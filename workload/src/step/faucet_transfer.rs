@@ -40,6 +40,7 @@ impl StepContext for FaucetTransfer {
             CodeType::Success => assert_always_step!("Done FaucetTransfer", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal FaucetTransfer", code),
             CodeType::Skip => assert_unreachable_step!("Skipped FaucetTransfer", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected FaucetTransfer", code),
             CodeType::Failed => assert_unreachable_step!("Failed FaucetTransfer", code),
         }
     }
@@ -48,6 +48,7 @@ impl StepContext for FundAll {
             CodeType::Success => assert_always_step!("Done FundAll", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal FundAll", code),
             CodeType::Skip => assert_unreachable_step!("Skipped FundAll", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected FundAll", code),
             CodeType::Failed => assert_unreachable_step!("Failed FundAll", code),
         }
     }
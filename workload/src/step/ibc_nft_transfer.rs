@@ -0,0 +1,131 @@
+use crate::code::{Code, CodeType};
+use crate::constants::COSMOS_NFT_CLASS;
+use crate::context::Ctx;
+use crate::error::{StepError, TaskError};
+use crate::state::State;
+use crate::step::StepContext;
+use crate::task::{self, Task, TaskSettings};
+use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
+
+use super::utils;
+
+#[derive(Clone, Debug, Default)]
+pub struct IbcNftTransferSend;
+
+impl StepContext for IbcNftTransferSend {
+    fn name(&self) -> String {
+        "ibc-nft-transfer-send".to_string()
+    }
+
+    async fn is_valid(&self, ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(ctx.any_nft_counterparty() && state.any_nft())
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let (source_account, trace) = state
+            .random_nft(vec![])
+            .ok_or(StepError::BuildTask("No more NFTs".to_string()))?;
+        // The NFT goes back to the counterparty through the channel it came from
+        let cosmos = ctx.counterparty_for_denom(&trace)?;
+        let (Some(src_channel_id), Some(dest_channel_id)) = (
+            cosmos.namada_nft_channel_id.clone(),
+            cosmos.cosmos_nft_channel_id.clone(),
+        ) else {
+            return Ok(vec![]);
+        };
+        let target_account = cosmos.account.to_string();
+
+        let gas_payer = utils::get_gas_payer(source_account.public_keys.iter(), state);
+        let task_settings = TaskSettings::new(source_account.public_keys, gas_payer);
+
+        Ok(vec![Task::IbcNftTransferSend(
+            task::ibc_nft_transfer::IbcNftTransferSend::builder()
+                .source(source_account.alias)
+                .receiver(target_account.into())
+                .trace(trace)
+                .src_channel_id(src_channel_id)
+                .dest_channel_id(dest_channel_id)
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done IbcNftTransferSend", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal IbcNftTransferSend", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped IbcNftTransferSend", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected IbcNftTransferSend", code),
+            CodeType::Failed if matches!(code, Code::TaskFailure(_, TaskError::IbcTransfer(_))) => {
+                assert_sometimes_step!("Failed IbcNftTransferSend (acceptable)", code)
+            }
+            CodeType::Failed => assert_unreachable_step!("Failed IbcNftTransferSend", code),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct IbcNftTransferRecv;
+
+impl StepContext for IbcNftTransferRecv {
+    fn name(&self) -> String {
+        "ibc-nft-transfer-recv".to_string()
+    }
+
+    async fn is_valid(&self, ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(ctx.any_nft_counterparty() && state.any_account())
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let Some(cosmos) = ctx.random_nft_counterparty() else {
+            return Ok(vec![]);
+        };
+        let (Some(src_channel_id), Some(dest_channel_id)) = (
+            cosmos.cosmos_nft_channel_id.clone(),
+            cosmos.namada_nft_channel_id.clone(),
+        ) else {
+            return Ok(vec![]);
+        };
+        let source = cosmos.account.to_string().into();
+        let target_account = state
+            .random_account(vec![])
+            .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
+        // Send back an NFT returned from Namada or mint a new one
+        let (token_id, is_new) = match state.random_foreign_nft(&source) {
+            Some(token_id) if utils::coin_flip(0.5) => (token_id, false),
+            _ => (
+                format!("workload{}", utils::get_random_string(8).to_lowercase()),
+                true,
+            ),
+        };
+
+        // task settings is not used, but required
+        let task_settings = TaskSettings::faucet();
+
+        Ok(vec![Task::IbcNftTransferRecv(
+            task::ibc_nft_transfer::IbcNftTransferRecv::builder()
+                .sender(source)
+                .target(target_account.alias)
+                .class_id(COSMOS_NFT_CLASS.to_string())
+                .token_id(token_id)
+                .is_new(is_new)
+                .src_channel_id(src_channel_id)
+                .dest_channel_id(dest_channel_id)
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done IbcNftTransferRecv", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal IbcNftTransferRecv", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped IbcNftTransferRecv", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected IbcNftTransferRecv", code),
+            CodeType::Failed if matches!(code, Code::TaskFailure(_, TaskError::IbcTransfer(_))) => {
+                assert_sometimes_step!("Failed IbcNftTransferRecv (acceptable)", code)
+            }
+            CodeType::Failed => assert_unreachable_step!("Failed IbcNftTransferRecv", code),
+        }
+    }
+}
@@ -1,17 +1,20 @@
 use std::collections::BTreeSet;
 
+use namada_sdk::token;
+
 use crate::code::{Code, CodeType};
 use crate::constants::DEFAULT_FEE;
-use crate::constants::{
-    COSMOS_TOKEN, MAX_BATCH_TX_NUM, MAX_COSMOS_TRANSFER_AMOUNT, MIN_TRANSFER_BALANCE,
-};
+use crate::constants::{MAX_BATCH_TX_NUM, MAX_COSMOS_TRANSFER_AMOUNT};
 use crate::context::Ctx;
 use crate::error::{StepError, TaskError};
 use crate::state::State;
 use crate::step::StepContext;
 use crate::task::{self, Task, TaskSettings};
 use crate::types::Alias;
-use crate::utils::{get_masp_epoch, ibc_denom, is_native_denom, retry_config};
+use crate::utils::{
+    get_cosmos_balance, get_ibc_mint_amount, get_ibc_rate_limits, get_masp_epoch, ibc_denom,
+    retry_config,
+};
 use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
 
 use super::utils;
@@ -30,21 +33,11 @@ impl StepContext for IbcTransferSend {
 
     async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
         let (source_account, denom) = state
-            .random_account_with_ibc_balance(vec![])
-            .filter(|_| utils::coin_flip(0.5))
-            .map(|account| (account, ibc_denom(&ctx.namada_channel_id, COSMOS_TOKEN)))
-            .or_else(|| {
-                state
-                    .random_account_with_min_balance(vec![], MIN_TRANSFER_BALANCE)
-                    .map(|account| (account, Alias::nam().name))
-            })
+            .random_account_with_token(vec![])
             .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
-        let target_account = ctx.cosmos.account.to_string();
-        let amount_account = if is_native_denom(&denom) {
-            state.get_balance_for(&source_account.alias)
-        } else {
-            state.get_ibc_balance_for(&source_account.alias, &denom)
-        };
+        let cosmos = ctx.counterparty_for_denom(&denom)?;
+        let target_account = cosmos.account.to_string();
+        let amount_account = state.get_balance_for(&source_account.alias, &denom);
         let amount = utils::random_between(1, amount_account / MAX_BATCH_TX_NUM);
 
         let gas_payer = utils::get_gas_payer(source_account.public_keys.iter(), state);
@@ -56,8 +49,8 @@ impl StepContext for IbcTransferSend {
                 .receiver(target_account.into())
                 .amount(amount)
                 .denom(denom)
-                .src_channel_id(ctx.namada_channel_id.clone())
-                .dest_channel_id(ctx.cosmos_channel_id.clone())
+                .src_channel_id(cosmos.namada_channel_id.clone())
+                .dest_channel_id(cosmos.cosmos_channel_id.clone())
                 .settings(task_settings)
                 .build(),
         )])
@@ -68,6 +61,7 @@ impl StepContext for IbcTransferSend {
             CodeType::Success => assert_always_step!("Done IbcTransferSend", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal IbcTransferSend", code),
             CodeType::Skip => assert_sometimes_step!("Skipped IbcTransferSend", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected IbcTransferSend", code),
             CodeType::Failed if matches!(code, Code::TaskFailure(_, TaskError::IbcTransfer(_))) => {
                 assert_sometimes_step!("Failed IbcTransferSend (acceptable)", code)
             }
@@ -89,18 +83,19 @@ impl StepContext for IbcTransferRecv {
     }
 
     async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
-        let source = ctx.cosmos.account.to_string().into();
+        let cosmos = ctx.random_counterparty();
+        let source = cosmos.account.to_string().into();
         let target_account = state
             .random_account(vec![])
             .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
         let foreign_balance = state.get_foreign_balance_for(&source);
         let (denom, max_amount) = if foreign_balance > 0 && utils::coin_flip(0.5) {
             (
-                ibc_denom(&ctx.cosmos_channel_id, &Alias::nam().name),
+                ibc_denom(&cosmos.cosmos_channel_id, &Alias::nam().name),
                 foreign_balance / MAX_BATCH_TX_NUM,
             )
         } else {
-            (COSMOS_TOKEN.to_string(), MAX_COSMOS_TRANSFER_AMOUNT)
+            (cosmos.random_token(), MAX_COSMOS_TRANSFER_AMOUNT)
         };
         let amount = utils::random_between(1, max_amount);
 
@@ -113,8 +108,8 @@ impl StepContext for IbcTransferRecv {
                 .target(target_account.alias)
                 .amount(amount)
                 .denom(denom)
-                .src_channel_id(ctx.cosmos_channel_id.clone())
-                .dest_channel_id(ctx.namada_channel_id.clone())
+                .src_channel_id(cosmos.cosmos_channel_id.clone())
+                .dest_channel_id(cosmos.namada_channel_id.clone())
                 .settings(task_settings)
                 .build(),
         )])
@@ -125,6 +120,7 @@ impl StepContext for IbcTransferRecv {
             CodeType::Success => assert_always_step!("Done IbcTransferRecv", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal IbcTransferRecv", code),
             CodeType::Skip => assert_sometimes_step!("Skipped IbcTransferRecv", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected IbcTransferRecv", code),
             CodeType::Failed if matches!(code, Code::TaskFailure(_, TaskError::IbcTransfer(_))) => {
                 assert_sometimes_step!("Failed IbcTransferRecv (acceptable)", code)
             }
@@ -146,18 +142,19 @@ impl StepContext for IbcShieldingTransfer {
     }
 
     async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
-        let source = ctx.cosmos.account.to_string().into();
+        let cosmos = ctx.random_counterparty();
+        let source = cosmos.account.to_string().into();
         let target_account = state
             .random_payment_address(vec![])
             .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
         let foreign_balance = state.get_foreign_balance_for(&source);
         let (denom, max_amount) = if foreign_balance > 0 && utils::coin_flip(0.5) {
             (
-                ibc_denom(&ctx.cosmos_channel_id, &Alias::nam().name),
+                ibc_denom(&cosmos.cosmos_channel_id, &Alias::nam().name),
                 foreign_balance / MAX_BATCH_TX_NUM,
             )
         } else {
-            (COSMOS_TOKEN.to_string(), MAX_COSMOS_TRANSFER_AMOUNT)
+            (cosmos.random_token(), MAX_COSMOS_TRANSFER_AMOUNT)
         };
         let amount = utils::random_between(1, max_amount);
 
@@ -170,8 +167,8 @@ impl StepContext for IbcShieldingTransfer {
                 .target(target_account.alias.payment_address())
                 .amount(amount)
                 .denom(denom)
-                .src_channel_id(ctx.cosmos_channel_id.clone())
-                .dest_channel_id(ctx.namada_channel_id.clone())
+                .src_channel_id(cosmos.cosmos_channel_id.clone())
+                .dest_channel_id(cosmos.namada_channel_id.clone())
                 .settings(task_settings)
                 .build(),
         )])
@@ -182,6 +179,7 @@ impl StepContext for IbcShieldingTransfer {
             CodeType::Success => assert_always_step!("Done IbcShieldingTransfer", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal IbcShieldingTransfer", code),
             CodeType::Skip => assert_sometimes_step!("Skipped IbcShieldingTransfer", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected IbcShieldingTransfer", code),
             CodeType::Failed if matches!(code, Code::TaskFailure(_, TaskError::IbcTransfer(_))) => {
                 assert_sometimes_step!("Failed IbcShieldingTransfer (acceptable)", code)
             }
@@ -211,29 +209,19 @@ impl StepContext for IbcUnshieldingTransfer {
     }
 
     async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
-        let Some((source_account, denom)) = state
-            .random_masp_account_with_ibc_balance(vec![])
-            .filter(|_| utils::coin_flip(0.5))
-            .map(|account| (account, ibc_denom(&ctx.namada_channel_id, COSMOS_TOKEN)))
-            .or_else(|| {
-                state
-                    .random_masp_account_with_min_balance(vec![], MIN_TRANSFER_BALANCE)
-                    .map(|account| (account, Alias::nam().name))
-            })
-        else {
+        let Some((source_account, denom)) = state.random_masp_account_with_token(vec![]) else {
             return Ok(vec![]);
         };
-        let target_account = ctx.cosmos.account.to_string();
-        let amount_account = if is_native_denom(&denom) {
-            state.get_shielded_balance_for(&source_account.alias)
-        } else {
-            state.get_ibc_balance_for(&source_account.alias.spending_key(), &denom)
-        };
+        let cosmos = ctx.counterparty_for_denom(&denom)?;
+        let target_account = cosmos.account.to_string();
+        let amount_account = state.get_shielded_balance_for(&source_account.alias, &denom);
         let amount = utils::random_between(1, amount_account / MAX_BATCH_TX_NUM);
 
-        let transparent_source_balance = state.get_balance_for(&source_account.alias.base());
+        let native_denom = Alias::nam().name;
+        let transparent_source_balance =
+            state.get_balance_for(&source_account.alias.base(), &native_denom);
         let shielded_source_balance =
-            state.get_shielded_balance_for(&source_account.alias.spending_key());
+            state.get_shielded_balance_for(&source_account.alias, &native_denom);
         if transparent_source_balance < DEFAULT_FEE && shielded_source_balance < DEFAULT_FEE {
             // Insufficient balance for the fee
             return Ok(vec![]);
@@ -257,8 +245,8 @@ impl StepContext for IbcUnshieldingTransfer {
                 .receiver(target_account.into())
                 .amount(amount)
                 .denom(denom)
-                .src_channel_id(ctx.namada_channel_id.clone())
-                .dest_channel_id(ctx.cosmos_channel_id.clone())
+                .src_channel_id(cosmos.namada_channel_id.clone())
+                .dest_channel_id(cosmos.cosmos_channel_id.clone())
                 .epoch(epoch)
                 .settings(task_settings)
                 .build(),
@@ -270,6 +258,7 @@ impl StepContext for IbcUnshieldingTransfer {
             CodeType::Success => assert_always_step!("Done IbcUnshieldingTransfer", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal IbcUnshieldingTransfer", code),
             CodeType::Skip => assert_sometimes_step!("Skipped IbcUnshieldingTransfer", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected IbcUnshieldingTransfer", code),
             CodeType::Failed if matches!(code, Code::TaskFailure(_, TaskError::IbcTransfer(_))) => {
                 assert_sometimes_step!("Failed IbcUnshieldingTransfer (acceptable)", code)
             }
@@ -285,3 +274,315 @@ impl StepContext for IbcUnshieldingTransfer {
         }
     }
 }
+
+#[derive(Clone, Debug, Default)]
+pub struct IbcTransferTimeout;
+
+impl StepContext for IbcTransferTimeout {
+    fn name(&self) -> String {
+        "ibc-transfer-timeout".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.any_account_can_make_transfer())
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let epoch = get_masp_epoch(ctx, retry_config()).await?;
+
+        let shielded_source = if utils::coin_flip(0.5) {
+            state.random_masp_account_with_token(vec![])
+        } else {
+            None
+        };
+        let (source, denom, amount, task_settings) = match shielded_source {
+            Some((source_account, denom)) => {
+                let amount_account = state.get_shielded_balance_for(&source_account.alias, &denom);
+                let amount = utils::random_between(1, amount_account / MAX_BATCH_TX_NUM);
+
+                let native_denom = Alias::nam().name;
+                let transparent_source_balance =
+                    state.get_balance_for(&source_account.alias.base(), &native_denom);
+                let shielded_source_balance =
+                    state.get_shielded_balance_for(&source_account.alias, &native_denom);
+                if transparent_source_balance < DEFAULT_FEE && shielded_source_balance < DEFAULT_FEE
+                {
+                    // Insufficient balance for the fee
+                    return Ok(vec![]);
+                }
+                let disposable_gas_payer = transparent_source_balance < DEFAULT_FEE
+                    || (shielded_source_balance >= DEFAULT_FEE && utils::coin_flip(0.5));
+                let task_settings = TaskSettings::new(
+                    BTreeSet::from([source_account.alias.base()]),
+                    if disposable_gas_payer {
+                        source_account.alias.spending_key()
+                    } else {
+                        source_account.alias.base()
+                    },
+                );
+
+                (
+                    source_account.alias.spending_key(),
+                    denom,
+                    amount,
+                    task_settings,
+                )
+            }
+            None => {
+                let (source_account, denom) = state
+                    .random_account_with_token(vec![])
+                    .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
+                let amount_account = state.get_balance_for(&source_account.alias, &denom);
+                let amount = utils::random_between(1, amount_account / MAX_BATCH_TX_NUM);
+
+                let gas_payer = utils::get_gas_payer(source_account.public_keys.iter(), state);
+                let task_settings = TaskSettings::new(source_account.public_keys, gas_payer);
+
+                (source_account.alias, denom, amount, task_settings)
+            }
+        };
+        let cosmos = ctx.counterparty_for_denom(&denom)?;
+        let target_account = cosmos.account.to_string();
+
+        Ok(vec![Task::IbcTransferTimeout(
+            task::ibc_transfer::IbcTransferTimeout::builder()
+                .source(source)
+                .receiver(target_account.into())
+                .amount(amount)
+                .denom(denom)
+                .src_channel_id(cosmos.namada_channel_id.clone())
+                .dest_channel_id(cosmos.cosmos_channel_id.clone())
+                .epoch(epoch)
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done IbcTransferTimeout", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal IbcTransferTimeout", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped IbcTransferTimeout", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected IbcTransferTimeout", code),
+            CodeType::Failed
+                if matches!(
+                    code,
+                    Code::TaskFailure(_, TaskError::InvalidShielded { .. })
+                ) =>
+            {
+                assert_sometimes_step!("Invalid IbcTransferTimeout", code)
+            }
+            _ => assert_unreachable_step!("Failed IbcTransferTimeout", code),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct IbcShieldedRoundTrip;
+
+impl StepContext for IbcShieldedRoundTrip {
+    fn name(&self) -> String {
+        "ibc-shielded-round-trip".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.at_least_masp_accounts(2) && state.any_masp_account_can_make_transfer())
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let Some((source_account, denom)) = state.random_masp_account_with_token(vec![]) else {
+            return Ok(vec![]);
+        };
+        let Some(target_account) = state.random_payment_address(vec![source_account.alias.clone()])
+        else {
+            return Ok(vec![]);
+        };
+        let cosmos = ctx.counterparty_for_denom(&denom)?;
+        let cosmos_account = cosmos.account.to_string();
+        let amount_account = state.get_shielded_balance_for(&source_account.alias, &denom);
+        let amount = utils::random_between(1, amount_account / MAX_BATCH_TX_NUM);
+
+        let native_denom = Alias::nam().name;
+        let transparent_source_balance =
+            state.get_balance_for(&source_account.alias.base(), &native_denom);
+        let shielded_source_balance =
+            state.get_shielded_balance_for(&source_account.alias, &native_denom);
+        if transparent_source_balance < DEFAULT_FEE && shielded_source_balance < DEFAULT_FEE {
+            // Insufficient balance for the fee
+            return Ok(vec![]);
+        }
+        let disposable_gas_payer = transparent_source_balance < DEFAULT_FEE
+            || (shielded_source_balance >= DEFAULT_FEE && utils::coin_flip(0.5));
+        let task_settings = TaskSettings::new(
+            BTreeSet::from([source_account.alias.base()]),
+            if disposable_gas_payer {
+                source_account.alias.spending_key()
+            } else {
+                source_account.alias.base()
+            },
+        );
+
+        let epoch = get_masp_epoch(ctx, retry_config()).await?;
+
+        Ok(vec![Task::IbcShieldedRoundTrip(
+            task::ibc_transfer::IbcShieldedRoundTrip::builder()
+                .source(source_account.alias.spending_key())
+                .cosmos_account(cosmos_account.into())
+                .target(target_account.alias.payment_address())
+                .amount(amount)
+                .denom(denom)
+                .namada_channel_id(cosmos.namada_channel_id.clone())
+                .cosmos_channel_id(cosmos.cosmos_channel_id.clone())
+                .epoch(epoch)
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        // the inbound transfer could fail after the outbound transfer
+        let task_error = match code {
+            Code::TaskFailure(_, TaskError::PartialExecution { err, .. }) => Some(err.as_ref()),
+            Code::TaskFailure(_, err) => Some(err),
+            _ => None,
+        };
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done IbcShieldedRoundTrip", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal IbcShieldedRoundTrip", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped IbcShieldedRoundTrip", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected IbcShieldedRoundTrip", code),
+            CodeType::Failed if matches!(task_error, Some(TaskError::IbcTransfer(_))) => {
+                assert_sometimes_step!("Failed IbcShieldedRoundTrip (acceptable)", code)
+            }
+            CodeType::Failed if matches!(task_error, Some(TaskError::InvalidShielded { .. })) => {
+                assert_sometimes_step!("Invalid IbcShieldedRoundTrip", code)
+            }
+            _ => assert_unreachable_step!("Failed IbcShieldedRoundTrip", code),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct IbcTransferOverLimit;
+
+impl StepContext for IbcTransferOverLimit {
+    fn name(&self) -> String {
+        "ibc-transfer-over-limit".to_string()
+    }
+
+    async fn is_valid(&self, ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(ctx.any_over_limit_counterparty() && state.any_account())
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let retry_config = retry_config();
+        let Some(cosmos) = ctx.random_over_limit_counterparty() else {
+            return Ok(vec![]);
+        };
+        let source: Alias = cosmos.account.to_string().into();
+        let target_account = state
+            .random_account(vec![])
+            .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
+
+        // the dedicated token is funded beyond the live limits on Namada
+        let denom = cosmos
+            .over_limit_token
+            .clone()
+            .expect("Over-limit token should exist");
+        let recv_denom = ibc_denom(&cosmos.namada_channel_id, &denom);
+        let (mint_limit, throughput_limit) =
+            get_ibc_rate_limits(ctx, &recv_denom, retry_config).await?;
+        let minted = get_ibc_mint_amount(ctx, &recv_denom, retry_config).await?;
+        let limit = std::cmp::min(
+            mint_limit.checked_sub(minted).unwrap_or_default(),
+            throughput_limit,
+        );
+        let cosmos_balance = get_cosmos_balance(cosmos, &source, &denom, retry_config).await?;
+        if token::Amount::from_u64(cosmos_balance) <= limit {
+            // Not enough balance to exceed the limits
+            return Ok(vec![]);
+        }
+        // Exceed the limits with a single transfer regardless of other transfers in the epoch
+        let amount = utils::random_between(limit.raw_amount().as_u64() + 1, cosmos_balance);
+
+        // task settings is not used, but required
+        let task_settings = TaskSettings::faucet();
+
+        Ok(vec![Task::IbcTransferOverLimit(
+            task::ibc_transfer::IbcTransferOverLimit::builder()
+                .sender(source)
+                .target(target_account.alias)
+                .denom(denom)
+                .amount(amount)
+                .mint_limit(mint_limit)
+                .src_channel_id(cosmos.cosmos_channel_id.clone())
+                .dest_channel_id(cosmos.namada_channel_id.clone())
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done IbcTransferOverLimit", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal IbcTransferOverLimit", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped IbcTransferOverLimit", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected IbcTransferOverLimit", code),
+            CodeType::Failed => assert_unreachable_step!("Failed IbcTransferOverLimit", code),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct IbcTransferForward;
+
+impl StepContext for IbcTransferForward {
+    fn name(&self) -> String {
+        "ibc-transfer-forward".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.any_account())
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let source = ctx.random_counterparty();
+        // the same counterparty means the packet goes back out
+        let destination = ctx.random_counterparty();
+        let intermediate_account = state
+            .random_account(vec![])
+            .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
+        let amount = utils::random_between(1, MAX_COSMOS_TRANSFER_AMOUNT);
+
+        // task settings is not used, but required
+        let task_settings = TaskSettings::faucet();
+
+        Ok(vec![Task::IbcTransferForward(
+            task::ibc_transfer::IbcTransferForward::builder()
+                .sender(source.account.to_string().into())
+                .intermediate(intermediate_account.alias)
+                .receiver(destination.account.to_string().into())
+                .denom(source.random_token())
+                .amount(amount)
+                .src_channel_id(source.cosmos_channel_id.clone())
+                .dest_channel_id(source.namada_channel_id.clone())
+                .forward_src_channel_id(destination.namada_channel_id.clone())
+                .forward_dest_channel_id(destination.cosmos_channel_id.clone())
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done IbcTransferForward", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal IbcTransferForward", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped IbcTransferForward", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected IbcTransferForward", code),
+            CodeType::Failed if matches!(code, Code::TaskFailure(_, TaskError::IbcTransfer(_))) => {
+                assert_sometimes_step!("Failed IbcTransferForward (acceptable)", code)
+            }
+            CodeType::Failed => assert_unreachable_step!("Failed IbcTransferForward", code),
+        }
+    }
+}
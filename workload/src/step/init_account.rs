@@ -53,6 +53,7 @@ impl StepContext for InitAccount {
             CodeType::Success => assert_always_step!("Done InitAccount", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal InitAccount", code),
             CodeType::Skip => assert_unreachable_step!("Skipped InitAccount", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected InitAccount", code),
             CodeType::Failed => assert_unreachable_step!("Failed InitAccount", code),
         }
     }
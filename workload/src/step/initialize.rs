@@ -1,4 +1,3 @@
-use antithesis_sdk::random::AntithesisRng;
 use rand::seq::IteratorRandom;
 
 use crate::code::{Code, CodeType};
@@ -11,7 +10,7 @@ use crate::state::State;
 use crate::step::{StepContext, StepType};
 use crate::task::{self, Task, TaskSettings};
 use crate::types::Alias;
-use crate::utils::{get_epoch, get_validator_addresses, retry_config};
+use crate::utils::{get_epoch, get_validator_addresses, retry_config, WorkloadRng};
 use crate::{assert_always_step, assert_unreachable_step};
 
 use super::utils;
@@ -64,7 +63,7 @@ impl StepContext for Initialize {
             let source_aliases = implicit_aliases
                 .clone()
                 .into_iter()
-                .choose_multiple(&mut AntithesisRng, total_signers as usize)
+                .choose_multiple(&mut WorkloadRng, total_signers as usize)
                 .into_iter()
                 .collect();
             // avoid batching them to save accounts to the wallet
@@ -109,7 +108,7 @@ impl StepContext for Initialize {
 
             let validator = validators
                 .iter()
-                .choose(&mut AntithesisRng)
+                .choose(&mut WorkloadRng)
                 .expect("There is always at least a validator");
 
             let task_settings = TaskSettings::new([alias.clone()].into(), Alias::faucet());
@@ -140,6 +139,7 @@ impl StepContext for Initialize {
             CodeType::Success => assert_always_step!("Done Initialize", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal Initialize", code),
             CodeType::Skip => assert_unreachable_step!("Skipped Initialize", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected Initialize", code),
             CodeType::Failed => assert_unreachable_step!("Failed Initialize", code),
         }
     }
@@ -98,6 +98,7 @@ impl StepContext for NewWalletKeyPair {
             CodeType::Success => assert_always_step!("Done NewWalletKeyPair", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal NewWalletKeyPair", code),
             CodeType::Skip => assert_unreachable_step!("Skipped NewWalletKeyPair", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected NewWalletKeyPair", code),
             CodeType::Failed => assert_unreachable_step!("Failed NewWalletKeyPair", code),
         }
     }
@@ -0,0 +1,123 @@
+use crate::code::{Code, CodeType};
+use crate::constants::PROPOSAL_DEPOSIT;
+use crate::context::Ctx;
+use crate::error::StepError;
+use crate::state::{ProposalPayload, State};
+use crate::step::StepContext;
+use crate::task::{self, Task, TaskSettings};
+use crate::utils::{is_pgf_steward, retry_config};
+use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
+
+use super::default_proposal::random_proposal_epochs;
+use super::utils;
+
+#[derive(Clone, Debug, Default)]
+pub struct PgfFundingProposal;
+
+impl StepContext for PgfFundingProposal {
+    fn name(&self) -> String {
+        "pgf-funding-proposal".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.any_account_with_min_balance(PROPOSAL_DEPOSIT))
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let source_account = state
+            .random_account_with_min_balance(vec![], PROPOSAL_DEPOSIT)
+            .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
+        let Some(target_account) = state.random_account(vec![]) else {
+            return Ok(vec![]);
+        };
+
+        let (start_epoch, end_epoch, grace_epoch) = random_proposal_epochs(ctx).await?;
+        let amount = utils::random_between(1_000, 1_000_000);
+        let is_continuous = utils::coin_flip(0.5);
+
+        let gas_payer = utils::get_gas_payer(source_account.public_keys.iter(), state);
+        let task_settings = TaskSettings::new(source_account.public_keys, gas_payer);
+
+        Ok(vec![Task::PgfFundingProposal(
+            task::pgf_proposal::PgfFundingProposal::builder()
+                .source(source_account.alias)
+                .target(target_account.alias)
+                .amount(amount)
+                .is_continuous(is_continuous)
+                .start_epoch(start_epoch)
+                .end_epoch(end_epoch)
+                .grace_epoch(grace_epoch)
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done PgfFundingProposal", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal PgfFundingProposal", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped PgfFundingProposal", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected PgfFundingProposal", code),
+            CodeType::Failed => assert_unreachable_step!("Failed PgfFundingProposal", code),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PgfStewardProposal;
+
+impl StepContext for PgfStewardProposal {
+    fn name(&self) -> String {
+        "pgf-steward-proposal".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.any_account_with_min_balance(PROPOSAL_DEPOSIT))
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let source_account = state
+            .random_account_with_min_balance(vec![], PROPOSAL_DEPOSIT)
+            .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
+
+        // the result of the other pending steward proposal would conflict
+        let is_pending = state.pending_proposals.values().any(|proposal| {
+            matches!(
+                &proposal.payload,
+                ProposalPayload::PgfSteward { steward, .. } if *steward == source_account.alias
+            )
+        });
+        if is_pending {
+            return Ok(vec![]);
+        }
+
+        // the author can add only itself, or remove itself if it is already a steward
+        let (_, is_steward) = is_pgf_steward(ctx, &source_account.alias, retry_config()).await?;
+
+        let (start_epoch, end_epoch, grace_epoch) = random_proposal_epochs(ctx).await?;
+
+        let gas_payer = utils::get_gas_payer(source_account.public_keys.iter(), state);
+        let task_settings = TaskSettings::new(source_account.public_keys, gas_payer);
+
+        Ok(vec![Task::PgfStewardProposal(
+            task::pgf_proposal::PgfStewardProposal::builder()
+                .source(source_account.alias)
+                .is_add(!is_steward)
+                .start_epoch(start_epoch)
+                .end_epoch(end_epoch)
+                .grace_epoch(grace_epoch)
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done PgfStewardProposal", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal PgfStewardProposal", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped PgfStewardProposal", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected PgfStewardProposal", code),
+            CodeType::Failed => assert_unreachable_step!("Failed PgfStewardProposal", code),
+        }
+    }
+}
@@ -43,6 +43,7 @@ impl StepContext for ReactivateValidator {
             CodeType::Success => assert_always_step!("Done ReactivateValidator", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal ReactivateValidator", code),
             CodeType::Skip => assert_sometimes_step!("Skipped ReactivateValidator", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected ReactivateValidator", code),
             CodeType::Failed => assert_unreachable_step!("Failed ReactivateValidator", code),
         }
     }
@@ -1,4 +1,3 @@
-use antithesis_sdk::random::AntithesisRng;
 use rand::seq::IteratorRandom;
 
 use crate::code::{Code, CodeType};
@@ -8,7 +7,7 @@ use crate::error::StepError;
 use crate::state::State;
 use crate::step::StepContext;
 use crate::task::{self, Task, TaskSettings};
-use crate::utils::{get_epoch, get_validator_addresses, retry_config};
+use crate::utils::{get_epoch, get_validator_addresses, retry_config, WorkloadRng};
 use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
 
 use super::utils;
@@ -43,7 +42,7 @@ impl StepContext for Redelegate {
         let to_validator = if let Some(validator) = validators
             .iter()
             .filter(|v| v.to_string() != source_bond.validator)
-            .choose(&mut AntithesisRng)
+            .choose(&mut WorkloadRng)
         {
             validator
         } else {
@@ -71,6 +70,7 @@ impl StepContext for Redelegate {
             CodeType::Success => assert_always_step!("Done Redelegate", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal Redelegate", code),
             CodeType::Skip => assert_sometimes_step!("Skipped Redelegate", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected Redelegate", code),
             CodeType::Failed => assert_unreachable_step!("Failed Redelegate", code),
         }
     }
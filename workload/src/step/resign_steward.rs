@@ -0,0 +1,58 @@
+use crate::code::{Code, CodeType};
+use crate::context::Ctx;
+use crate::error::StepError;
+use crate::state::{ProposalPayload, State};
+use crate::step::StepContext;
+use crate::task::{self, Task, TaskSettings};
+use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
+
+use super::utils;
+
+#[derive(Clone, Debug, Default)]
+pub struct ResignSteward;
+
+impl StepContext for ResignSteward {
+    fn name(&self) -> String {
+        "resign-steward".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.any_steward())
+    }
+
+    async fn build_task(&self, _ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let Some(steward_account) = state.random_steward() else {
+            return Ok(vec![]);
+        };
+        // the pending steward proposal will check the membership after its activation
+        let is_pending = state.pending_proposals.values().any(|proposal| {
+            matches!(
+                &proposal.payload,
+                ProposalPayload::PgfSteward { steward, .. } if *steward == steward_account.alias
+            )
+        });
+        if is_pending {
+            return Ok(vec![]);
+        }
+
+        let gas_payer = utils::get_gas_payer(steward_account.public_keys.iter(), state);
+        let task_settings = TaskSettings::new(steward_account.public_keys, gas_payer);
+
+        Ok(vec![Task::ResignSteward(
+            task::resign_steward::ResignSteward::builder()
+                .source(steward_account.alias)
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done ResignSteward", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal ResignSteward", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped ResignSteward", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected ResignSteward", code),
+            CodeType::Failed => assert_unreachable_step!("Failed ResignSteward", code),
+        }
+    }
+}
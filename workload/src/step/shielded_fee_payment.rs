@@ -0,0 +1,103 @@
+use std::collections::BTreeSet;
+
+use crate::code::{Code, CodeType};
+use crate::constants::{DEFAULT_FEE, MAX_BATCH_TX_NUM};
+use crate::context::Ctx;
+use crate::error::{StepError, TaskError};
+use crate::state::State;
+use crate::step::utils::coin_flip;
+use crate::step::StepContext;
+use crate::task::{self, Task, TaskSettings};
+use crate::types::Alias;
+use crate::utils::{get_masp_epoch, retry_config};
+use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
+
+use super::utils;
+
+#[derive(Clone, Debug, Default)]
+pub struct ShieldedFeePayment;
+
+impl StepContext for ShieldedFeePayment {
+    fn name(&self) -> String {
+        "shielded-fee-payment".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.at_least_masp_accounts(2) && state.any_masp_account_can_make_transfer())
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let (source_account, denom) = state
+            .random_masp_account_with_token(vec![])
+            .ok_or(StepError::BuildTask("No more source accounts".to_string()))?;
+
+        let epoch = get_masp_epoch(ctx, retry_config()).await?;
+        let target_account = state
+            .random_payment_address(vec![source_account.alias.clone()])
+            .ok_or(StepError::BuildTask("No more target accounts".to_string()))?;
+
+        let native_denom = Alias::nam().name;
+        let amount_account = state.get_shielded_balance_for(&source_account.alias, &denom);
+        let shielded_source_balance =
+            state.get_shielded_balance_for(&source_account.alias, &native_denom);
+        let (amount, is_fee_insufficient) = if shielded_source_balance < DEFAULT_FEE {
+            // No shielded balance for the fee
+            (
+                utils::random_between(1, amount_account / MAX_BATCH_TX_NUM),
+                true,
+            )
+        } else if denom == native_denom {
+            if coin_flip(0.2) {
+                // The remaining shielded balance after the transfer doesn't cover the fee
+                (amount_account - DEFAULT_FEE + 1, true)
+            } else {
+                let available = amount_account - DEFAULT_FEE;
+                (
+                    utils::random_between(1, available / MAX_BATCH_TX_NUM),
+                    false,
+                )
+            }
+        } else {
+            (
+                utils::random_between(1, amount_account / MAX_BATCH_TX_NUM),
+                false,
+            )
+        };
+
+        // The gas is paid only from the shielded pool
+        let task_settings = TaskSettings::new(
+            BTreeSet::from([source_account.alias.base()]),
+            source_account.alias.spending_key(),
+        );
+
+        Ok(vec![Task::ShieldedFeePayment(
+            task::shielded_fee_payment::ShieldedFeePayment::builder()
+                .source(source_account.alias.spending_key())
+                .target(target_account.alias.payment_address())
+                .amount(amount)
+                .denom(denom)
+                .epoch(epoch)
+                .settings(task_settings)
+                .is_fee_insufficient(is_fee_insufficient)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done ShieldedFeePayment", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal ShieldedFeePayment", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped ShieldedFeePayment", code),
+            CodeType::Rejected => assert_sometimes_step!("Rejected ShieldedFeePayment", code),
+            CodeType::Failed
+                if matches!(
+                    code,
+                    Code::TaskFailure(_, TaskError::InvalidShielded { .. })
+                ) =>
+            {
+                assert_sometimes_step!("Invalid ShieldedFeePayment", code)
+            }
+            _ => assert_unreachable_step!("Failed ShieldedFeePayment", code),
+        }
+    }
+}
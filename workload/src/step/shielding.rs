@@ -1,5 +1,5 @@
 use crate::code::{Code, CodeType};
-use crate::constants::{MAX_BATCH_TX_NUM, MIN_TRANSFER_BALANCE};
+use crate::constants::MAX_BATCH_TX_NUM;
 use crate::context::Ctx;
 use crate::error::{StepError, TaskError};
 use crate::state::State;
@@ -19,18 +19,18 @@ impl StepContext for Shielding {
     }
 
     async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
-        Ok(state.any_account_with_min_balance(MIN_TRANSFER_BALANCE))
+        Ok(state.any_account_can_make_transfer())
     }
 
     async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
-        let source_account = state
-            .random_account_with_min_balance(vec![], MIN_TRANSFER_BALANCE)
+        let (source_account, denom) = state
+            .random_account_with_token(vec![])
             .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
         let epoch = get_masp_epoch(ctx, retry_config()).await?;
         let target_account = state
             .random_payment_address(vec![])
             .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
-        let amount_account = state.get_balance_for(&source_account.alias);
+        let amount_account = state.get_balance_for(&source_account.alias, &denom);
         let amount = utils::random_between(1, amount_account / MAX_BATCH_TX_NUM);
 
         let gas_payer = utils::get_gas_payer(source_account.public_keys.iter(), state);
@@ -41,6 +41,7 @@ impl StepContext for Shielding {
                 .source(source_account.alias)
                 .target(target_account.alias.payment_address())
                 .amount(amount)
+                .denom(denom)
                 .epoch(epoch)
                 .settings(task_settings)
                 .build(),
@@ -52,6 +53,7 @@ impl StepContext for Shielding {
             CodeType::Success => assert_always_step!("Done Shielding", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal Shielding", code),
             CodeType::Skip => assert_sometimes_step!("Skipped Shielding", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected Shielding", code),
             CodeType::Failed
                 if matches!(
                     code,
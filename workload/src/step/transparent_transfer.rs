@@ -1,5 +1,5 @@
 use crate::code::{Code, CodeType};
-use crate::constants::{MAX_BATCH_TX_NUM, MIN_TRANSFER_BALANCE};
+use crate::constants::MAX_BATCH_TX_NUM;
 use crate::context::Ctx;
 use crate::error::StepError;
 use crate::state::State;
@@ -22,23 +22,26 @@ impl StepContext for TransparentTransfer {
     }
 
     async fn build_task(&self, _ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
-        let source_account = state
-            .random_account_with_min_balance(vec![], MIN_TRANSFER_BALANCE)
+        let (source_account, denom) = state
+            .random_account_with_token(vec![])
             .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
         let target_account = state
             .random_account(vec![source_account.alias.clone()])
             .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
-        let amount_account = state.get_balance_for(&source_account.alias);
+        let amount_account = state.get_balance_for(&source_account.alias, &denom);
         let amount = utils::random_between(1, amount_account / MAX_BATCH_TX_NUM);
 
+        let (signers, is_below_threshold) = utils::get_random_signers(&source_account);
         let gas_payer = utils::get_gas_payer(source_account.public_keys.iter(), state);
-        let task_settings = TaskSettings::new(source_account.public_keys, gas_payer);
+        let mut task_settings = TaskSettings::new(signers, gas_payer);
+        task_settings.is_below_threshold = is_below_threshold;
 
         Ok(vec![Task::TransparentTransfer(
             task::transparent_transfer::TransparentTransfer::builder()
                 .source(source_account.alias)
                 .target(target_account.alias)
                 .amount(amount)
+                .denom(denom)
                 .settings(task_settings)
                 .build(),
         )])
@@ -49,6 +52,7 @@ impl StepContext for TransparentTransfer {
             CodeType::Success => assert_always_step!("Done TransparentTransfer", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal TransparentTransfer", code),
             CodeType::Skip => assert_sometimes_step!("Skipped TransparentTransfer", code),
+            CodeType::Rejected => assert_sometimes_step!("Rejected TransparentTransfer", code),
             CodeType::Failed => assert_unreachable_step!("Failed TransparentTransfer", code),
         }
     }
@@ -50,6 +50,7 @@ impl StepContext for Unbond {
             CodeType::Success => assert_always_step!("Done Unbond", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal Unbond", code),
             CodeType::Skip => assert_sometimes_step!("Skipped Unbond", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected Unbond", code),
             CodeType::Failed => assert_unreachable_step!("Failed Unbond", code),
         }
     }
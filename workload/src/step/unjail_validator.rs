@@ -0,0 +1,85 @@
+use namada_sdk::proof_of_stake::types::ValidatorState;
+use rand::seq::SliceRandom;
+
+use crate::code::{Code, CodeType};
+use crate::constants::{PIPELINE_LEN, SLASH_PROCESSING_EPOCH_OFFSET};
+use crate::context::Ctx;
+use crate::error::StepError;
+use crate::state::State;
+use crate::step::StepContext;
+use crate::task::{self, Task, TaskSettings};
+use crate::utils::{
+    get_epoch, get_validator_last_slash_epoch, get_validator_state, retry_config, WorkloadRng,
+};
+use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
+
+use super::utils;
+
+#[derive(Clone, Debug, Default)]
+pub struct UnjailValidator;
+
+impl StepContext for UnjailValidator {
+    fn name(&self) -> String {
+        "unjail-validator".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.min_n_validators(1))
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let epoch = get_epoch(ctx, retry_config()).await?;
+
+        let mut validators = state.validators.values().cloned().collect::<Vec<_>>();
+        validators.shuffle(&mut WorkloadRng);
+
+        for account in validators {
+            // the validator should be still jailed at the pipeline epoch,
+            // otherwise it has been already unjailed
+            let (_, (current_state, _)) =
+                get_validator_state(ctx, &account.alias, epoch, retry_config()).await?;
+            let (_, (pipeline_state, _)) =
+                get_validator_state(ctx, &account.alias, epoch + PIPELINE_LEN, retry_config())
+                    .await?;
+            if !matches!(current_state, Some(ValidatorState::Jailed))
+                || !matches!(pipeline_state, Some(ValidatorState::Jailed))
+            {
+                continue;
+            }
+
+            // the validator is frozen until the last slash is processed
+            // (the execution could be at the next epoch)
+            let last_slash_epoch =
+                get_validator_last_slash_epoch(ctx, &account.alias, retry_config()).await?;
+            if last_slash_epoch
+                .is_some_and(|slash_epoch| epoch <= slash_epoch + SLASH_PROCESSING_EPOCH_OFFSET)
+            {
+                continue;
+            }
+
+            tracing::info!("Jailed validator found: {}", account.alias.name);
+
+            let gas_payer = utils::get_gas_payer(account.public_keys.iter(), state);
+            let task_settings = TaskSettings::new(account.public_keys, gas_payer);
+
+            return Ok(vec![Task::UnjailValidator(
+                task::unjail_validator::UnjailValidator::builder()
+                    .target(account.alias)
+                    .settings(task_settings)
+                    .build(),
+            )]);
+        }
+
+        Ok(vec![])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done UnjailValidator", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal UnjailValidator", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped UnjailValidator", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected UnjailValidator", code),
+            CodeType::Failed => assert_unreachable_step!("Failed UnjailValidator", code),
+        }
+    }
+}
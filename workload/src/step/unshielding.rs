@@ -1,13 +1,14 @@
 use std::collections::BTreeSet;
 
 use crate::code::{Code, CodeType};
-use crate::constants::{DEFAULT_FEE, MAX_BATCH_TX_NUM, MIN_TRANSFER_BALANCE};
+use crate::constants::{DEFAULT_FEE, MAX_BATCH_TX_NUM};
 use crate::context::Ctx;
 use crate::error::{StepError, TaskError};
 use crate::state::State;
 use crate::step::utils::coin_flip;
 use crate::step::StepContext;
 use crate::task::{self, Task, TaskSettings};
+use crate::types::Alias;
 use crate::utils::{get_masp_epoch, retry_config};
 use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
 
@@ -22,26 +23,32 @@ impl StepContext for Unshielding {
     }
 
     async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
-        Ok(
-            state.at_least_masp_account_with_minimal_balance(1, MIN_TRANSFER_BALANCE)
-                && state.at_least_accounts(1),
-        )
+        Ok(state.any_masp_account_can_make_transfer() && state.at_least_accounts(1))
     }
 
     async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
-        let source_account = state
-            .random_masp_account_with_min_balance(vec![], MIN_TRANSFER_BALANCE)
+        let (source_account, denom) = state
+            .random_masp_account_with_token(vec![])
             .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
 
         let epoch = get_masp_epoch(ctx, retry_config()).await?;
         let target_account = state
             .random_account(vec![])
             .ok_or(StepError::BuildTask("No more accounts".to_string()))?;
-        let amount_account = state.get_shielded_balance_for(&source_account.alias);
+        let amount_account = state.get_shielded_balance_for(&source_account.alias, &denom);
         let amount = utils::random_between(1, amount_account / MAX_BATCH_TX_NUM);
 
-        let transparent_source_balance = state.get_balance_for(&source_account.alias.base());
-        let disposable_gas_payer = transparent_source_balance < DEFAULT_FEE || coin_flip(0.5);
+        let native_denom = Alias::nam().name;
+        let transparent_source_balance =
+            state.get_balance_for(&source_account.alias.base(), &native_denom);
+        let shielded_source_balance =
+            state.get_shielded_balance_for(&source_account.alias, &native_denom);
+        if transparent_source_balance < DEFAULT_FEE && shielded_source_balance < DEFAULT_FEE {
+            // Insufficient balance for the fee
+            return Ok(vec![]);
+        }
+        let disposable_gas_payer = transparent_source_balance < DEFAULT_FEE
+            || (shielded_source_balance >= DEFAULT_FEE && coin_flip(0.5));
         let task_settings = TaskSettings::new(
             BTreeSet::from([source_account.alias.base()]),
             if disposable_gas_payer {
@@ -56,6 +63,7 @@ impl StepContext for Unshielding {
                 .source(source_account.alias.spending_key())
                 .target(target_account.alias)
                 .amount(amount)
+                .denom(denom)
                 .epoch(epoch)
                 .settings(task_settings)
                 .build(),
@@ -67,6 +75,7 @@ impl StepContext for Unshielding {
             CodeType::Success => assert_always_step!("Done Unshielding", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal Unshielding", code),
             CodeType::Skip => assert_sometimes_step!("Skipped Unshielding", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected Unshielding", code),
             CodeType::Failed
                 if matches!(
                     code,
@@ -35,8 +35,10 @@ impl StepContext for UpdateAccount {
             .map(|account| account.alias)
             .collect::<BTreeSet<Alias>>();
 
+        let (signers, is_below_threshold) = utils::get_random_signers(&account);
         let gas_payer = utils::get_gas_payer(account.public_keys.iter(), state);
-        let task_settings = TaskSettings::new(account.public_keys, gas_payer);
+        let mut task_settings = TaskSettings::new(signers, gas_payer);
+        task_settings.is_below_threshold = is_below_threshold;
 
         Ok(vec![Task::UpdateAccount(
             task::update_account::UpdateAccount::builder()
@@ -53,6 +55,7 @@ impl StepContext for UpdateAccount {
             CodeType::Success => assert_always_step!("Done UpdateAccount", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal UpdateAccount", code),
             CodeType::Skip => assert_sometimes_step!("Skipped UpdateAccount", code),
+            CodeType::Rejected => assert_sometimes_step!("Rejected UpdateAccount", code),
             CodeType::Failed => assert_unreachable_step!("Failed UpdateAccount", code),
         }
     }
@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+
+use namada_sdk::dec::Dec;
+use rand::seq::IteratorRandom;
+
+use crate::code::{Code, CodeType};
+use crate::context::Ctx;
+use crate::error::StepError;
+use crate::state::State;
+use crate::step::StepContext;
+use crate::task::{self, Task, TaskSettings};
+use crate::utils::WorkloadRng;
+use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
+
+use super::utils;
+
+#[derive(Clone, Debug, Default)]
+pub struct UpdateStewardCommission;
+
+impl StepContext for UpdateStewardCommission {
+    fn name(&self) -> String {
+        "update-steward-commission".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.any_steward())
+    }
+
+    async fn build_task(&self, _ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let Some(steward_account) = state.random_steward() else {
+            return Ok(vec![]);
+        };
+
+        // the distribution shouldn't exceed 100%
+        let num_targets = utils::random_between(1, 3);
+        let mut remaining = 100;
+        let mut commission = BTreeMap::new();
+        for account in state
+            .accounts
+            .values()
+            .choose_multiple(&mut WorkloadRng, num_targets)
+        {
+            let rate = utils::random_between(0, remaining);
+            remaining -= rate;
+            commission.insert(
+                account.alias.clone(),
+                Dec::new(rate as i128, 2).expect("Dec creation shouldn't fail"),
+            );
+        }
+
+        let gas_payer = utils::get_gas_payer(steward_account.public_keys.iter(), state);
+        let task_settings = TaskSettings::new(steward_account.public_keys, gas_payer);
+
+        Ok(vec![Task::UpdateStewardCommission(
+            task::update_steward_commission::UpdateStewardCommission::builder()
+                .source(steward_account.alias)
+                .commission(commission)
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done UpdateStewardCommission", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal UpdateStewardCommission", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped UpdateStewardCommission", code),
+            CodeType::Rejected => {
+                assert_unreachable_step!("Rejected UpdateStewardCommission", code)
+            }
+            CodeType::Failed => assert_unreachable_step!("Failed UpdateStewardCommission", code),
+        }
+    }
+}
@@ -1,29 +1,31 @@
-use antithesis_sdk::random::AntithesisRng;
+use std::collections::BTreeSet;
+
 use rand::distributions::uniform::SampleUniform;
 use rand::distributions::{Alphanumeric, DistString};
 use rand::prelude::IteratorRandom;
 use rand::Rng;
 
 use crate::constants::DEFAULT_FEE;
-use crate::state::State;
+use crate::state::{Account, State};
 use crate::types::Alias;
+use crate::utils::WorkloadRng;
 
 pub(crate) fn coin_flip(p: f64) -> bool {
-    AntithesisRng.gen_bool(p)
+    WorkloadRng.gen_bool(p)
 }
 
 pub(crate) fn random_between<T: SampleUniform + std::cmp::PartialOrd>(from: T, to: T) -> T {
     if from == to {
         from
     } else {
-        AntithesisRng.gen_range(from..=to)
+        WorkloadRng.gen_range(from..=to)
     }
 }
 
 pub(crate) fn random_alias() -> Alias {
     format!(
         "workload-generator-{}",
-        Alphanumeric.sample_string(&mut AntithesisRng, 8)
+        Alphanumeric.sample_string(&mut WorkloadRng, 8)
     )
     .into()
 }
@@ -31,7 +33,7 @@ pub(crate) fn random_alias() -> Alias {
 pub fn get_random_string(length: usize) -> String {
     let mut result = String::new();
     for _ in 0..length {
-        let c = AntithesisRng.gen_range(0..62);
+        let c = WorkloadRng.gen_range(0..62);
         let c = if c < 26 {
             (b'a' + c) as char
         } else if c < 52 {
@@ -47,8 +49,8 @@ pub fn get_random_string(length: usize) -> String {
 pub fn get_gas_payer<'a>(candidates: impl IntoIterator<Item = &'a Alias>, state: &State) -> Alias {
     let payer = candidates
         .into_iter()
-        .filter(|alias| state.get_balance_for(alias) >= DEFAULT_FEE)
-        .choose(&mut AntithesisRng)
+        .filter(|alias| state.get_balance_for(alias, &Alias::nam().name) >= DEFAULT_FEE)
+        .choose(&mut WorkloadRng)
         .cloned()
         .unwrap_or(Alias::faucet());
 
@@ -57,6 +59,29 @@ pub fn get_gas_payer<'a>(candidates: impl IntoIterator<Item = &'a Alias>, state:
     payer
 }
 
+/// Random signers of the account and whether they are below the threshold:
+/// all keys, exactly the threshold keys, or fewer keys than the threshold
+pub fn get_random_signers(account: &Account) -> (BTreeSet<Alias>, bool) {
+    let threshold = account.threshold as usize;
+    let num_signers = if threshold > 1 && coin_flip(0.1) {
+        random_between(1, threshold - 1)
+    } else if coin_flip(0.3) {
+        threshold
+    } else {
+        account.public_keys.len()
+    };
+
+    let signers = account
+        .public_keys
+        .iter()
+        .cloned()
+        .choose_multiple(&mut WorkloadRng, num_signers)
+        .into_iter()
+        .collect();
+
+    (signers, num_signers < threshold)
+}
+
 #[macro_export]
 macro_rules! assert_always_step {
     ($msg:literal, $code:expr) => {
@@ -1,11 +1,14 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use crate::code::{Code, CodeType};
+use crate::constants::PIPELINE_LEN;
 use crate::context::Ctx;
 use crate::error::StepError;
 use crate::state::State;
 use crate::step::StepContext;
 use crate::task::{self, Task, TaskSettings};
 use crate::types::ProposalVote;
-use crate::utils::{get_epoch, retry_config};
+use crate::utils::{get_bond, get_epoch, retry_config};
 use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
 
 use super::utils;
@@ -30,16 +33,46 @@ impl StepContext for Vote {
         };
 
         // voter should have bonded at the start epoch
-        let start_epoch = state
+        let (start_epoch, end_epoch) = *state
             .proposals
             .get(&proposal_id)
-            .expect("Proposal should exist")
-            .0;
+            .expect("Proposal should exist");
         let Some(source_bond) = state.random_bond(start_epoch) else {
             return Ok(vec![]);
         };
         let source_account = state.get_account_by_alias(&source_bond.alias);
 
+        // the voting power is the bonded stake at the end epoch
+        let validators = state
+            .bonds
+            .get(&source_bond.alias)
+            .into_iter()
+            .flat_map(|bonds| bonds.keys())
+            .chain(
+                state
+                    .redelegations
+                    .get(&source_bond.alias)
+                    .into_iter()
+                    .flat_map(|redelegations| redelegations.keys()),
+            )
+            .collect::<BTreeSet<_>>();
+        let mut stakes = BTreeMap::new();
+        for validator in validators {
+            let stake = get_bond(
+                ctx,
+                &source_bond.alias,
+                validator,
+                end_epoch - PIPELINE_LEN,
+                retry_config(),
+            )
+            .await?;
+            let stake: u64 = stake
+                .to_string()
+                .parse()
+                .expect("Amount conversion shouldn't fail");
+            stakes.insert(validator.clone(), stake);
+        }
+
         let vote = if utils::coin_flip(0.5) {
             ProposalVote::Yay
         } else if utils::coin_flip(0.5) {
@@ -57,6 +90,7 @@ impl StepContext for Vote {
                 .source(source_account.alias)
                 .proposal_id(proposal_id)
                 .vote(vote)
+                .stakes(stakes)
                 .settings(task_settings)
                 .build(),
         )])
@@ -67,6 +101,7 @@ impl StepContext for Vote {
             CodeType::Success => assert_always_step!("Done Vote", code),
             CodeType::Fatal => assert_unreachable_step!("Fatal Vote", code),
             CodeType::Skip => assert_sometimes_step!("Skipped Vote", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected Vote", code),
             CodeType::Failed => assert_unreachable_step!("Failed Vote", code),
         }
     }
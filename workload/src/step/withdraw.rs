@@ -0,0 +1,55 @@
+use crate::code::{Code, CodeType};
+use crate::context::Ctx;
+use crate::error::StepError;
+use crate::state::State;
+use crate::step::StepContext;
+use crate::task::{self, Task, TaskSettings};
+use crate::utils::{get_epoch, retry_config};
+use crate::{assert_always_step, assert_sometimes_step, assert_unreachable_step};
+
+use super::utils;
+
+#[derive(Clone, Debug, Default)]
+pub struct Withdraw;
+
+impl StepContext for Withdraw {
+    fn name(&self) -> String {
+        "withdraw".to_string()
+    }
+
+    async fn is_valid(&self, _ctx: &Ctx, state: &State) -> Result<bool, StepError> {
+        Ok(state.any_unbond())
+    }
+
+    async fn build_task(&self, ctx: &Ctx, state: &State) -> Result<Vec<Task>, StepError> {
+        let current_epoch = get_epoch(ctx, retry_config()).await?;
+        let Some(source_unbond) = state.random_withdrawable_unbond(current_epoch) else {
+            return Ok(vec![]);
+        };
+        let source_account = state.get_account_by_alias(&source_unbond.alias);
+
+        let gas_payer = utils::get_gas_payer(source_account.public_keys.iter(), state);
+        let mut task_settings = TaskSettings::new(source_account.public_keys, gas_payer);
+        task_settings.gas_limit *= 3;
+
+        Ok(vec![Task::Withdraw(
+            task::withdraw::Withdraw::builder()
+                .source(source_account.alias)
+                .validator(source_unbond.validator)
+                .amount(source_unbond.amount)
+                .epoch(current_epoch)
+                .settings(task_settings)
+                .build(),
+        )])
+    }
+
+    fn assert(&self, code: &Code) {
+        match code.code_type() {
+            CodeType::Success => assert_always_step!("Done Withdraw", code),
+            CodeType::Fatal => assert_unreachable_step!("Fatal Withdraw", code),
+            CodeType::Skip => assert_sometimes_step!("Skipped Withdraw", code),
+            CodeType::Rejected => assert_unreachable_step!("Rejected Withdraw", code),
+            CodeType::Failed => assert_unreachable_step!("Failed Withdraw", code),
+        }
+    }
+}
@@ -4,13 +4,14 @@ use std::fmt::Display;
 use cosmrs::Any;
 use enum_dispatch::enum_dispatch;
 use namada_sdk::{args, signing::SigningTxData, tx::Tx};
+use serde_json::json;
 
 use crate::check::Check;
 use crate::constants::DEFAULT_GAS_LIMIT;
-use crate::context::Ctx;
+use crate::context::{CosmosCtx, Ctx};
 use crate::error::TaskError;
-use crate::state::State;
-use crate::types::{Alias, Fee, Height, MaspEpoch};
+use crate::state::{ProposalPayload, State};
+use crate::types::{Alias, Epoch, Fee, Height, MaspEpoch};
 use crate::utils::{
     execute_cosmos_tx, execute_tx, get_block_height, get_masp_epoch, get_masp_epoch_at_height,
     retry_config, wait_block_settlement, wait_cosmos_settlement, RetryConfig,
@@ -19,30 +20,41 @@ use crate::utils::{
 pub mod batch;
 pub mod become_validator;
 pub mod bond;
+pub mod change_commission;
 pub mod change_consensus_key;
 pub mod change_metadata;
+pub mod claim_masp_rewards;
 pub mod claim_rewards;
 pub mod deactivate_validator;
 pub mod default_proposal;
 pub mod faucet_transfer;
+pub mod ibc_nft_transfer;
 pub mod ibc_transfer;
 pub mod init_account;
 pub mod new_wallet_keypair;
+pub mod pgf_proposal;
 pub mod reactivate_validator;
 pub mod redelegate;
+pub mod resign_steward;
 pub mod shielded;
+pub mod shielded_fee_payment;
 pub mod shielding;
 pub mod transparent_transfer;
 pub mod unbond;
+pub mod unjail_validator;
 pub mod unshielding;
 pub mod update_account;
+pub mod update_steward_commission;
 pub mod vote;
+pub mod withdraw;
 
 #[derive(Clone, Debug)]
 pub struct TaskSettings {
     pub signers: BTreeSet<Alias>,
     pub gas_payer: Alias,
     pub gas_limit: u64,
+    // the signers don't reach the account threshold
+    pub is_below_threshold: bool,
 }
 
 impl TaskSettings {
@@ -51,6 +63,7 @@ impl TaskSettings {
             signers,
             gas_payer,
             gas_limit: DEFAULT_GAS_LIMIT,
+            is_below_threshold: false,
         }
     }
 
@@ -59,6 +72,7 @@ impl TaskSettings {
             signers: BTreeSet::from_iter(vec![Alias::faucet()]),
             gas_payer: Alias::faucet(),
             gas_limit: DEFAULT_GAS_LIMIT,
+            is_below_threshold: false,
         }
     }
 
@@ -67,6 +81,7 @@ impl TaskSettings {
             signers: BTreeSet::from_iter(vec![Alias::faucet()]),
             gas_payer: Alias::faucet(),
             gas_limit: DEFAULT_GAS_LIMIT * size as u64,
+            is_below_threshold: false,
         }
     }
 }
@@ -81,26 +96,75 @@ pub enum Task {
     IbcTransferRecv(ibc_transfer::IbcTransferRecv),
     IbcShieldingTransfer(ibc_transfer::IbcShieldingTransfer),
     IbcUnshieldingTransfer(ibc_transfer::IbcUnshieldingTransfer),
+    IbcTransferTimeout(ibc_transfer::IbcTransferTimeout),
+    IbcShieldedRoundTrip(ibc_transfer::IbcShieldedRoundTrip),
+    IbcTransferOverLimit(ibc_transfer::IbcTransferOverLimit),
+    IbcTransferForward(ibc_transfer::IbcTransferForward),
+    IbcNftTransferSend(ibc_nft_transfer::IbcNftTransferSend),
+    IbcNftTransferRecv(ibc_nft_transfer::IbcNftTransferRecv),
     Bond(bond::Bond),
     Unbond(unbond::Unbond),
+    Withdraw(withdraw::Withdraw),
     Redelegate(redelegate::Redelegate),
     ClaimRewards(claim_rewards::ClaimRewards),
+    ClaimMaspRewards(claim_masp_rewards::ClaimMaspRewards),
     Batch(batch::Batch),
     ShieldedTransfer(shielded::ShieldedTransfer),
+    ShieldedFeePayment(shielded_fee_payment::ShieldedFeePayment),
     Shielding(shielding::Shielding),
     InitAccount(init_account::InitAccount),
     Unshielding(unshielding::Unshielding),
     BecomeValidator(become_validator::BecomeValidator),
     ChangeMetadata(change_metadata::ChangeMetadata),
+    ChangeCommission(change_commission::ChangeCommission),
     ChangeConsensusKey(change_consensus_key::ChangeConsensusKey),
     DeactivateValidator(deactivate_validator::DeactivateValidator),
     ReactivateValidator(reactivate_validator::ReactivateValidator),
+    UnjailValidator(unjail_validator::UnjailValidator),
     UpdateAccount(update_account::UpdateAccount),
+    UpdateStewardCommission(update_steward_commission::UpdateStewardCommission),
+    ResignSteward(resign_steward::ResignSteward),
     DefaultProposal(default_proposal::DefaultProposal),
+    PgfFundingProposal(pgf_proposal::PgfFundingProposal),
+    PgfStewardProposal(pgf_proposal::PgfStewardProposal),
     Vote(vote::Vote),
 }
 
 impl Task {
+    /// The author, the start epoch, the activation epoch and the payload of the submitted proposal
+    pub fn proposal(&self) -> Option<(Alias, Epoch, Epoch, ProposalPayload)> {
+        match self {
+            Task::DefaultProposal(p) => Some((
+                p.source().clone(),
+                p.start_epoch(),
+                p.grace_epoch(),
+                p.payload(),
+            )),
+            Task::PgfFundingProposal(p) => Some((
+                p.source().clone(),
+                p.start_epoch(),
+                p.grace_epoch(),
+                p.payload(),
+            )),
+            Task::PgfStewardProposal(p) => Some((
+                p.source().clone(),
+                p.start_epoch(),
+                p.grace_epoch(),
+                p.payload(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Accounts signing or paying the gas for the task
+    pub fn accounts(&self) -> Option<BTreeSet<Alias>> {
+        self.task_settings().map(|settings| {
+            let mut accounts = settings.signers.clone();
+            accounts.insert(settings.gas_payer.clone());
+            accounts
+        })
+    }
+
     pub fn aggregate_fees(&self, fees: &mut HashMap<Alias, Fee>, is_successful: bool) {
         match self {
             Task::Batch(batch) => {
@@ -159,6 +223,12 @@ pub trait TaskContext {
     #[allow(async_fn_in_trait)]
     async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError>;
 
+    /// Whether the rejection of the tx with the errors is expected. The checks
+    /// confirm the tx had no effect.
+    fn is_rejection_expected(&self, _errors: &str) -> bool {
+        false
+    }
+
     #[allow(async_fn_in_trait)]
     async fn execute(&self, ctx: &Ctx) -> Result<Height, TaskError> {
         let retry_config = retry_config();
@@ -167,9 +237,30 @@ pub trait TaskContext {
         let start_height = get_block_height(ctx, retry_config)
             .await
             .unwrap_or_default();
+        let is_below_threshold = self
+            .task_settings()
+            .is_some_and(|settings| settings.is_below_threshold);
 
         match execute_tx(ctx, tx, signing_data, &tx_args).await {
             Ok(height) => {
+                antithesis_sdk::assert_always!(
+                    !is_below_threshold,
+                    "Tx with insufficient signatures wasn't accepted",
+                    &json!({ "task": self.summary(), "height": height })
+                );
+                wait_block_settlement(ctx, height, retry_config).await;
+                Ok(height)
+            }
+            // The rejection is expected when the signers don't reach the threshold
+            Err(TaskError::Execution {
+                err,
+                height,
+                is_invalid_sig: true,
+            }) if is_below_threshold => {
+                wait_block_settlement(ctx, height, retry_config).await;
+                Err(TaskError::InsufficientSignatures { err, height })
+            }
+            Err(TaskError::Execution { err, height, .. }) if self.is_rejection_expected(&err) => {
                 wait_block_settlement(ctx, height, retry_config).await;
                 Ok(height)
             }
@@ -228,10 +319,10 @@ pub trait TaskContext {
     }
 
     #[allow(async_fn_in_trait)]
-    async fn execute_cosmos_tx(&self, ctx: &Ctx) -> Result<Height, TaskError> {
+    async fn execute_cosmos_tx(&self, ctx: &Ctx, cosmos: &CosmosCtx) -> Result<Height, TaskError> {
         let any_msg = self.build_cosmos_tx(ctx).await?;
-        let height = execute_cosmos_tx(ctx, any_msg).await?;
-        wait_cosmos_settlement(ctx, height).await;
+        let height = execute_cosmos_tx(cosmos, any_msg).await?;
+        wait_cosmos_settlement(cosmos, height).await;
         Ok(height)
     }
 
@@ -244,6 +335,9 @@ pub trait TaskContext {
 
     fn update_state(&self, state: &mut State);
 
+    /// Update the state for the txs executed before the task failed
+    fn update_failed_state(&self, _err: &TaskError, _state: &mut State) {}
+
     fn update_stats(&self, state: &mut State) {
         state
             .stats
@@ -10,8 +10,8 @@ use crate::state::State;
 use crate::task::{Task, TaskContext, TaskSettings};
 use crate::types::{Alias, Height};
 use crate::utils::{
-    execute_tx, get_balance, get_block_height, get_bond, get_shielded_balance, merge_tx,
-    retry_config, wait_block_settlement, RetryConfig,
+    execute_tx, get_balance, get_block_height, get_bond, get_shielded_balance, get_unbonds,
+    merge_tx, retry_config, wait_block_settlement, RetryConfig,
 };
 
 #[derive(Clone, Debug, TypedBuilder)]
@@ -108,33 +108,46 @@ impl TaskContext for Batch {
         }
 
         let mut prepared_checks = vec![];
-        let mut balances: HashMap<Alias, i64> = HashMap::default();
-        let mut shielded_balances: HashMap<Alias, i64> = HashMap::default();
+        let mut balances: HashMap<(Alias, String), i64> = HashMap::default();
+        let mut shielded_balances: HashMap<(Alias, String), i64> = HashMap::default();
         let mut bonds: HashMap<String, (u64, i64)> = HashMap::default();
+        let mut unbonds: HashMap<String, (u64, u64)> = HashMap::default();
         for check in checks {
             match check {
                 Check::RevealPk(_) => prepared_checks.push(check),
                 Check::BalanceSource(balance_source) => {
                     balances
-                        .entry(balance_source.target().clone())
+                        .entry((
+                            balance_source.target().clone(),
+                            balance_source.denom().to_string(),
+                        ))
                         .and_modify(|balance| *balance -= balance_source.amount() as i64)
                         .or_insert(-(balance_source.amount() as i64));
                 }
                 Check::BalanceTarget(balance_target) => {
                     balances
-                        .entry(balance_target.target().clone())
+                        .entry((
+                            balance_target.target().clone(),
+                            balance_target.denom().to_string(),
+                        ))
                         .and_modify(|balance| *balance += balance_target.amount() as i64)
                         .or_insert(balance_target.amount() as i64);
                 }
                 Check::BalanceShieldedSource(balance_source) => {
                     shielded_balances
-                        .entry(balance_source.target().base().clone())
+                        .entry((
+                            balance_source.target().base(),
+                            balance_source.denom().to_string(),
+                        ))
                         .and_modify(|balance| *balance -= balance_source.amount() as i64)
                         .or_insert(-(balance_source.amount() as i64));
                 }
                 Check::BalanceShieldedTarget(balance_target) => {
                     shielded_balances
-                        .entry(balance_target.target().base().clone())
+                        .entry((
+                            balance_target.target().base(),
+                            balance_target.denom().to_string(),
+                        ))
                         .and_modify(|balance| *balance += balance_target.amount() as i64)
                         .or_insert(balance_target.amount() as i64);
                 }
@@ -162,6 +175,18 @@ impl TaskContext for Batch {
                         })
                         .or_insert((bond_decrease.epoch(), -(bond_decrease.amount() as i64)));
                 }
+                Check::UnbondIncrease(unbond_increase) => {
+                    unbonds
+                        .entry(format!(
+                            "{}@{}",
+                            unbond_increase.target().name,
+                            unbond_increase.validator()
+                        ))
+                        .and_modify(|(_withdraw_epoch, unbond_amount)| {
+                            *unbond_amount += unbond_increase.amount()
+                        })
+                        .or_insert((unbond_increase.withdraw_epoch(), unbond_increase.amount()));
+                }
                 _ => {
                     return Err(TaskError::BuildCheck(format!(
                         "Unexpected check happened: {check}"
@@ -170,15 +195,14 @@ impl TaskContext for Batch {
             }
         }
 
-        let denom = Alias::nam().name;
-        for (alias, amount) in balances {
+        for ((alias, denom), amount) in balances {
             let (_, pre_balance) = get_balance(ctx, &alias, &denom, retry_config).await?;
             if amount >= 0 {
                 prepared_checks.push(Check::BalanceTarget(
                     check::balance_target::BalanceTarget::builder()
                         .target(alias)
                         .pre_balance(pre_balance)
-                        .denom(denom.clone())
+                        .denom(denom)
                         .amount(amount.unsigned_abs())
                         .build(),
                 ));
@@ -187,7 +211,7 @@ impl TaskContext for Batch {
                     check::balance_source::BalanceSource::builder()
                         .target(alias)
                         .pre_balance(pre_balance)
-                        .denom(denom.clone())
+                        .denom(denom)
                         .amount(amount.unsigned_abs())
                         .build(),
                 ));
@@ -221,7 +245,22 @@ impl TaskContext for Batch {
             }
         }
 
-        for (alias, amount) in shielded_balances {
+        for (key, (withdraw_epoch, amount)) in unbonds {
+            let (source, validator) = key.split_once('@').unwrap();
+            let pre_unbonds =
+                get_unbonds(ctx, &Alias::from(source), validator, retry_config).await?;
+            prepared_checks.push(Check::UnbondIncrease(
+                check::unbond_increase::UnbondIncrease::builder()
+                    .target(Alias::from(source))
+                    .validator(validator.to_owned())
+                    .pre_unbonds(pre_unbonds)
+                    .withdraw_epoch(withdraw_epoch)
+                    .amount(amount)
+                    .build(),
+            ));
+        }
+
+        for ((alias, denom), amount) in shielded_balances {
             // shielded-sync has been already done in each task.build_checks()
             let pre_balance = get_shielded_balance(ctx, &alias, &denom, retry_config)
                 .await?
@@ -231,7 +270,7 @@ impl TaskContext for Batch {
                     check::balance_shielded_target::BalanceShieldedTarget::builder()
                         .target(alias.payment_address())
                         .pre_balance(pre_balance)
-                        .denom(denom.clone())
+                        .denom(denom)
                         .amount(amount.unsigned_abs())
                         .build(),
                 ));
@@ -240,7 +279,7 @@ impl TaskContext for Batch {
                     check::balance_shielded_source::BalanceShieldedSource::builder()
                         .target(alias.spending_key())
                         .pre_balance(pre_balance)
-                        .denom(denom.clone())
+                        .denom(denom)
                         .amount(amount.unsigned_abs())
                         .build(),
                 ));
@@ -26,6 +26,12 @@ pub struct Bond {
     settings: TaskSettings,
 }
 
+impl Bond {
+    pub fn source(&self) -> &Alias {
+        &self.source
+    }
+}
+
 impl TaskContext for Bond {
     fn name(&self) -> String {
         "bond".to_string()
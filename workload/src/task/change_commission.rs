@@ -0,0 +1,116 @@
+use namada_sdk::args::{self, TxBuilder};
+use namada_sdk::signing::SigningTxData;
+use namada_sdk::tx::data::GasLimit;
+use namada_sdk::tx::Tx;
+use namada_sdk::Namada;
+use typed_builder::TypedBuilder;
+
+use crate::check::{self, Check};
+use crate::context::Ctx;
+use crate::error::TaskError;
+use crate::state::State;
+use crate::task::{TaskContext, TaskSettings};
+use crate::types::{Alias, CommissionRate};
+use crate::utils::RetryConfig;
+
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct ChangeCommission {
+    source: Alias,
+    rate: CommissionRate,
+    pre_rate: CommissionRate,
+    within_max_change: bool,
+    settings: TaskSettings,
+}
+
+impl TaskContext for ChangeCommission {
+    fn name(&self) -> String {
+        "change-commission".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "change-commission/{}/{}/{}",
+            self.source.name,
+            self.rate,
+            if self.within_max_change {
+                "valid"
+            } else {
+                "invalid"
+            }
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        let wallet = ctx.namada.wallet.read().await;
+        let source_address = wallet
+            .find_address(&self.source.name)
+            .ok_or_else(|| TaskError::Wallet(format!("No source address: {}", self.source.name)))?;
+        let fee_payer = wallet
+            .find_public_key(&self.settings.gas_payer.name)
+            .map_err(|e| TaskError::Wallet(e.to_string()))?;
+
+        let mut change_commission_tx_builder = ctx
+            .namada
+            .new_change_commission_rate(self.rate, source_address.into_owned());
+
+        change_commission_tx_builder =
+            change_commission_tx_builder.gas_limit(GasLimit::from(self.settings.gas_limit));
+        change_commission_tx_builder = change_commission_tx_builder.wrapper_fee_payer(fee_payer);
+        // Skip the client-side validation to submit a change beyond the max change
+        change_commission_tx_builder = change_commission_tx_builder.force(true);
+
+        let mut signing_keys = vec![];
+        for signer in &self.settings.signers {
+            let public_key = wallet
+                .find_public_key(&signer.name)
+                .map_err(|e| TaskError::Wallet(e.to_string()))?;
+            signing_keys.push(public_key)
+        }
+        change_commission_tx_builder = change_commission_tx_builder.signing_keys(signing_keys);
+        drop(wallet);
+
+        let (change_commission, signing_data) = change_commission_tx_builder
+            .build(&ctx.namada)
+            .await
+            .map_err(|e| TaskError::BuildTx(e.to_string()))?;
+
+        Ok((
+            change_commission,
+            vec![signing_data],
+            change_commission_tx_builder.tx,
+        ))
+    }
+
+    // The rejection is expected for a change beyond the max change, which is
+    // `CommissionRateChangeError::RateChangeTooLarge` of PoS.
+    // The check confirms the commission rate wasn't updated.
+    fn is_rejection_expected(&self, errors: &str) -> bool {
+        !self.within_max_change && errors.contains("is too large for validator")
+    }
+
+    async fn build_checks(
+        &self,
+        _ctx: &Ctx,
+        _retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        let expected_rate = if self.within_max_change {
+            self.rate
+        } else {
+            self.pre_rate
+        };
+
+        Ok(vec![Check::ValidatorCommission(
+            check::validator_commission::ValidatorCommission::builder()
+                .target(self.source.clone())
+                .requested_rate(self.rate)
+                .expected_rate(expected_rate)
+                .build(),
+        )])
+    }
+
+    fn update_state(&self, _state: &mut State) {}
+}
@@ -0,0 +1,86 @@
+use namada_sdk::args;
+use namada_sdk::signing::SigningTxData;
+use namada_sdk::tx::Tx;
+use typed_builder::TypedBuilder;
+
+use crate::check::{self, Check};
+use crate::context::Ctx;
+use crate::error::TaskError;
+use crate::state::State;
+use crate::task::shielded::ShieldedTransfer;
+use crate::task::{TaskContext, TaskSettings};
+use crate::types::{Alias, Amount, Height, MaspEpoch};
+use crate::utils::{get_shielded_balance, shielded_sync_with_retry, RetryConfig};
+
+/// Apply the MASP conversions to the notes of the account by spending all of
+/// them to its own payment address
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct ClaimMaspRewards {
+    source: Alias,
+    amount: Amount,
+    epoch: MaspEpoch,
+    settings: TaskSettings,
+}
+
+impl ClaimMaspRewards {
+    fn self_transfer(&self) -> ShieldedTransfer {
+        ShieldedTransfer::builder()
+            .source(self.source.spending_key())
+            .target(self.source.payment_address())
+            .amount(self.amount)
+            .denom(Alias::nam().name)
+            .epoch(self.epoch)
+            .settings(self.settings.clone())
+            .build()
+    }
+}
+
+impl TaskContext for ClaimMaspRewards {
+    fn name(&self) -> String {
+        "claim-masp-rewards".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!("claim-masp-rewards/{}/{}", self.source.name, self.amount)
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        self.self_transfer().build_tx(ctx).await
+    }
+
+    async fn execute(&self, ctx: &Ctx) -> Result<Height, TaskError> {
+        self.execute_shielded_tx(ctx, self.epoch).await
+    }
+
+    async fn build_checks(
+        &self,
+        ctx: &Ctx,
+        retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        let source = self.source.spending_key();
+        shielded_sync_with_retry(ctx, &source, None, false, retry_config).await?;
+
+        let denom = Alias::nam().name;
+        let pre_balance = get_shielded_balance(ctx, &source, &denom, retry_config)
+            .await?
+            .unwrap_or_default();
+
+        // Applying the conversions doesn't change the exchanged balance
+        Ok(vec![Check::BalanceShieldedSource(
+            check::balance_shielded_source::BalanceShieldedSource::builder()
+                .target(source)
+                .pre_balance(pre_balance)
+                .denom(denom)
+                .amount(0)
+                .build(),
+        )])
+    }
+
+    fn update_state(&self, _state: &mut State) {
+        // The rewards have been already accrued by syncing the MASP rewards
+    }
+}
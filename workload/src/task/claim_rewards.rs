@@ -98,6 +98,6 @@ impl TaskContext for ClaimRewards {
     }
 
     fn update_state(&self, state: &mut State) {
-        state.increase_balance(&self.source, self.amount);
+        state.increase_balance(&self.source, &Alias::nam().name, self.amount);
     }
 }
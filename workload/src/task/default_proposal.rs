@@ -12,27 +12,158 @@ use crate::check::{self, Check};
 use crate::constants::PROPOSAL_DEPOSIT;
 use crate::context::Ctx;
 use crate::error::TaskError;
-use crate::state::State;
+use crate::state::{ProposalPayload, State};
 use crate::task::{TaskContext, TaskSettings};
 use crate::types::{Alias, Epoch};
 use crate::utils::{get_balance, RetryConfig};
 
+/// Offset of the key and the value in the memory, apart from the tx data
+/// written at the beginning of the memory
+const PROPOSAL_WASM_DATA_OFFSET: u32 = 0x8000;
+
+/// Minimal proposal code which writes `0x01` to the storage key:
+/// `(module
+///   (import "env" "memory" (memory 1))
+///   (import "env" "namada_tx_write" (func (param i64 i64 i64 i64)))
+///   (func (export "_apply_tx") (param i64 i64) (result i64)
+///     (call 0 (i64.const offset) (i64.const key_len)
+///       (i64.const offset + key_len) (i64.const 1))
+///     i64.const 1)
+///   (data (i32.const offset) "<key>\01"))`
+fn proposal_wasm(key: &str) -> Vec<u8> {
+    let offset = PROPOSAL_WASM_DATA_OFFSET as i64;
+    let key_len = key.len() as i64;
+
+    let mut code = vec![0x00]; // no local
+    for arg in [offset, key_len, offset + key_len, 1] {
+        code.push(0x42); // i64.const
+        code.extend(sleb128(arg));
+    }
+    code.extend([0x10, 0x00]); // call namada_tx_write
+    code.extend([0x42, 0x01, 0x0b]); // i64.const 1, end
+
+    let mut data = vec![0x01, 0x00, 0x41]; // one active segment at i32.const
+    data.extend(sleb128(offset));
+    data.push(0x0b);
+    data.extend(uleb128(key.len() as u64 + 1));
+    data.extend(key.as_bytes());
+    data.push(0x01);
+
+    let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00]; // header
+    let sections: [(u8, Vec<u8>); 6] = [
+        (
+            0x01, // type section: (i64 i64 i64 i64) -> () and (i64 i64) -> i64
+            vec![
+                0x02, 0x60, 0x04, 0x7e, 0x7e, 0x7e, 0x7e, 0x00, 0x60, 0x02, 0x7e, 0x7e, 0x01, 0x7e,
+            ],
+        ),
+        (
+            0x02, // import section: memory and namada_tx_write of type 0
+            [
+                vec![0x02],
+                wasm_name("env"),
+                wasm_name("memory"),
+                vec![0x02, 0x00, 0x01],
+                wasm_name("env"),
+                wasm_name("namada_tx_write"),
+                vec![0x00, 0x00],
+            ]
+            .concat(),
+        ),
+        (0x03, vec![0x01, 0x01]), // function section
+        (
+            0x07, // export section
+            [vec![0x01], wasm_name("_apply_tx"), vec![0x00, 0x01]].concat(),
+        ),
+        (
+            0x0a, // code section
+            [vec![0x01], uleb128(code.len() as u64), code].concat(),
+        ),
+        (0x0b, data), // data section
+    ];
+    for (id, content) in sections {
+        wasm.push(id);
+        wasm.extend(uleb128(content.len() as u64));
+        wasm.extend(content);
+    }
+
+    wasm
+}
+
+fn wasm_name(name: &str) -> Vec<u8> {
+    [uleb128(name.len() as u64), name.as_bytes().to_vec()].concat()
+}
+
+fn uleb128(mut value: u64) -> Vec<u8> {
+    let mut bytes = vec![];
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn sleb128(mut value: i64) -> Vec<u8> {
+    let mut bytes = vec![];
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
 #[derive(Clone, Debug, TypedBuilder)]
 pub struct DefaultProposal {
     source: Alias,
     start_epoch: Epoch,
     end_epoch: Epoch,
     grace_epoch: Epoch,
+    /// Storage key written by the proposal code
+    #[builder(default)]
+    wasm_key: Option<String>,
     settings: TaskSettings,
 }
 
+impl DefaultProposal {
+    pub fn source(&self) -> &Alias {
+        &self.source
+    }
+
+    pub fn start_epoch(&self) -> Epoch {
+        self.start_epoch
+    }
+
+    pub fn grace_epoch(&self) -> Epoch {
+        self.grace_epoch
+    }
+
+    pub fn payload(&self) -> ProposalPayload {
+        match &self.wasm_key {
+            Some(key) => ProposalPayload::Wasm { key: key.clone() },
+            None => ProposalPayload::Default,
+        }
+    }
+}
+
 impl TaskContext for DefaultProposal {
     fn name(&self) -> String {
         "default-proposal".to_string()
     }
 
     fn summary(&self) -> String {
-        format!("default-proposal/{}", self.source.name)
+        if let Some(key) = &self.wasm_key {
+            format!("default-proposal/{}/wasm/{key}", self.source.name)
+        } else {
+            format!("default-proposal/{}", self.source.name)
+        }
     }
 
     fn task_settings(&self) -> Option<&TaskSettings> {
@@ -56,7 +187,9 @@ impl TaskContext for DefaultProposal {
                 voting_end_epoch: self.end_epoch.into(),
                 activation_epoch: self.grace_epoch.into(),
             },
-            data: if self.start_epoch % 2 == 0 {
+            data: if let Some(key) = &self.wasm_key {
+                Some(proposal_wasm(key))
+            } else if self.start_epoch % 2 == 0 {
                 Some(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10])
             } else {
                 None
@@ -113,7 +246,7 @@ impl TaskContext for DefaultProposal {
     }
 
     fn update_state(&self, state: &mut State) {
-        state.decrease_balance(&self.source, PROPOSAL_DEPOSIT);
+        state.decrease_balance(&self.source, &Alias::nam().name, PROPOSAL_DEPOSIT);
         // proposal will be added later
     }
 }
@@ -109,6 +109,6 @@ impl TaskContext for FaucetTransfer {
     }
 
     fn update_state(&self, state: &mut State) {
-        state.increase_balance(&self.target, self.amount);
+        state.increase_balance(&self.target, &Alias::nam().name, self.amount);
     }
 }
@@ -0,0 +1,319 @@
+use cosmrs::Any;
+use namada_sdk::args::{self, InputAmount, TxBuilder};
+use namada_sdk::ibc::core::host::types::identifiers::ChannelId;
+use namada_sdk::masp_primitives::transaction::components::sapling::builder::RngBuildParams;
+use namada_sdk::signing::SigningTxData;
+use namada_sdk::tx::data::GasLimit;
+use namada_sdk::tx::Tx;
+use namada_sdk::Namada;
+use namada_sdk::{token, TransferSource};
+use rand::rngs::OsRng;
+use typed_builder::TypedBuilder;
+
+use crate::check::{self, Check};
+use crate::constants::IBC_TIMEOUT_HEIGHT_OFFSET;
+use crate::context::Ctx;
+use crate::error::TaskError;
+use crate::state::State;
+use crate::task::{TaskContext, TaskSettings};
+use crate::types::{Alias, Height};
+use crate::utils::{
+    build_cosmos_nft_mint, build_cosmos_nft_transfer, execute_cosmos_tx, execute_tx,
+    get_block_height, get_ibc_packet_sequence, ibc_token_address, is_ibc_transfer_successful,
+    is_recv_packet, nft_class_and_token_id, nft_port_id, nft_trace, retry_config,
+    wait_block_settlement, wait_cosmos_settlement, RetryConfig,
+};
+
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct IbcNftTransferSend {
+    source: Alias,
+    receiver: Alias,
+    trace: String,
+    src_channel_id: ChannelId,
+    dest_channel_id: ChannelId,
+    settings: TaskSettings,
+}
+
+impl TaskContext for IbcNftTransferSend {
+    fn name(&self) -> String {
+        "ibc-nft-transfer-send".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "ibc-nft-transfer-send/{}/{}/'{}'",
+            self.source.name, self.receiver.name, self.trace
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        let mut bparams = RngBuildParams::new(OsRng);
+
+        let wallet = ctx.namada.wallet.read().await;
+
+        let source_address = wallet
+            .find_address(&self.source.name)
+            .ok_or_else(|| TaskError::Wallet(format!("No source address: {}", self.source.name)))?;
+        let fee_payer = wallet
+            .find_public_key(&self.settings.gas_payer.name)
+            .map_err(|e| TaskError::Wallet(e.to_string()))?;
+        // NFT is a token whose amount is 1 without decimals
+        let amount = InputAmount::Unvalidated(token::DenominatedAmount::new(
+            token::Amount::from_u64(1),
+            0u8.into(),
+        ));
+
+        let source = TransferSource::Address(source_address.into_owned());
+        let mut tx_builder = ctx.namada.new_ibc_transfer(
+            source,
+            self.receiver.name.clone(),
+            ibc_token_address(&self.trace),
+            amount,
+            self.src_channel_id.clone(),
+            false,
+        );
+        tx_builder.port_id = nft_port_id();
+        tx_builder = tx_builder.gas_limit(GasLimit::from(self.settings.gas_limit));
+        tx_builder = tx_builder.wrapper_fee_payer(fee_payer);
+        let mut signing_keys = vec![];
+        for signer in &self.settings.signers {
+            let public_key = wallet
+                .find_public_key(&signer.name)
+                .map_err(|e| TaskError::Wallet(e.to_string()))?;
+            signing_keys.push(public_key)
+        }
+        tx_builder = tx_builder.signing_keys(signing_keys);
+        drop(wallet);
+
+        let (transfer_tx, signing_data, _) = tx_builder
+            .build(&ctx.namada, &mut bparams)
+            .await
+            .map_err(|e| TaskError::BuildTx(e.to_string()))?;
+
+        Ok((transfer_tx, vec![signing_data], tx_builder.tx))
+    }
+
+    async fn execute(&self, ctx: &Ctx) -> Result<Height, TaskError> {
+        let retry_config = retry_config();
+        let (tx, signing_data, tx_args) = self.build_tx(ctx).await?;
+
+        let start_height = get_block_height(ctx, retry_config)
+            .await
+            .unwrap_or_default();
+
+        let height = match execute_tx(ctx, tx, signing_data, &tx_args).await {
+            Ok(height) => height,
+            Err(e) => {
+                wait_block_settlement(ctx, start_height, retry_config).await;
+                return Err(e);
+            }
+        };
+
+        // Wait for the NFT transfer completion
+        let sequence = get_ibc_packet_sequence(
+            ctx,
+            &self.src_channel_id,
+            &self.source,
+            &self.receiver,
+            height,
+            true,
+            retry_config,
+        )
+        .await?;
+        if is_ibc_transfer_successful(
+            ctx,
+            &nft_port_id(),
+            &self.src_channel_id,
+            &self.dest_channel_id,
+            sequence.into(),
+            retry_config,
+        )
+        .await?
+        {
+            Ok(height)
+        } else {
+            // The destination rejected the transfer or the packet timed out
+            let err = format!(
+                "Sending NFT failed: {} from {} to {}",
+                self.trace, self.source.name, self.receiver.name
+            );
+            Err(TaskError::IbcTransfer(err))
+        }
+    }
+
+    async fn build_checks(
+        &self,
+        _ctx: &Ctx,
+        _retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        let (class_id, token_id) = nft_class_and_token_id(&self.trace);
+
+        let source_check = Check::NftOwner(
+            check::nft_owner::NftOwner::builder()
+                .owner(self.source.clone())
+                .trace(self.trace.clone())
+                .is_owner(false)
+                .build(),
+        );
+        let receiver_check = Check::ForeignNftOwner(
+            check::foreign_nft_owner::ForeignNftOwner::builder()
+                .channel_id(self.src_channel_id.clone())
+                .owner(self.receiver.clone())
+                .class_id(class_id)
+                .token_id(token_id)
+                .is_owner(true)
+                .build(),
+        );
+
+        Ok(vec![source_check, receiver_check])
+    }
+
+    fn update_state(&self, state: &mut State) {
+        let (_, token_id) = nft_class_and_token_id(&self.trace);
+        state.remove_nft(&self.source, &self.trace);
+        state.add_foreign_nft(&self.receiver, &token_id);
+    }
+}
+
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct IbcNftTransferRecv {
+    sender: Alias,
+    target: Alias,
+    class_id: String,
+    token_id: String,
+    /// Mint the NFT on Cosmos before the transfer
+    is_new: bool,
+    src_channel_id: ChannelId,
+    dest_channel_id: ChannelId,
+    settings: TaskSettings,
+}
+
+impl IbcNftTransferRecv {
+    fn trace(&self) -> String {
+        nft_trace(&self.dest_channel_id, &self.class_id, &self.token_id)
+    }
+}
+
+impl TaskContext for IbcNftTransferRecv {
+    fn name(&self) -> String {
+        "ibc-nft-transfer-recv".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "ibc-nft-transfer-recv/{}/{}/{}/{}",
+            self.sender.name, self.target.name, self.class_id, self.token_id
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, _ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        unreachable!("Namada tx shouldn't be built")
+    }
+
+    async fn execute(&self, ctx: &Ctx) -> Result<Height, TaskError> {
+        let retry_config = retry_config();
+
+        let cosmos = ctx.counterparty(&self.dest_channel_id)?;
+        if self.is_new {
+            let mint_msg = build_cosmos_nft_mint(&self.sender.name, &self.class_id, &self.token_id);
+            let height = execute_cosmos_tx(cosmos, mint_msg).await?;
+            wait_cosmos_settlement(cosmos, height).await;
+        }
+
+        let height = self.execute_cosmos_tx(ctx, cosmos).await?;
+
+        // Check the packet receiving on Namada
+        let sequence = get_ibc_packet_sequence(
+            ctx,
+            &self.dest_channel_id,
+            &self.sender,
+            &self.target,
+            height,
+            false,
+            retry_config,
+        )
+        .await?;
+        let (is_successful, recv_height) = is_recv_packet(
+            ctx,
+            &nft_port_id(),
+            &self.src_channel_id,
+            &self.dest_channel_id,
+            sequence.into(),
+            retry_config,
+        )
+        .await?;
+        if is_successful {
+            wait_block_settlement(ctx, recv_height, retry_config).await;
+            Ok(recv_height)
+        } else {
+            // Receiving failed or timed out
+            let err = format!(
+                "Receiving NFT failed: {}/{} from {} to {}",
+                self.class_id, self.token_id, self.sender.name, self.target.name
+            );
+            Err(TaskError::IbcTransfer(err))
+        }
+    }
+
+    async fn build_cosmos_tx(&self, ctx: &Ctx) -> Result<Any, TaskError> {
+        let wallet = ctx.namada.wallet.read().await;
+        let target_address = wallet
+            .find_address(&self.target.name)
+            .ok_or_else(|| TaskError::Wallet(format!("No target address: {}", self.target.name)))?
+            .into_owned();
+        drop(wallet);
+
+        let namada_timeout_height =
+            get_block_height(ctx, retry_config()).await? + IBC_TIMEOUT_HEIGHT_OFFSET;
+
+        let any_msg = build_cosmos_nft_transfer(
+            &self.sender.name,
+            &target_address.to_string(),
+            &self.class_id,
+            &self.token_id,
+            &self.src_channel_id,
+            namada_timeout_height,
+        );
+
+        Ok(any_msg)
+    }
+
+    async fn build_checks(
+        &self,
+        _ctx: &Ctx,
+        _retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        let target_check = Check::NftOwner(
+            check::nft_owner::NftOwner::builder()
+                .owner(self.target.clone())
+                .trace(self.trace())
+                .is_owner(true)
+                .build(),
+        );
+        // the NFT is escrowed on Cosmos
+        let sender_check = Check::ForeignNftOwner(
+            check::foreign_nft_owner::ForeignNftOwner::builder()
+                .channel_id(self.dest_channel_id.clone())
+                .owner(self.sender.clone())
+                .class_id(self.class_id.clone())
+                .token_id(self.token_id.clone())
+                .is_owner(false)
+                .build(),
+        );
+
+        Ok(vec![target_check, sender_check])
+    }
+
+    fn update_state(&self, state: &mut State) {
+        state.remove_foreign_nft(&self.sender, &self.token_id);
+        state.add_nft(&self.target, &self.trace());
+    }
+}
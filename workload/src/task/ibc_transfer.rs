@@ -1,7 +1,7 @@
 use cosmrs::Any;
 use namada_sdk::args::{self, InputAmount, TxBuilder};
 use namada_sdk::ibc::convert_masp_tx_to_ibc_memo;
-use namada_sdk::ibc::core::host::types::identifiers::ChannelId;
+use namada_sdk::ibc::core::host::types::identifiers::{ChannelId, PortId};
 use namada_sdk::masp_primitives;
 use namada_sdk::masp_primitives::transaction::components::sapling::builder::RngBuildParams;
 use namada_sdk::masp_primitives::zip32::PseudoExtendedKey;
@@ -14,19 +14,34 @@ use rand::rngs::OsRng;
 use typed_builder::TypedBuilder;
 
 use crate::check::{self, Check};
-use crate::constants::IBC_TIMEOUT_HEIGHT_OFFSET;
+use crate::constants::{IBC_EXPIRED_TIMEOUT_SEC_OFFSET, IBC_TIMEOUT_HEIGHT_OFFSET};
 use crate::context::Ctx;
 use crate::error::TaskError;
 use crate::state::State;
 use crate::task::{TaskContext, TaskSettings};
 use crate::types::{Alias, Amount, Height, MaspEpoch};
 use crate::utils::{
-    base_denom, build_cosmos_ibc_transfer, cosmos_denom_hash, execute_tx, gen_shielding_tx,
-    get_balance, get_block_height, get_ibc_packet_sequence, get_shielded_balance, ibc_denom,
-    ibc_token_address, is_ibc_transfer_successful, is_native_denom, is_recv_packet, retry_config,
-    shielded_sync_with_retry, wait_block_settlement, RetryConfig,
+    base_denom, build_cosmos_ibc_transfer, cosmos_denom, cosmos_denom_hash, execute_tx,
+    gen_shielding_tx, get_balance, get_block_height, get_cosmos_balance, get_ibc_packet_sequence,
+    get_shielded_balance, ibc_denom, ibc_token_address, is_ibc_transfer_successful,
+    is_native_denom, is_recv_packet, pfm_memo, retry_config, shielded_sync_with_retry,
+    wait_block_settlement, wait_ibc_timeout, RetryConfig,
 };
 
+/// The denom on Cosmos of the token sent from Namada
+async fn sent_cosmos_denom(
+    ctx: &Ctx,
+    dest_channel_id: &ChannelId,
+    denom: &str,
+) -> Result<String, TaskError> {
+    let trace = if is_native_denom(denom) {
+        ibc_denom(dest_channel_id, denom)
+    } else {
+        base_denom(denom)
+    };
+    Ok(cosmos_denom(ctx, &trace).await?)
+}
+
 #[derive(Clone, Debug, TypedBuilder)]
 pub struct IbcTransferSend {
     source: Alias,
@@ -35,6 +50,8 @@ pub struct IbcTransferSend {
     amount: Amount,
     src_channel_id: ChannelId,
     dest_channel_id: ChannelId,
+    #[builder(default)]
+    timeout_sec_offset: Option<u64>,
     settings: TaskSettings,
 }
 
@@ -91,6 +108,7 @@ impl TaskContext for IbcTransferSend {
             self.src_channel_id.clone(),
             false,
         );
+        tx_builder.timeout_sec_offset = self.timeout_sec_offset;
         tx_builder = tx_builder.gas_limit(GasLimit::from(self.settings.gas_limit));
         tx_builder = tx_builder.wrapper_fee_payer(fee_payer);
         let mut signing_keys = vec![];
@@ -130,6 +148,7 @@ impl TaskContext for IbcTransferSend {
         // Wait for the IBC transfer completion
         let sequence = get_ibc_packet_sequence(
             ctx,
+            &self.src_channel_id,
             &self.source,
             &self.receiver,
             height,
@@ -139,6 +158,7 @@ impl TaskContext for IbcTransferSend {
         .await?;
         if is_ibc_transfer_successful(
             ctx,
+            &PortId::transfer(),
             &self.src_channel_id,
             &self.dest_channel_id,
             sequence.into(),
@@ -172,15 +192,32 @@ impl TaskContext for IbcTransferSend {
                 .build(),
         );
 
-        Ok(vec![source_check])
+        let denom_on_cosmos = sent_cosmos_denom(ctx, &self.dest_channel_id, &self.denom).await?;
+        let pre_balance = get_cosmos_balance(
+            ctx.counterparty(&self.src_channel_id)?,
+            &self.receiver,
+            &denom_on_cosmos,
+            retry_config,
+        )
+        .await?;
+        let receiver_check = Check::BalanceForeignTarget(
+            check::balance_foreign_target::BalanceForeignTarget::builder()
+                .channel_id(self.src_channel_id.clone())
+                .target(self.receiver.clone())
+                .pre_balance(pre_balance)
+                .denom(denom_on_cosmos)
+                .amount(self.amount)
+                .is_wrapped_nam(is_native_denom(&self.denom))
+                .build(),
+        );
+
+        Ok(vec![source_check, receiver_check])
     }
 
     fn update_state(&self, state: &mut State) {
+        state.decrease_balance(&self.source, &self.denom, self.amount);
         if is_native_denom(&self.denom) {
-            state.decrease_balance(&self.source, self.amount);
             state.increase_foreign_balance(&self.receiver, self.amount);
-        } else {
-            state.decrease_ibc_balance(&self.source, &self.denom, self.amount);
         }
     }
 }
@@ -196,6 +233,38 @@ pub struct IbcTransferRecv {
     settings: TaskSettings,
 }
 
+impl IbcTransferRecv {
+    /// Whether NAM returns from Cosmos
+    fn is_wrapped_nam(&self) -> bool {
+        self.denom == ibc_denom(&self.src_channel_id, &Alias::nam().name)
+    }
+
+    async fn foreign_source_check(
+        &self,
+        ctx: &Ctx,
+        retry_config: RetryConfig,
+    ) -> Result<Check, TaskError> {
+        let denom_on_cosmos = cosmos_denom(ctx, &self.denom).await?;
+        let pre_balance = get_cosmos_balance(
+            ctx.counterparty(&self.dest_channel_id)?,
+            &self.sender,
+            &denom_on_cosmos,
+            retry_config,
+        )
+        .await?;
+        Ok(Check::BalanceForeignSource(
+            check::balance_foreign_source::BalanceForeignSource::builder()
+                .channel_id(self.dest_channel_id.clone())
+                .target(self.sender.clone())
+                .pre_balance(pre_balance)
+                .denom(denom_on_cosmos)
+                .amount(self.amount)
+                .is_wrapped_nam(self.is_wrapped_nam())
+                .build(),
+        ))
+    }
+}
+
 impl TaskContext for IbcTransferRecv {
     fn name(&self) -> String {
         "ibc-transfer-recv".to_string()
@@ -219,14 +288,24 @@ impl TaskContext for IbcTransferRecv {
     async fn execute(&self, ctx: &Ctx) -> Result<Height, TaskError> {
         let retry_config = retry_config();
 
-        let height = self.execute_cosmos_tx(ctx).await?;
+        let height = self
+            .execute_cosmos_tx(ctx, ctx.counterparty(&self.dest_channel_id)?)
+            .await?;
 
         // Check the packet receiving on Namada
-        let sequence =
-            get_ibc_packet_sequence(ctx, &self.sender, &self.target, height, false, retry_config)
-                .await?;
+        let sequence = get_ibc_packet_sequence(
+            ctx,
+            &self.dest_channel_id,
+            &self.sender,
+            &self.target,
+            height,
+            false,
+            retry_config,
+        )
+        .await?;
         let (is_successful, recv_height) = is_recv_packet(
             ctx,
+            &PortId::transfer(),
             &self.src_channel_id,
             &self.dest_channel_id,
             sequence.into(),
@@ -252,19 +331,10 @@ impl TaskContext for IbcTransferRecv {
             .find_address(&self.target.name)
             .ok_or_else(|| TaskError::Wallet(format!("No source address: {}", self.target.name)))?
             .into_owned();
-
-        let denom = if is_native_denom(&self.denom) {
-            self.denom.clone()
-        } else {
-            let base_token = base_denom(&self.denom);
-            let token_address = wallet.find_address(&base_token).ok_or_else(|| {
-                TaskError::Wallet(format!("No native token address: {base_token}",))
-            })?;
-            let denom = self.denom.replace(&base_token, &token_address.to_string());
-            cosmos_denom_hash(&denom)
-        };
         drop(wallet);
 
+        let denom = cosmos_denom(ctx, &self.denom).await?;
+
         let namada_timeout_height =
             get_block_height(ctx, retry_config()).await? + IBC_TIMEOUT_HEIGHT_OFFSET;
 
@@ -301,17 +371,19 @@ impl TaskContext for IbcTransferRecv {
                 .build(),
         );
 
-        Ok(vec![target_check])
+        let sender_check = self.foreign_source_check(ctx, retry_config).await?;
+
+        Ok(vec![target_check, sender_check])
     }
 
     fn update_state(&self, state: &mut State) {
-        if self.denom == ibc_denom(&self.dest_channel_id, &Alias::nam().name) {
+        if self.is_wrapped_nam() {
             // receiving NAM
-            state.increase_balance(&self.target, self.amount);
+            state.increase_balance(&self.target, &Alias::nam().name, self.amount);
             state.decrease_foreign_balance(&self.sender, self.amount);
         } else {
             let ibc_denom = ibc_denom(&self.dest_channel_id, &self.denom);
-            state.increase_ibc_balance(&self.target, &ibc_denom, self.amount);
+            state.increase_balance(&self.target, &ibc_denom, self.amount);
         }
     }
 }
@@ -327,6 +399,38 @@ pub struct IbcShieldingTransfer {
     settings: TaskSettings,
 }
 
+impl IbcShieldingTransfer {
+    /// Whether NAM returns from Cosmos
+    fn is_wrapped_nam(&self) -> bool {
+        self.denom == ibc_denom(&self.src_channel_id, &Alias::nam().name)
+    }
+
+    async fn foreign_source_check(
+        &self,
+        ctx: &Ctx,
+        retry_config: RetryConfig,
+    ) -> Result<Check, TaskError> {
+        let denom_on_cosmos = cosmos_denom(ctx, &self.denom).await?;
+        let pre_balance = get_cosmos_balance(
+            ctx.counterparty(&self.dest_channel_id)?,
+            &self.sender,
+            &denom_on_cosmos,
+            retry_config,
+        )
+        .await?;
+        Ok(Check::BalanceForeignSource(
+            check::balance_foreign_source::BalanceForeignSource::builder()
+                .channel_id(self.dest_channel_id.clone())
+                .target(self.sender.clone())
+                .pre_balance(pre_balance)
+                .denom(denom_on_cosmos)
+                .amount(self.amount)
+                .is_wrapped_nam(self.is_wrapped_nam())
+                .build(),
+        ))
+    }
+}
+
 impl TaskContext for IbcShieldingTransfer {
     fn name(&self) -> String {
         "ibc-shielding-transfer".to_string()
@@ -350,11 +454,14 @@ impl TaskContext for IbcShieldingTransfer {
     async fn execute(&self, ctx: &Ctx) -> Result<Height, TaskError> {
         let retry_config = retry_config();
 
-        let height = self.execute_cosmos_tx(ctx).await?;
+        let height = self
+            .execute_cosmos_tx(ctx, ctx.counterparty(&self.dest_channel_id)?)
+            .await?;
 
         // Need to check the packet receipt before checking
         let sequence = get_ibc_packet_sequence(
             ctx,
+            &self.dest_channel_id,
             &self.sender,
             &Alias::masp(),
             height,
@@ -364,6 +471,7 @@ impl TaskContext for IbcShieldingTransfer {
         .await?;
         let (is_successful, recv_height) = is_recv_packet(
             ctx,
+            &PortId::transfer(),
             &self.src_channel_id,
             &self.dest_channel_id,
             sequence.into(),
@@ -407,8 +515,14 @@ impl TaskContext for IbcShieldingTransfer {
         };
         drop(wallet);
 
-        let shielding_tx =
-            gen_shielding_tx(ctx, target_payment_address, &ibc_denom, self.amount).await?;
+        let shielding_tx = gen_shielding_tx(
+            ctx,
+            &self.dest_channel_id,
+            target_payment_address,
+            &ibc_denom,
+            self.amount,
+        )
+        .await?;
         let memo = convert_masp_tx_to_ibc_memo(&shielding_tx);
 
         let namada_timeout_height =
@@ -451,17 +565,19 @@ impl TaskContext for IbcShieldingTransfer {
                 .build(),
         );
 
-        Ok(vec![target_check])
+        let sender_check = self.foreign_source_check(ctx, retry_config).await?;
+
+        Ok(vec![target_check, sender_check])
     }
 
     fn update_state(&self, state: &mut State) {
-        if self.denom == ibc_denom(&self.dest_channel_id, &Alias::nam().name) {
+        if self.is_wrapped_nam() {
             // receiving NAM
-            state.increase_masp_balance(&self.target, self.amount);
+            state.increase_masp_balance(&self.target, &Alias::nam().name, self.amount);
             state.decrease_foreign_balance(&self.sender, self.amount);
         } else {
             let ibc_denom = ibc_denom(&self.dest_channel_id, &self.denom);
-            state.increase_ibc_balance(&self.target, &ibc_denom, self.amount);
+            state.increase_masp_balance(&self.target, &ibc_denom, self.amount);
         }
     }
 }
@@ -475,6 +591,8 @@ pub struct IbcUnshieldingTransfer {
     src_channel_id: ChannelId,
     dest_channel_id: ChannelId,
     epoch: MaspEpoch,
+    #[builder(default)]
+    timeout_sec_offset: Option<u64>,
     settings: TaskSettings,
 }
 
@@ -542,6 +660,7 @@ impl TaskContext for IbcUnshieldingTransfer {
             disposable_gas_payer,
         );
         tx_builder.gas_spending_key = gas_spending_key;
+        tx_builder.timeout_sec_offset = self.timeout_sec_offset;
         let refund_target = wallet
             .find_address(self.source.base().name)
             .ok_or_else(|| {
@@ -578,6 +697,7 @@ impl TaskContext for IbcUnshieldingTransfer {
         // Wait for the IBC transfer completion
         let sequence = get_ibc_packet_sequence(
             ctx,
+            &self.src_channel_id,
             &self.source.base(),
             &self.receiver,
             height,
@@ -587,6 +707,7 @@ impl TaskContext for IbcUnshieldingTransfer {
         .await?;
         if is_ibc_transfer_successful(
             ctx,
+            &PortId::transfer(),
             &self.src_channel_id,
             &self.dest_channel_id,
             sequence.into(),
@@ -624,15 +745,741 @@ impl TaskContext for IbcUnshieldingTransfer {
                 .build(),
         );
 
-        Ok(vec![source_check])
+        let denom_on_cosmos = sent_cosmos_denom(ctx, &self.dest_channel_id, &self.denom).await?;
+        let pre_balance = get_cosmos_balance(
+            ctx.counterparty(&self.src_channel_id)?,
+            &self.receiver,
+            &denom_on_cosmos,
+            retry_config,
+        )
+        .await?;
+        let receiver_check = Check::BalanceForeignTarget(
+            check::balance_foreign_target::BalanceForeignTarget::builder()
+                .channel_id(self.src_channel_id.clone())
+                .target(self.receiver.clone())
+                .pre_balance(pre_balance)
+                .denom(denom_on_cosmos)
+                .amount(self.amount)
+                .is_wrapped_nam(is_native_denom(&self.denom))
+                .build(),
+        );
+
+        Ok(vec![source_check, receiver_check])
     }
 
     fn update_state(&self, state: &mut State) {
+        state.decrease_masp_balance(&self.source, &self.denom, self.amount);
         if is_native_denom(&self.denom) {
-            state.decrease_masp_balance(&self.source, self.amount);
             state.increase_foreign_balance(&self.receiver, self.amount);
+        }
+    }
+}
+
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct IbcTransferTimeout {
+    /// Transparent address or spending key
+    source: Alias,
+    receiver: Alias,
+    denom: String,
+    amount: Amount,
+    src_channel_id: ChannelId,
+    dest_channel_id: ChannelId,
+    epoch: MaspEpoch,
+    settings: TaskSettings,
+}
+
+impl IbcTransferTimeout {
+    fn is_shielded(&self) -> bool {
+        self.source.is_spending_key()
+    }
+}
+
+impl TaskContext for IbcTransferTimeout {
+    fn name(&self) -> String {
+        "ibc-transfer-timeout".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "ibc-transfer-timeout/{}/{}/'{}'/{}",
+            self.source.name, self.receiver.name, self.denom, self.amount
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        if self.is_shielded() {
+            IbcUnshieldingTransfer::builder()
+                .source(self.source.clone())
+                .receiver(self.receiver.clone())
+                .denom(self.denom.clone())
+                .amount(self.amount)
+                .src_channel_id(self.src_channel_id.clone())
+                .dest_channel_id(self.dest_channel_id.clone())
+                .epoch(self.epoch)
+                .timeout_sec_offset(Some(IBC_EXPIRED_TIMEOUT_SEC_OFFSET))
+                .settings(self.settings.clone())
+                .build()
+                .build_tx(ctx)
+                .await
         } else {
-            state.decrease_ibc_balance(&self.source, &self.denom, self.amount);
+            IbcTransferSend::builder()
+                .source(self.source.clone())
+                .receiver(self.receiver.clone())
+                .denom(self.denom.clone())
+                .amount(self.amount)
+                .src_channel_id(self.src_channel_id.clone())
+                .dest_channel_id(self.dest_channel_id.clone())
+                .timeout_sec_offset(Some(IBC_EXPIRED_TIMEOUT_SEC_OFFSET))
+                .settings(self.settings.clone())
+                .build()
+                .build_tx(ctx)
+                .await
+        }
+    }
+
+    async fn execute(&self, ctx: &Ctx) -> Result<Height, TaskError> {
+        let retry_config = retry_config();
+
+        let height = if self.is_shielded() {
+            self.execute_shielded_tx(ctx, self.epoch).await?
+        } else {
+            let (tx, signing_data, tx_args) = self.build_tx(ctx).await?;
+
+            let start_height = get_block_height(ctx, retry_config)
+                .await
+                .unwrap_or_default();
+
+            match execute_tx(ctx, tx, signing_data, &tx_args).await {
+                Ok(height) => height,
+                Err(e) => {
+                    wait_block_settlement(ctx, start_height, retry_config).await;
+                    return Err(e);
+                }
+            }
+        };
+
+        // Wait for the relayer to deliver the timeout
+        let sequence = get_ibc_packet_sequence(
+            ctx,
+            &self.src_channel_id,
+            &self.source.base(),
+            &self.receiver,
+            height,
+            true,
+            retry_config,
+        )
+        .await?;
+        match wait_ibc_timeout(
+            ctx,
+            &PortId::transfer(),
+            &self.src_channel_id,
+            &self.dest_channel_id,
+            sequence.into(),
+            retry_config,
+        )
+        .await?
+        {
+            Some(timeout_height) => {
+                wait_block_settlement(ctx, timeout_height, retry_config).await;
+                // Returns Namada height where the refund was executed
+                Ok(timeout_height)
+            }
+            None => {
+                let err = format!(
+                    "Expired packet was acknowledged: {} {} from {} to {}",
+                    self.amount, self.denom, self.source.name, self.receiver.name
+                );
+                Err(TaskError::IbcTransfer(err))
+            }
+        }
+    }
+
+    async fn build_checks(
+        &self,
+        ctx: &Ctx,
+        retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        // nothing should arrive on Cosmos
+        let denom_on_cosmos = sent_cosmos_denom(ctx, &self.dest_channel_id, &self.denom).await?;
+        let pre_balance = get_cosmos_balance(
+            ctx.counterparty(&self.src_channel_id)?,
+            &self.receiver,
+            &denom_on_cosmos,
+            retry_config,
+        )
+        .await?;
+        let receiver_check = Check::BalanceForeignTarget(
+            check::balance_foreign_target::BalanceForeignTarget::builder()
+                .channel_id(self.src_channel_id.clone())
+                .target(self.receiver.clone())
+                .pre_balance(pre_balance)
+                .denom(denom_on_cosmos)
+                .amount(0)
+                .is_wrapped_nam(is_native_denom(&self.denom))
+                .build(),
+        );
+
+        if self.is_shielded() {
+            shielded_sync_with_retry(ctx, &self.source, None, false, retry_config).await?;
+
+            let pre_balance = get_shielded_balance(ctx, &self.source, &self.denom, retry_config)
+                .await?
+                .unwrap_or_default();
+            let source_check = Check::BalanceShieldedSource(
+                check::balance_shielded_source::BalanceShieldedSource::builder()
+                    .target(self.source.clone())
+                    .pre_balance(pre_balance)
+                    .denom(self.denom.clone())
+                    .amount(self.amount)
+                    .build(),
+            );
+
+            // the refund goes to the transparent refund target
+            let refund_target = self.source.base();
+            let (_, pre_balance) =
+                get_balance(ctx, &refund_target, &self.denom, retry_config).await?;
+            let refund_check = Check::BalanceTarget(
+                check::balance_target::BalanceTarget::builder()
+                    .target(refund_target)
+                    .pre_balance(pre_balance)
+                    .denom(self.denom.clone())
+                    .amount(self.amount)
+                    .build(),
+            );
+
+            Ok(vec![source_check, refund_check, receiver_check])
+        } else {
+            // the source should get back the whole amount
+            let (_, pre_balance) =
+                get_balance(ctx, &self.source, &self.denom, retry_config).await?;
+            let source_check = Check::BalanceSource(
+                check::balance_source::BalanceSource::builder()
+                    .target(self.source.clone())
+                    .pre_balance(pre_balance)
+                    .denom(self.denom.clone())
+                    .amount(0)
+                    .build(),
+            );
+
+            Ok(vec![source_check, receiver_check])
+        }
+    }
+
+    fn update_state(&self, state: &mut State) {
+        if self.is_shielded() {
+            state.decrease_masp_balance(&self.source, &self.denom, self.amount);
+            state.increase_balance(&self.source.base(), &self.denom, self.amount);
+        }
+    }
+}
+
+/// Unshield over IBC and send the funds straight back into another payment address
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct IbcShieldedRoundTrip {
+    source: Alias,
+    cosmos_account: Alias,
+    target: Alias,
+    denom: String,
+    amount: Amount,
+    namada_channel_id: ChannelId,
+    cosmos_channel_id: ChannelId,
+    epoch: MaspEpoch,
+    settings: TaskSettings,
+}
+
+impl IbcShieldedRoundTrip {
+    fn outbound(&self) -> IbcUnshieldingTransfer {
+        IbcUnshieldingTransfer::builder()
+            .source(self.source.clone())
+            .receiver(self.cosmos_account.clone())
+            .denom(self.denom.clone())
+            .amount(self.amount)
+            .src_channel_id(self.namada_channel_id.clone())
+            .dest_channel_id(self.cosmos_channel_id.clone())
+            .epoch(self.epoch)
+            .settings(self.settings.clone())
+            .build()
+    }
+
+    fn inbound(&self) -> IbcShieldingTransfer {
+        // the denom on Cosmos to be sent back
+        let denom = if is_native_denom(&self.denom) {
+            ibc_denom(&self.cosmos_channel_id, &self.denom)
+        } else {
+            base_denom(&self.denom)
+        };
+        IbcShieldingTransfer::builder()
+            .sender(self.cosmos_account.clone())
+            .target(self.target.clone())
+            .denom(denom)
+            .amount(self.amount)
+            .src_channel_id(self.cosmos_channel_id.clone())
+            .dest_channel_id(self.namada_channel_id.clone())
+            .settings(TaskSettings::faucet())
+            .build()
+    }
+}
+
+impl TaskContext for IbcShieldedRoundTrip {
+    fn name(&self) -> String {
+        "ibc-shielded-round-trip".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "ibc-shielded-round-trip/{}/{}/{}/'{}'/{}",
+            self.source.name, self.cosmos_account.name, self.target.name, self.denom, self.amount
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        self.outbound().build_tx(ctx).await
+    }
+
+    async fn execute(&self, ctx: &Ctx) -> Result<Height, TaskError> {
+        let height = self.outbound().execute(ctx).await?;
+
+        let recv_height =
+            self.inbound()
+                .execute(ctx)
+                .await
+                .map_err(|err| TaskError::PartialExecution {
+                    err: Box::new(err),
+                    height,
+                })?;
+        wait_block_settlement(ctx, recv_height, retry_config()).await;
+
+        Ok(recv_height)
+    }
+
+    async fn build_checks(
+        &self,
+        ctx: &Ctx,
+        retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        let mut pre_balances = vec![];
+        for alias in [&self.source, &self.target] {
+            shielded_sync_with_retry(ctx, alias, None, false, retry_config).await?;
+            let pre_balance = get_shielded_balance(ctx, alias, &self.denom, retry_config)
+                .await?
+                .unwrap_or_default();
+            pre_balances.push(pre_balance);
+        }
+
+        let source_check = Check::BalanceShieldedSource(
+            check::balance_shielded_source::BalanceShieldedSource::builder()
+                .target(self.source.clone())
+                .pre_balance(pre_balances[0])
+                .denom(self.denom.clone())
+                .amount(self.amount)
+                .build(),
+        );
+        let target_check = Check::BalanceShieldedTarget(
+            check::balance_shielded_target::BalanceShieldedTarget::builder()
+                .target(self.target.clone())
+                .pre_balance(pre_balances[1])
+                .denom(self.denom.clone())
+                .amount(self.amount)
+                .build(),
+        );
+        let conservation_check = Check::MaspConservation(
+            check::masp_conservation::MaspConservation::builder()
+                .source(self.source.clone())
+                .target(self.target.clone())
+                .denom(self.denom.clone())
+                .pre_source_balance(pre_balances[0])
+                .pre_target_balance(pre_balances[1])
+                .build(),
+        );
+
+        // the funds pass through the Cosmos account
+        let denom_on_cosmos = sent_cosmos_denom(ctx, &self.cosmos_channel_id, &self.denom).await?;
+        let pre_balance = get_cosmos_balance(
+            ctx.counterparty(&self.namada_channel_id)?,
+            &self.cosmos_account,
+            &denom_on_cosmos,
+            retry_config,
+        )
+        .await?;
+        let foreign_check = Check::BalanceForeignSource(
+            check::balance_foreign_source::BalanceForeignSource::builder()
+                .channel_id(self.namada_channel_id.clone())
+                .target(self.cosmos_account.clone())
+                .pre_balance(pre_balance)
+                .denom(denom_on_cosmos)
+                .amount(0)
+                .is_wrapped_nam(is_native_denom(&self.denom))
+                .build(),
+        );
+
+        Ok(vec![
+            source_check,
+            target_check,
+            conservation_check,
+            foreign_check,
+        ])
+    }
+
+    fn update_state(&self, state: &mut State) {
+        // the foreign balance is unchanged because the funds came back
+        state.decrease_masp_balance(&self.source, &self.denom, self.amount);
+        state.increase_masp_balance(&self.target, &self.denom, self.amount);
+    }
+
+    fn update_failed_state(&self, err: &TaskError, state: &mut State) {
+        // the funds stay on Cosmos when only the inbound transfer failed
+        if matches!(err, TaskError::PartialExecution { .. }) {
+            self.outbound().update_state(state);
+        }
+    }
+}
+
+/// Receive the amount exceeding the IBC rate limits, which should be rejected
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct IbcTransferOverLimit {
+    sender: Alias,
+    target: Alias,
+    denom: String,
+    amount: Amount,
+    mint_limit: token::Amount,
+    src_channel_id: ChannelId,
+    dest_channel_id: ChannelId,
+    settings: TaskSettings,
+}
+
+impl IbcTransferOverLimit {
+    fn recv(&self) -> IbcTransferRecv {
+        IbcTransferRecv::builder()
+            .sender(self.sender.clone())
+            .target(self.target.clone())
+            .denom(self.denom.clone())
+            .amount(self.amount)
+            .src_channel_id(self.src_channel_id.clone())
+            .dest_channel_id(self.dest_channel_id.clone())
+            .settings(self.settings.clone())
+            .build()
+    }
+}
+
+impl TaskContext for IbcTransferOverLimit {
+    fn name(&self) -> String {
+        "ibc-transfer-over-limit".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "ibc-transfer-over-limit/{}/{}/'{}'/{}",
+            self.sender.name, self.target.name, self.denom, self.amount
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, _ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        unreachable!("Namada tx shouldn't be built")
+    }
+
+    async fn execute(&self, ctx: &Ctx) -> Result<Height, TaskError> {
+        let retry_config = retry_config();
+
+        let height = self
+            .execute_cosmos_tx(ctx, ctx.counterparty(&self.dest_channel_id)?)
+            .await?;
+
+        let sequence = get_ibc_packet_sequence(
+            ctx,
+            &self.dest_channel_id,
+            &self.sender,
+            &self.target,
+            height,
+            false,
+            retry_config,
+        )
+        .await?;
+        let (is_successful, recv_height) = is_recv_packet(
+            ctx,
+            &PortId::transfer(),
+            &self.src_channel_id,
+            &self.dest_channel_id,
+            sequence.into(),
+            retry_config,
+        )
+        .await?;
+        if is_successful {
+            let err = format!(
+                "Receiving token beyond the limit succeeded: {} {} from {} to {}",
+                self.amount, self.denom, self.sender.name, self.target.name
+            );
+            Err(TaskError::IbcTransfer(err))
+        } else {
+            // Rejected as expected
+            wait_block_settlement(ctx, recv_height, retry_config).await;
+            Ok(recv_height)
+        }
+    }
+
+    async fn build_cosmos_tx(&self, ctx: &Ctx) -> Result<Any, TaskError> {
+        self.recv().build_cosmos_tx(ctx).await
+    }
+
+    async fn build_checks(
+        &self,
+        ctx: &Ctx,
+        retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        let recv_denom = ibc_denom(&self.dest_channel_id, &self.denom);
+
+        // nothing should be received
+        let (_, pre_balance) = get_balance(ctx, &self.target, &recv_denom, retry_config).await?;
+        let target_check = Check::BalanceTarget(
+            check::balance_target::BalanceTarget::builder()
+                .target(self.target.clone())
+                .pre_balance(pre_balance)
+                .denom(recv_denom.clone())
+                .amount(0)
+                .build(),
+        );
+        // the sender should be refunded
+        let denom_on_cosmos = cosmos_denom(ctx, &self.denom).await?;
+        let pre_balance = get_cosmos_balance(
+            ctx.counterparty(&self.dest_channel_id)?,
+            &self.sender,
+            &denom_on_cosmos,
+            retry_config,
+        )
+        .await?;
+        let sender_check = Check::BalanceForeignSource(
+            check::balance_foreign_source::BalanceForeignSource::builder()
+                .channel_id(self.dest_channel_id.clone())
+                .target(self.sender.clone())
+                .pre_balance(pre_balance)
+                .denom(denom_on_cosmos)
+                .amount(0)
+                .build(),
+        );
+        let mint_limit_check = Check::IbcMintLimit(
+            check::ibc_mint_limit::IbcMintLimit::builder()
+                .denom(recv_denom)
+                .mint_limit(self.mint_limit)
+                .build(),
+        );
+
+        Ok(vec![target_check, sender_check, mint_limit_check])
+    }
+
+    fn update_state(&self, _state: &mut State) {}
+}
+
+/// Transfer from Cosmos forwarded by the packet-forward-middleware on Namada
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct IbcTransferForward {
+    sender: Alias,
+    /// Receiver on Namada of the first hop
+    intermediate: Alias,
+    receiver: Alias,
+    denom: String,
+    amount: Amount,
+    src_channel_id: ChannelId,
+    dest_channel_id: ChannelId,
+    /// Channels of the second hop from Namada
+    forward_src_channel_id: ChannelId,
+    forward_dest_channel_id: ChannelId,
+    settings: TaskSettings,
+}
+
+impl IbcTransferForward {
+    /// Whether the packet goes back to the sender chain
+    fn is_back_out(&self) -> bool {
+        self.forward_src_channel_id == self.dest_channel_id
+    }
+
+    /// The trace of the token on Namada after the first hop
+    fn intermediate_denom(&self) -> String {
+        ibc_denom(&self.dest_channel_id, &self.denom)
+    }
+
+    /// The trace of the token on the final destination
+    fn forwarded_trace(&self) -> String {
+        if self.is_back_out() {
+            // the token is unwound to the original denom
+            self.denom.clone()
+        } else {
+            ibc_denom(&self.forward_dest_channel_id, &self.intermediate_denom())
+        }
+    }
+}
+
+impl TaskContext for IbcTransferForward {
+    fn name(&self) -> String {
+        "ibc-transfer-forward".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "ibc-transfer-forward/{}/{}/{}/'{}'/{}",
+            self.sender.name, self.intermediate.name, self.receiver.name, self.denom, self.amount
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, _ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        unreachable!("Namada tx shouldn't be built")
+    }
+
+    async fn execute(&self, ctx: &Ctx) -> Result<Height, TaskError> {
+        let retry_config = retry_config();
+
+        let height = self
+            .execute_cosmos_tx(ctx, ctx.counterparty(&self.dest_channel_id)?)
+            .await?;
+
+        let sequence = get_ibc_packet_sequence(
+            ctx,
+            &self.dest_channel_id,
+            &self.sender,
+            &self.intermediate,
+            height,
+            false,
+            retry_config,
+        )
+        .await?;
+        // The ack on Namada is written after the forwarded packet is acknowledged
+        let (is_successful, recv_height) = is_recv_packet(
+            ctx,
+            &PortId::transfer(),
+            &self.src_channel_id,
+            &self.dest_channel_id,
+            sequence.into(),
+            retry_config,
+        )
+        .await?;
+        if is_successful {
+            wait_block_settlement(ctx, recv_height, retry_config).await;
+            Ok(recv_height)
+        } else {
+            // Forwarding failed or timed out
+            let err = format!(
+                "Forwarding token failed: {} {} from {} to {} via {}",
+                self.amount,
+                self.denom,
+                self.sender.name,
+                self.receiver.name,
+                self.intermediate.name
+            );
+            Err(TaskError::IbcTransfer(err))
+        }
+    }
+
+    async fn build_cosmos_tx(&self, ctx: &Ctx) -> Result<Any, TaskError> {
+        let wallet = ctx.namada.wallet.read().await;
+        let intermediate_address = wallet
+            .find_address(&self.intermediate.name)
+            .ok_or_else(|| {
+                TaskError::Wallet(format!(
+                    "No intermediate address: {}",
+                    self.intermediate.name
+                ))
+            })?
+            .into_owned();
+        drop(wallet);
+
+        let memo = pfm_memo(&self.receiver.name, &self.forward_src_channel_id);
+
+        let namada_timeout_height =
+            get_block_height(ctx, retry_config()).await? + IBC_TIMEOUT_HEIGHT_OFFSET;
+
+        let any_msg = build_cosmos_ibc_transfer(
+            &self.sender.name,
+            &intermediate_address.to_string(),
+            &self.denom,
+            self.amount,
+            &self.src_channel_id,
+            namada_timeout_height,
+            Some(&memo),
+        );
+
+        Ok(any_msg)
+    }
+
+    async fn build_checks(
+        &self,
+        ctx: &Ctx,
+        retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        // nothing should remain on Namada
+        let intermediate_denom = self.intermediate_denom();
+        let (_, pre_balance) =
+            get_balance(ctx, &self.intermediate, &intermediate_denom, retry_config).await?;
+        let intermediate_check = Check::BalanceTarget(
+            check::balance_target::BalanceTarget::builder()
+                .target(self.intermediate.clone())
+                .pre_balance(pre_balance)
+                .denom(intermediate_denom)
+                .amount(0)
+                .build(),
+        );
+
+        let pre_balance = get_cosmos_balance(
+            ctx.counterparty(&self.dest_channel_id)?,
+            &self.sender,
+            &self.denom,
+            retry_config,
+        )
+        .await?;
+        let sender_check = Check::BalanceForeignSource(
+            check::balance_foreign_source::BalanceForeignSource::builder()
+                .channel_id(self.dest_channel_id.clone())
+                .target(self.sender.clone())
+                .pre_balance(pre_balance)
+                .denom(self.denom.clone())
+                // the token comes back to the sender when backing out
+                .amount(if self.is_back_out() { 0 } else { self.amount })
+                .build(),
+        );
+
+        if self.is_back_out() {
+            return Ok(vec![intermediate_check, sender_check]);
+        }
+
+        let trace = self.forwarded_trace();
+        let denom_on_cosmos = cosmos_denom(ctx, &trace).await?;
+        let pre_balance = get_cosmos_balance(
+            ctx.counterparty(&self.forward_src_channel_id)?,
+            &self.receiver,
+            &denom_on_cosmos,
+            retry_config,
+        )
+        .await?;
+        let receiver_check = Check::BalanceForeignTarget(
+            check::balance_foreign_target::BalanceForeignTarget::builder()
+                .channel_id(self.forward_src_channel_id.clone())
+                .target(self.receiver.clone())
+                .pre_balance(pre_balance)
+                .denom(denom_on_cosmos)
+                .amount(self.amount)
+                .trace(Some(trace))
+                .build(),
+        );
+
+        Ok(vec![intermediate_check, sender_check, receiver_check])
+    }
+
+    fn update_state(&self, state: &mut State) {
+        if !self.is_back_out() {
+            state.increase_ibc_balance(&self.receiver, &self.forwarded_trace(), self.amount);
         }
     }
 }
@@ -0,0 +1,307 @@
+use std::collections::BTreeMap;
+
+use namada_sdk::args::{self, TxBuilder};
+use namada_sdk::governance::cli::onchain::{
+    OnChainProposal, PgfFunding, PgfFundingProposal as FundingProposal,
+    PgfStewardProposal as StewardProposal, StewardsUpdate,
+};
+use namada_sdk::governance::storage::proposal::{PGFInternalTarget, PGFTarget};
+use namada_sdk::signing::SigningTxData;
+use namada_sdk::token;
+use namada_sdk::tx::data::GasLimit;
+use namada_sdk::tx::Tx;
+use namada_sdk::Namada;
+use typed_builder::TypedBuilder;
+
+use crate::check::{self, Check};
+use crate::constants::PROPOSAL_DEPOSIT;
+use crate::context::Ctx;
+use crate::error::TaskError;
+use crate::state::{ProposalPayload, State};
+use crate::task::{TaskContext, TaskSettings};
+use crate::types::{Alias, Amount, Epoch};
+use crate::utils::{get_balance, RetryConfig};
+
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct PgfFundingProposal {
+    source: Alias,
+    target: Alias,
+    amount: Amount,
+    is_continuous: bool,
+    start_epoch: Epoch,
+    end_epoch: Epoch,
+    grace_epoch: Epoch,
+    settings: TaskSettings,
+}
+
+impl PgfFundingProposal {
+    pub fn source(&self) -> &Alias {
+        &self.source
+    }
+
+    pub fn start_epoch(&self) -> Epoch {
+        self.start_epoch
+    }
+
+    pub fn grace_epoch(&self) -> Epoch {
+        self.grace_epoch
+    }
+
+    pub fn payload(&self) -> ProposalPayload {
+        ProposalPayload::PgfFunding {
+            target: self.target.clone(),
+            amount: self.amount,
+            is_continuous: self.is_continuous,
+        }
+    }
+}
+
+impl TaskContext for PgfFundingProposal {
+    fn name(&self) -> String {
+        "pgf-funding-proposal".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "pgf-funding-proposal/{}/{}/{}/{}",
+            self.source.name,
+            self.target.name,
+            self.amount,
+            if self.is_continuous {
+                "continuous"
+            } else {
+                "retro"
+            }
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        let wallet = ctx.namada.wallet.read().await;
+        let source_address = wallet
+            .find_address(&self.source.name)
+            .ok_or_else(|| TaskError::Wallet(format!("No source address: {}", self.source.name)))?;
+        let target_address = wallet
+            .find_address(&self.target.name)
+            .ok_or_else(|| TaskError::Wallet(format!("No target address: {}", self.target.name)))?;
+        let fee_payer = wallet
+            .find_public_key(&self.settings.gas_payer.name)
+            .map_err(|e| TaskError::Wallet(e.to_string()))?;
+
+        let funding_target = PGFTarget::Internal(PGFInternalTarget {
+            target: target_address.into_owned(),
+            amount: token::Amount::from_u64(self.amount),
+        });
+        let (continuous, retro) = if self.is_continuous {
+            (vec![funding_target], vec![])
+        } else {
+            (vec![], vec![funding_target])
+        };
+        let funding_proposal = FundingProposal {
+            proposal: OnChainProposal {
+                content: BTreeMap::from_iter([("workload".to_string(), "tester".to_string())]),
+                author: source_address.into_owned(),
+                voting_start_epoch: self.start_epoch.into(),
+                voting_end_epoch: self.end_epoch.into(),
+                activation_epoch: self.grace_epoch.into(),
+            },
+            data: PgfFunding { continuous, retro },
+        };
+        let proposal_json =
+            serde_json::to_string(&funding_proposal).expect("Encoding proposal shouldn't fail");
+
+        let mut funding_proposal_tx_builder = ctx
+            .namada
+            .new_init_proposal(proposal_json.into_bytes())
+            .is_pgf_funding(true);
+
+        funding_proposal_tx_builder =
+            funding_proposal_tx_builder.gas_limit(GasLimit::from(self.settings.gas_limit));
+        funding_proposal_tx_builder = funding_proposal_tx_builder.wrapper_fee_payer(fee_payer);
+
+        let mut signing_keys = vec![];
+        for signer in &self.settings.signers {
+            let public_key = wallet
+                .find_public_key(&signer.name)
+                .map_err(|e| TaskError::Wallet(e.to_string()))?;
+            signing_keys.push(public_key)
+        }
+        funding_proposal_tx_builder = funding_proposal_tx_builder.signing_keys(signing_keys);
+        drop(wallet);
+
+        let (funding_proposal, signing_data) = funding_proposal_tx_builder
+            .build(&ctx.namada)
+            .await
+            .map_err(|e| TaskError::BuildTx(e.to_string()))?;
+
+        Ok((
+            funding_proposal,
+            vec![signing_data],
+            funding_proposal_tx_builder.tx,
+        ))
+    }
+
+    async fn build_checks(
+        &self,
+        ctx: &Ctx,
+        retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        let denom = Alias::nam().name;
+        let (_, pre_balance) = get_balance(ctx, &self.source, &denom, retry_config).await?;
+
+        Ok(vec![Check::BalanceSource(
+            check::balance_source::BalanceSource::builder()
+                .target(self.source.clone())
+                .pre_balance(pre_balance)
+                .denom(denom)
+                .amount(PROPOSAL_DEPOSIT)
+                .build(),
+        )])
+    }
+
+    fn update_state(&self, state: &mut State) {
+        state.decrease_balance(&self.source, &Alias::nam().name, PROPOSAL_DEPOSIT);
+        // proposal will be added later
+    }
+}
+
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct PgfStewardProposal {
+    source: Alias,
+    is_add: bool,
+    start_epoch: Epoch,
+    end_epoch: Epoch,
+    grace_epoch: Epoch,
+    settings: TaskSettings,
+}
+
+impl PgfStewardProposal {
+    pub fn source(&self) -> &Alias {
+        &self.source
+    }
+
+    pub fn start_epoch(&self) -> Epoch {
+        self.start_epoch
+    }
+
+    pub fn grace_epoch(&self) -> Epoch {
+        self.grace_epoch
+    }
+
+    pub fn payload(&self) -> ProposalPayload {
+        ProposalPayload::PgfSteward {
+            steward: self.source.clone(),
+            is_add: self.is_add,
+        }
+    }
+}
+
+impl TaskContext for PgfStewardProposal {
+    fn name(&self) -> String {
+        "pgf-steward-proposal".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "pgf-steward-proposal/{}/{}",
+            self.source.name,
+            if self.is_add { "add" } else { "remove" }
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        let wallet = ctx.namada.wallet.read().await;
+        let source_address = wallet
+            .find_address(&self.source.name)
+            .ok_or_else(|| TaskError::Wallet(format!("No source address: {}", self.source.name)))?
+            .into_owned();
+        let fee_payer = wallet
+            .find_public_key(&self.settings.gas_payer.name)
+            .map_err(|e| TaskError::Wallet(e.to_string()))?;
+
+        // Only the author can be added as a steward
+        let stewards_update = if self.is_add {
+            StewardsUpdate {
+                add: Some(source_address.clone()),
+                remove: vec![],
+            }
+        } else {
+            StewardsUpdate {
+                add: None,
+                remove: vec![source_address.clone()],
+            }
+        };
+        let steward_proposal = StewardProposal {
+            proposal: OnChainProposal {
+                content: BTreeMap::from_iter([("workload".to_string(), "tester".to_string())]),
+                author: source_address,
+                voting_start_epoch: self.start_epoch.into(),
+                voting_end_epoch: self.end_epoch.into(),
+                activation_epoch: self.grace_epoch.into(),
+            },
+            data: stewards_update,
+        };
+        let proposal_json =
+            serde_json::to_string(&steward_proposal).expect("Encoding proposal shouldn't fail");
+
+        let mut steward_proposal_tx_builder = ctx
+            .namada
+            .new_init_proposal(proposal_json.into_bytes())
+            .is_pgf_stewards(true);
+
+        steward_proposal_tx_builder =
+            steward_proposal_tx_builder.gas_limit(GasLimit::from(self.settings.gas_limit));
+        steward_proposal_tx_builder = steward_proposal_tx_builder.wrapper_fee_payer(fee_payer);
+
+        let mut signing_keys = vec![];
+        for signer in &self.settings.signers {
+            let public_key = wallet
+                .find_public_key(&signer.name)
+                .map_err(|e| TaskError::Wallet(e.to_string()))?;
+            signing_keys.push(public_key)
+        }
+        steward_proposal_tx_builder = steward_proposal_tx_builder.signing_keys(signing_keys);
+        drop(wallet);
+
+        let (steward_proposal, signing_data) = steward_proposal_tx_builder
+            .build(&ctx.namada)
+            .await
+            .map_err(|e| TaskError::BuildTx(e.to_string()))?;
+
+        Ok((
+            steward_proposal,
+            vec![signing_data],
+            steward_proposal_tx_builder.tx,
+        ))
+    }
+
+    async fn build_checks(
+        &self,
+        ctx: &Ctx,
+        retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        let denom = Alias::nam().name;
+        let (_, pre_balance) = get_balance(ctx, &self.source, &denom, retry_config).await?;
+
+        Ok(vec![Check::BalanceSource(
+            check::balance_source::BalanceSource::builder()
+                .target(self.source.clone())
+                .pre_balance(pre_balance)
+                .denom(denom)
+                .amount(PROPOSAL_DEPOSIT)
+                .build(),
+        )])
+    }
+
+    fn update_state(&self, state: &mut State) {
+        state.decrease_balance(&self.source, &Alias::nam().name, PROPOSAL_DEPOSIT);
+        // proposal will be added later
+    }
+}
@@ -146,6 +146,7 @@ impl TaskContext for Redelegate {
             &self.from_validator,
             &self.to_validator,
             self.amount,
+            self.epoch,
         )
     }
 }
@@ -0,0 +1,89 @@
+use namada_sdk::args::{self, TxBuilder};
+use namada_sdk::signing::SigningTxData;
+use namada_sdk::tx::data::GasLimit;
+use namada_sdk::tx::Tx;
+use namada_sdk::Namada;
+use typed_builder::TypedBuilder;
+
+use crate::check::{self, Check};
+use crate::context::Ctx;
+use crate::error::TaskError;
+use crate::state::State;
+use crate::task::{TaskContext, TaskSettings};
+use crate::types::Alias;
+use crate::utils::RetryConfig;
+
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct ResignSteward {
+    source: Alias,
+    settings: TaskSettings,
+}
+
+impl TaskContext for ResignSteward {
+    fn name(&self) -> String {
+        "resign-steward".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!("resign-steward/{}", self.source.name)
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        let wallet = ctx.namada.wallet.read().await;
+        let source_address = wallet
+            .find_address(&self.source.name)
+            .ok_or_else(|| TaskError::Wallet(format!("No source address: {}", self.source.name)))?
+            .into_owned();
+        let fee_payer = wallet
+            .find_public_key(&self.settings.gas_payer.name)
+            .map_err(|e| TaskError::Wallet(e.to_string()))?;
+
+        let mut resign_steward_tx_builder = ctx.namada.new_resign_steward(source_address);
+
+        resign_steward_tx_builder =
+            resign_steward_tx_builder.gas_limit(GasLimit::from(self.settings.gas_limit));
+        resign_steward_tx_builder = resign_steward_tx_builder.wrapper_fee_payer(fee_payer);
+
+        let mut signing_keys = vec![];
+        for signer in &self.settings.signers {
+            let public_key = wallet
+                .find_public_key(&signer.name)
+                .map_err(|e| TaskError::Wallet(e.to_string()))?;
+            signing_keys.push(public_key)
+        }
+        resign_steward_tx_builder = resign_steward_tx_builder.signing_keys(signing_keys);
+        drop(wallet);
+
+        let (resign_steward, signing_data) = resign_steward_tx_builder
+            .build(&ctx.namada)
+            .await
+            .map_err(|e| TaskError::BuildTx(e.to_string()))?;
+
+        Ok((
+            resign_steward,
+            vec![signing_data],
+            resign_steward_tx_builder.tx,
+        ))
+    }
+
+    async fn build_checks(
+        &self,
+        _ctx: &Ctx,
+        _retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        Ok(vec![Check::StewardMembership(
+            check::steward_membership::StewardMembership::builder()
+                .steward(self.source.clone())
+                .is_steward(false)
+                .build(),
+        )])
+    }
+
+    fn update_state(&self, state: &mut State) {
+        state.remove_steward(&self.source);
+    }
+}
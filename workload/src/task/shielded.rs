@@ -16,13 +16,16 @@ use crate::error::TaskError;
 use crate::state::State;
 use crate::task::{TaskContext, TaskSettings};
 use crate::types::{Alias, Amount, Height, MaspEpoch};
-use crate::utils::{get_shielded_balance, shielded_sync_with_retry, RetryConfig};
+use crate::utils::{
+    get_shielded_balance, ibc_token_address, is_native_denom, shielded_sync_with_retry, RetryConfig,
+};
 
 #[derive(Clone, Debug, TypedBuilder)]
 pub struct ShieldedTransfer {
     source: Alias,
     target: Alias,
     amount: Amount,
+    denom: String,
     epoch: MaspEpoch,
     settings: TaskSettings,
 }
@@ -46,8 +49,8 @@ impl TaskContext for ShieldedTransfer {
 
     fn summary(&self) -> String {
         format!(
-            "shielded-transfer/{}/{}/{}",
-            self.source.name, self.target.name, self.amount
+            "shielded-transfer/{}/{}/{}/{}",
+            self.source.name, self.target.name, self.amount, self.denom
         )
     }
 
@@ -59,8 +62,6 @@ impl TaskContext for ShieldedTransfer {
         let mut bparams = RngBuildParams::new(OsRng);
         let mut wallet = ctx.namada.wallet.write().await;
 
-        let native_token_alias = Alias::nam();
-
         let source_spending_key = wallet
             .find_spending_key(&self.source.name, None)
             .map_err(|e| TaskError::Wallet(e.to_string()))?;
@@ -70,20 +71,26 @@ impl TaskContext for ShieldedTransfer {
             *wallet.find_payment_addr(&self.target.name).ok_or_else(|| {
                 TaskError::Wallet(format!("No payment address: {}", self.target.name))
             })?;
-        let token = wallet
-            .find_address(&native_token_alias.name)
-            .ok_or_else(|| {
-                TaskError::Wallet(format!(
-                    "No native token address: {}",
-                    native_token_alias.name
-                ))
-            })?;
         let token_amount = token::Amount::from_u64(self.amount);
-        let amount = InputAmount::Unvalidated(token::DenominatedAmount::native(token_amount));
+        let (token, denominated_amount) = if is_native_denom(&self.denom) {
+            let address = wallet
+                .find_address(&self.denom)
+                .ok_or_else(|| {
+                    TaskError::Wallet(format!("No native token address: {}", self.denom))
+                })?
+                .into_owned();
+            (address, token::DenominatedAmount::native(token_amount))
+        } else {
+            (
+                ibc_token_address(&self.denom),
+                token::DenominatedAmount::new(token_amount, 0u8.into()),
+            )
+        };
+        let amount = InputAmount::Unvalidated(denominated_amount);
         let tx_transfer_data = TxShieldedTransferData {
             source: pseudo_spending_key_from_spending_key,
             target: target_payment_address,
-            token: token.into_owned(),
+            token,
             amount,
         };
 
@@ -134,7 +141,7 @@ impl TaskContext for ShieldedTransfer {
     ) -> Result<Vec<Check>, TaskError> {
         shielded_sync_with_retry(ctx, &self.source, None, false, retry_config).await?;
 
-        let denom = Alias::nam().name;
+        let denom = self.denom.clone();
         let pre_balance = get_shielded_balance(ctx, &self.source, &denom, retry_config)
             .await?
             .unwrap_or_default();
@@ -163,6 +170,6 @@ impl TaskContext for ShieldedTransfer {
     }
 
     fn update_state(&self, state: &mut State) {
-        state.modify_shielded_transfer(&self.source, &self.target, self.amount);
+        state.modify_shielded_transfer(&self.source, &self.target, &self.denom, self.amount);
     }
 }
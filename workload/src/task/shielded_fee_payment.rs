@@ -0,0 +1,114 @@
+use namada_sdk::args;
+use namada_sdk::signing::SigningTxData;
+use namada_sdk::tx::Tx;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{self, Check};
+use crate::context::Ctx;
+use crate::error::TaskError;
+use crate::state::State;
+use crate::task::shielded::ShieldedTransfer;
+use crate::task::{TaskContext, TaskSettings};
+use crate::types::{Alias, Amount, Height, MaspEpoch};
+use crate::utils::{
+    get_balance, get_block_height, is_insufficient_shielded_funds, retry_config, RetryConfig,
+};
+
+/// Shielded transfer whose fee is paid only from the shielded balance of the
+/// source through a disposable gas payer
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct ShieldedFeePayment {
+    source: Alias,
+    target: Alias,
+    amount: Amount,
+    denom: String,
+    epoch: MaspEpoch,
+    settings: TaskSettings,
+    // the shielded balance doesn't cover the fee
+    is_fee_insufficient: bool,
+}
+
+impl ShieldedFeePayment {
+    fn transfer(&self) -> ShieldedTransfer {
+        ShieldedTransfer::builder()
+            .source(self.source.clone())
+            .target(self.target.clone())
+            .amount(self.amount)
+            .denom(self.denom.clone())
+            .epoch(self.epoch)
+            .settings(self.settings.clone())
+            .build()
+    }
+}
+
+impl TaskContext for ShieldedFeePayment {
+    fn name(&self) -> String {
+        "shielded-fee-payment".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "shielded-fee-payment/{}/{}/{}/{}",
+            self.source.name, self.target.name, self.amount, self.denom
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        self.transfer().build_tx(ctx).await
+    }
+
+    async fn execute(&self, ctx: &Ctx) -> Result<Height, TaskError> {
+        let result = self.execute_shielded_tx(ctx, self.epoch).await;
+        if !self.is_fee_insufficient {
+            return result;
+        }
+
+        match result {
+            Ok(height) => {
+                antithesis_sdk::assert_unreachable!(
+                    "Shielded tx without enough shielded balance for the fee was accepted",
+                    &json!({ "task": self.summary(), "height": height })
+                );
+                Ok(height)
+            }
+            // The fee can't be unshielded to the disposable gas payer
+            Err(TaskError::BuildTx(err)) if is_insufficient_shielded_funds(&err) => {
+                let height = get_block_height(ctx, retry_config()).await?;
+                Err(TaskError::InsufficientShieldedFee { err, height })
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn build_checks(
+        &self,
+        ctx: &Ctx,
+        retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        let mut checks = self.transfer().build_checks(ctx, retry_config).await?;
+
+        // The transparent balance of the source shouldn't pay the fee
+        let source = self.source.base();
+        let denom = Alias::nam().name;
+        let (_, pre_balance) = get_balance(ctx, &source, &denom, retry_config).await?;
+        checks.push(Check::BalanceSource(
+            check::balance_source::BalanceSource::builder()
+                .target(source)
+                .pre_balance(pre_balance)
+                .denom(denom)
+                .amount(0)
+                .build(),
+        ));
+
+        Ok(checks)
+    }
+
+    fn update_state(&self, state: &mut State) {
+        self.transfer().update_state(state);
+    }
+}
@@ -14,13 +14,17 @@ use crate::error::TaskError;
 use crate::state::State;
 use crate::task::{TaskContext, TaskSettings};
 use crate::types::{Alias, Amount, Height, MaspEpoch};
-use crate::utils::{get_balance, get_shielded_balance, shielded_sync_with_retry, RetryConfig};
+use crate::utils::{
+    get_balance, get_shielded_balance, ibc_token_address, is_native_denom,
+    shielded_sync_with_retry, RetryConfig,
+};
 
 #[derive(Clone, Debug, TypedBuilder)]
 pub struct Shielding {
     source: Alias,
     target: Alias,
     amount: Amount,
+    denom: String,
     epoch: MaspEpoch,
     settings: TaskSettings,
 }
@@ -38,8 +42,8 @@ impl TaskContext for Shielding {
 
     fn summary(&self) -> String {
         format!(
-            "shielding/{}/{}/{}",
-            self.source.name, self.target.name, self.amount
+            "shielding/{}/{}/{}/{}",
+            self.source.name, self.target.name, self.amount, self.denom
         )
     }
 
@@ -52,8 +56,6 @@ impl TaskContext for Shielding {
 
         let wallet = ctx.namada.wallet.read().await;
 
-        let native_token_alias = Alias::nam();
-
         let source_address = wallet
             .find_address(&self.source.name)
             .ok_or_else(|| TaskError::Wallet(format!("No source address: {}", self.source.name)))?;
@@ -61,23 +63,29 @@ impl TaskContext for Shielding {
             *wallet.find_payment_addr(&self.target.name).ok_or_else(|| {
                 TaskError::Wallet(format!("No payment address: {}", self.target.name))
             })?;
-        let token_address = wallet
-            .find_address(&native_token_alias.name)
-            .ok_or_else(|| {
-                TaskError::Wallet(format!(
-                    "No native token address: {}",
-                    native_token_alias.name
-                ))
-            })?;
+        let token_amount = token::Amount::from_u64(self.amount);
+        let (token_address, denominated_amount) = if is_native_denom(&self.denom) {
+            let address = wallet
+                .find_address(&self.denom)
+                .ok_or_else(|| {
+                    TaskError::Wallet(format!("No native token address: {}", self.denom))
+                })?
+                .into_owned();
+            (address, DenominatedAmount::native(token_amount))
+        } else {
+            (
+                ibc_token_address(&self.denom),
+                DenominatedAmount::new(token_amount, 0u8.into()),
+            )
+        };
         let fee_payer = wallet
             .find_public_key(&self.settings.gas_payer.name)
             .map_err(|e| TaskError::Wallet(e.to_string()))?;
-        let token_amount = token::Amount::from_u64(self.amount);
 
         let tx_transfer_data = TxShieldingTransferData {
             source: source_address.into_owned(),
-            token: token_address.into_owned(),
-            amount: InputAmount::Unvalidated(DenominatedAmount::native(token_amount)),
+            token: token_address,
+            amount: InputAmount::Unvalidated(denominated_amount),
         };
 
         let mut transfer_tx_builder = ctx
@@ -113,7 +121,7 @@ impl TaskContext for Shielding {
         ctx: &Ctx,
         retry_config: RetryConfig,
     ) -> Result<Vec<Check>, TaskError> {
-        let denom = Alias::nam().name;
+        let denom = self.denom.clone();
         let (_, pre_balance) = get_balance(ctx, &self.source, &denom, retry_config).await?;
         let source_check = Check::BalanceSource(
             check::balance_source::BalanceSource::builder()
@@ -142,6 +150,6 @@ impl TaskContext for Shielding {
     }
 
     fn update_state(&self, state: &mut State) {
-        state.modify_shielding(&self.source, &self.target, self.amount)
+        state.modify_shielding(&self.source, &self.target, &self.denom, self.amount)
     }
 }
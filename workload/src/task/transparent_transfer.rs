@@ -12,13 +12,14 @@ use crate::error::TaskError;
 use crate::state::State;
 use crate::task::{TaskContext, TaskSettings};
 use crate::types::{Alias, Amount};
-use crate::utils::{get_balance, RetryConfig};
+use crate::utils::{get_balance, ibc_token_address, is_native_denom, RetryConfig};
 
 #[derive(Clone, Debug, TypedBuilder)]
 pub struct TransparentTransfer {
     source: Alias,
     target: Alias,
     amount: Amount,
+    denom: String,
     settings: TaskSettings,
 }
 
@@ -29,8 +30,8 @@ impl TaskContext for TransparentTransfer {
 
     fn summary(&self) -> String {
         format!(
-            "transparent-transfer/{}/{}/{}",
-            self.source.name, self.target.name, self.amount
+            "transparent-transfer/{}/{}/{}/{}",
+            self.source.name, self.target.name, self.amount, self.denom
         )
     }
 
@@ -41,32 +42,36 @@ impl TaskContext for TransparentTransfer {
     async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
         let wallet = ctx.namada.wallet.read().await;
 
-        let native_token_alias = Alias::nam();
-
         let source_address = wallet
             .find_address(&self.source.name)
             .ok_or_else(|| TaskError::Wallet(format!("No source address: {}", self.source.name)))?;
         let target_address = wallet
             .find_address(&self.target.name)
             .ok_or_else(|| TaskError::Wallet(format!("No target address: {}", self.target.name)))?;
-        let token_address = wallet
-            .find_address(&native_token_alias.name)
-            .ok_or_else(|| {
-                TaskError::Wallet(format!(
-                    "No native token address: {}",
-                    native_token_alias.name
-                ))
-            })?;
+        let token_amount = token::Amount::from_u64(self.amount);
+        let (token_address, denominated_amount) = if is_native_denom(&self.denom) {
+            let address = wallet
+                .find_address(&self.denom)
+                .ok_or_else(|| {
+                    TaskError::Wallet(format!("No native token address: {}", self.denom))
+                })?
+                .into_owned();
+            (address, DenominatedAmount::native(token_amount))
+        } else {
+            (
+                ibc_token_address(&self.denom),
+                DenominatedAmount::new(token_amount, 0u8.into()),
+            )
+        };
         let fee_payer = wallet
             .find_public_key(&self.settings.gas_payer.name)
             .map_err(|e| TaskError::Wallet(e.to_string()))?;
-        let token_amount = token::Amount::from_u64(self.amount);
 
         let tx_transfer_data = TxTransparentTransferData {
             source: source_address.into_owned(),
             target: target_address.into_owned(),
-            token: token_address.into_owned(),
-            amount: InputAmount::Unvalidated(DenominatedAmount::native(token_amount)),
+            token: token_address,
+            amount: InputAmount::Unvalidated(denominated_amount),
         };
 
         let mut transfer_tx_builder = ctx.namada.new_transparent_transfer(vec![tx_transfer_data]);
@@ -96,7 +101,7 @@ impl TaskContext for TransparentTransfer {
         ctx: &Ctx,
         retry_config: RetryConfig,
     ) -> Result<Vec<Check>, TaskError> {
-        let denom = Alias::nam().name;
+        let denom = self.denom.clone();
         let (_, pre_balance) = get_balance(ctx, &self.source, &denom, retry_config).await?;
         let source_check = Check::BalanceSource(
             check::balance_source::BalanceSource::builder()
@@ -121,7 +126,7 @@ impl TaskContext for TransparentTransfer {
     }
 
     fn update_state(&self, state: &mut State) {
-        state.decrease_balance(&self.source, self.amount);
-        state.increase_balance(&self.target, self.amount);
+        state.decrease_balance(&self.source, &self.denom, self.amount);
+        state.increase_balance(&self.target, &self.denom, self.amount);
     }
 }
@@ -10,12 +10,13 @@ use namada_sdk::Namada;
 use typed_builder::TypedBuilder;
 
 use crate::check::{self, Check};
+use crate::constants::WITHDRAW_EPOCH_OFFSET;
 use crate::context::Ctx;
 use crate::error::TaskError;
 use crate::state::State;
 use crate::task::{TaskContext, TaskSettings};
 use crate::types::{Alias, Amount, Epoch, ValidatorAddress};
-use crate::utils::{get_bond, RetryConfig};
+use crate::utils::{get_bond, get_unbonds, RetryConfig};
 
 #[derive(Clone, Debug, TypedBuilder)]
 pub struct Unbond {
@@ -26,6 +27,16 @@ pub struct Unbond {
     settings: TaskSettings,
 }
 
+impl Unbond {
+    pub fn source(&self) -> &Alias {
+        &self.source
+    }
+
+    pub fn withdraw_epoch(&self) -> Epoch {
+        self.epoch + WITHDRAW_EPOCH_OFFSET
+    }
+}
+
 impl TaskContext for Unbond {
     fn name(&self) -> String {
         "unbond".to_string()
@@ -87,7 +98,9 @@ impl TaskContext for Unbond {
         let pre_bond =
             get_bond(ctx, &self.source, &self.validator, self.epoch, retry_config).await?;
 
-        Ok(vec![Check::BondDecrease(
+        let pre_unbonds = get_unbonds(ctx, &self.source, &self.validator, retry_config).await?;
+
+        let check_bond = Check::BondDecrease(
             check::bond_decrease::BondDecrease::builder()
                 .target(self.source.clone())
                 .validator(self.validator.clone())
@@ -95,10 +108,27 @@ impl TaskContext for Unbond {
                 .epoch(self.epoch)
                 .amount(self.amount)
                 .build(),
-        )])
+        );
+
+        let check_unbond = Check::UnbondIncrease(
+            check::unbond_increase::UnbondIncrease::builder()
+                .target(self.source.clone())
+                .validator(self.validator.clone())
+                .pre_unbonds(pre_unbonds)
+                .withdraw_epoch(self.withdraw_epoch())
+                .amount(self.amount)
+                .build(),
+        );
+
+        Ok(vec![check_bond, check_unbond])
     }
 
     fn update_state(&self, state: &mut State) {
-        state.modify_unbond(&self.source, &self.validator, self.amount);
+        state.modify_unbond(
+            &self.source,
+            &self.validator,
+            self.amount,
+            self.withdraw_epoch(),
+        );
     }
 }
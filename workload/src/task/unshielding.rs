@@ -16,13 +16,17 @@ use crate::error::TaskError;
 use crate::state::State;
 use crate::task::{TaskContext, TaskSettings};
 use crate::types::{Alias, Amount, Height, MaspEpoch};
-use crate::utils::{get_balance, get_shielded_balance, shielded_sync_with_retry, RetryConfig};
+use crate::utils::{
+    get_balance, get_shielded_balance, ibc_token_address, is_native_denom,
+    shielded_sync_with_retry, RetryConfig,
+};
 
 #[derive(Clone, Debug, TypedBuilder)]
 pub struct Unshielding {
     source: Alias,
     target: Alias,
     amount: Amount,
+    denom: String,
     epoch: MaspEpoch,
     settings: TaskSettings,
 }
@@ -46,8 +50,8 @@ impl TaskContext for Unshielding {
 
     fn summary(&self) -> String {
         format!(
-            "unshielding/{}/{}/{}",
-            self.source.name, self.target.name, self.amount
+            "unshielding/{}/{}/{}/{}",
+            self.source.name, self.target.name, self.amount, self.denom
         )
     }
 
@@ -69,21 +73,26 @@ impl TaskContext for Unshielding {
             .find_address(&self.target.name)
             .ok_or_else(|| TaskError::Wallet(format!("No target address: {}", self.target.name)))?;
 
-        let native_token_alias = Alias::nam();
-        let token = wallet
-            .find_address(&native_token_alias.name)
-            .ok_or_else(|| {
-                TaskError::Wallet(format!(
-                    "No native token address: {}",
-                    native_token_alias.name
-                ))
-            })?;
         let token_amount = token::Amount::from_u64(self.amount);
-        let amount = InputAmount::Unvalidated(token::DenominatedAmount::native(token_amount));
+        let (token, denominated_amount) = if is_native_denom(&self.denom) {
+            let address = wallet
+                .find_address(&self.denom)
+                .ok_or_else(|| {
+                    TaskError::Wallet(format!("No native token address: {}", self.denom))
+                })?
+                .into_owned();
+            (address, token::DenominatedAmount::native(token_amount))
+        } else {
+            (
+                ibc_token_address(&self.denom),
+                token::DenominatedAmount::new(token_amount, 0u8.into()),
+            )
+        };
+        let amount = InputAmount::Unvalidated(denominated_amount);
 
         let tx_transfer_data = TxUnshieldingTransferData {
             target: target_address.into_owned(),
-            token: token.into_owned(),
+            token,
             amount,
         };
 
@@ -135,7 +144,7 @@ impl TaskContext for Unshielding {
     ) -> Result<Vec<Check>, TaskError> {
         shielded_sync_with_retry(ctx, &self.source, None, false, retry_config).await?;
 
-        let denom = Alias::nam().name;
+        let denom = self.denom.clone();
         let pre_balance = get_shielded_balance(ctx, &self.source, &denom, retry_config)
             .await?
             .unwrap_or_default();
@@ -162,6 +171,6 @@ impl TaskContext for Unshielding {
     }
 
     fn update_state(&self, state: &mut State) {
-        state.modify_unshielding(&self.source, &self.target, self.amount)
+        state.modify_unshielding(&self.source, &self.target, &self.denom, self.amount)
     }
 }
@@ -0,0 +1,107 @@
+use std::collections::{BTreeMap, HashMap};
+
+use namada_sdk::args::{self, TxBuilder};
+use namada_sdk::dec::Dec;
+use namada_sdk::signing::SigningTxData;
+use namada_sdk::tx::data::GasLimit;
+use namada_sdk::tx::Tx;
+use namada_sdk::Namada;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::check::{self, Check};
+use crate::context::Ctx;
+use crate::error::TaskError;
+use crate::state::State;
+use crate::task::{TaskContext, TaskSettings};
+use crate::types::Alias;
+use crate::utils::RetryConfig;
+
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct UpdateStewardCommission {
+    source: Alias,
+    commission: BTreeMap<Alias, Dec>,
+    settings: TaskSettings,
+}
+
+impl TaskContext for UpdateStewardCommission {
+    fn name(&self) -> String {
+        "update-steward-commission".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "update-steward-commission/{}/{}",
+            self.source.name,
+            self.commission.len()
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        let wallet = ctx.namada.wallet.read().await;
+        let source_address = wallet
+            .find_address(&self.source.name)
+            .ok_or_else(|| TaskError::Wallet(format!("No source address: {}", self.source.name)))?
+            .into_owned();
+        let fee_payer = wallet
+            .find_public_key(&self.settings.gas_payer.name)
+            .map_err(|e| TaskError::Wallet(e.to_string()))?;
+
+        let mut reward_distribution = HashMap::new();
+        for (target, rate) in &self.commission {
+            let target_address = wallet
+                .find_address(&target.name)
+                .ok_or_else(|| TaskError::Wallet(format!("No target address: {}", target.name)))?;
+            reward_distribution.insert(target_address.encode(), rate.to_string());
+        }
+        let commission = json!({ "reward_distribution": reward_distribution });
+
+        let mut update_commission_tx_builder = ctx
+            .namada
+            .new_update_steward_rewards(source_address, commission.to_string().into_bytes());
+
+        update_commission_tx_builder =
+            update_commission_tx_builder.gas_limit(GasLimit::from(self.settings.gas_limit));
+        update_commission_tx_builder = update_commission_tx_builder.wrapper_fee_payer(fee_payer);
+
+        let mut signing_keys = vec![];
+        for signer in &self.settings.signers {
+            let public_key = wallet
+                .find_public_key(&signer.name)
+                .map_err(|e| TaskError::Wallet(e.to_string()))?;
+            signing_keys.push(public_key)
+        }
+        update_commission_tx_builder = update_commission_tx_builder.signing_keys(signing_keys);
+        drop(wallet);
+
+        let (update_commission, signing_data) = update_commission_tx_builder
+            .build(&ctx.namada)
+            .await
+            .map_err(|e| TaskError::BuildTx(e.to_string()))?;
+
+        Ok((
+            update_commission,
+            vec![signing_data],
+            update_commission_tx_builder.tx,
+        ))
+    }
+
+    async fn build_checks(
+        &self,
+        _ctx: &Ctx,
+        _retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        Ok(vec![Check::StewardCommission(
+            check::steward_commission::StewardCommission::builder()
+                .steward(self.source.clone())
+                .commission(self.commission.clone())
+                .build(),
+        )])
+    }
+
+    fn update_state(&self, _state: &mut State) {}
+}
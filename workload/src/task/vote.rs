@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use namada_sdk::args::{self, TxBuilder};
 use namada_sdk::signing::SigningTxData;
 use namada_sdk::tx::data::GasLimit;
@@ -18,6 +20,8 @@ pub struct Vote {
     source: Alias,
     proposal_id: ProposalId,
     vote: ProposalVote,
+    /// Bonded stake of the source to each validator at the end epoch
+    stakes: BTreeMap<String, u64>,
     settings: TaskSettings,
 }
 
@@ -85,5 +89,12 @@ impl TaskContext for Vote {
         )])
     }
 
-    fn update_state(&self, _state: &mut State) {}
+    fn update_state(&self, state: &mut State) {
+        state.add_vote(
+            self.proposal_id,
+            &self.source,
+            self.vote.clone(),
+            self.stakes.clone(),
+        );
+    }
 }
@@ -0,0 +1,121 @@
+use std::str::FromStr;
+
+use namada_sdk::address::Address;
+use namada_sdk::args::{self, TxBuilder};
+use namada_sdk::signing::SigningTxData;
+use namada_sdk::tx::data::GasLimit;
+use namada_sdk::tx::Tx;
+use namada_sdk::Namada;
+use typed_builder::TypedBuilder;
+
+use crate::check::{self, Check};
+use crate::context::Ctx;
+use crate::error::TaskError;
+use crate::state::State;
+use crate::task::{TaskContext, TaskSettings};
+use crate::types::{Alias, Amount, Epoch, ValidatorAddress};
+use crate::utils::{get_balance, RetryConfig};
+
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct Withdraw {
+    source: Alias,
+    validator: ValidatorAddress,
+    amount: Amount,
+    epoch: Epoch,
+    settings: TaskSettings,
+}
+
+impl Withdraw {
+    pub fn source(&self) -> &Alias {
+        &self.source
+    }
+
+    pub fn validator(&self) -> &ValidatorAddress {
+        &self.validator
+    }
+}
+
+impl TaskContext for Withdraw {
+    fn name(&self) -> String {
+        "withdraw".to_string()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "withdraw/{}/{}/{}",
+            self.source.name, self.validator, self.amount
+        )
+    }
+
+    fn task_settings(&self) -> Option<&TaskSettings> {
+        Some(&self.settings)
+    }
+
+    async fn build_tx(&self, ctx: &Ctx) -> Result<(Tx, Vec<SigningTxData>, args::Tx), TaskError> {
+        let wallet = ctx.namada.wallet.read().await;
+
+        let source_address = wallet
+            .find_address(&self.source.name)
+            .ok_or_else(|| TaskError::Wallet(format!("No source address: {}", self.source.name)))?;
+        let fee_payer = wallet
+            .find_public_key(&self.settings.gas_payer.name)
+            .map_err(|e| TaskError::Wallet(e.to_string()))?;
+        let validator =
+            Address::from_str(&self.validator).expect("ValidatorAddress should be converted");
+
+        let mut withdraw_tx_builder = ctx
+            .namada
+            .new_withdraw(validator)
+            .source(source_address.into_owned());
+        withdraw_tx_builder =
+            withdraw_tx_builder.gas_limit(GasLimit::from(self.settings.gas_limit));
+        withdraw_tx_builder = withdraw_tx_builder.wrapper_fee_payer(fee_payer);
+        let mut signing_keys = vec![];
+        for signer in &self.settings.signers {
+            let public_key = wallet
+                .find_public_key(&signer.name)
+                .map_err(|e| TaskError::Wallet(e.to_string()))?;
+            signing_keys.push(public_key)
+        }
+        withdraw_tx_builder = withdraw_tx_builder.signing_keys(signing_keys);
+        drop(wallet);
+
+        let (withdraw_tx, signing_data) = withdraw_tx_builder
+            .build(&ctx.namada)
+            .await
+            .map_err(|e| TaskError::BuildTx(e.to_string()))?;
+
+        Ok((withdraw_tx, vec![signing_data], withdraw_tx_builder.tx))
+    }
+
+    async fn build_checks(
+        &self,
+        ctx: &Ctx,
+        retry_config: RetryConfig,
+    ) -> Result<Vec<Check>, TaskError> {
+        let denom = Alias::nam().name;
+        let (_, pre_balance) = get_balance(ctx, &self.source, &denom, retry_config).await?;
+
+        Ok(vec![
+            Check::BalanceTarget(
+                check::balance_target::BalanceTarget::builder()
+                    .target(self.source.clone())
+                    .pre_balance(pre_balance)
+                    .denom(denom)
+                    .amount(self.amount)
+                    .build(),
+            ),
+            Check::UnbondWithdrawn(
+                check::unbond_withdrawn::UnbondWithdrawn::builder()
+                    .target(self.source.clone())
+                    .validator(self.validator.clone())
+                    .epoch(self.epoch)
+                    .build(),
+            ),
+        ])
+    }
+
+    fn update_state(&self, state: &mut State) {
+        state.modify_withdraw(&self.source, &self.validator, self.epoch);
+    }
+}
@@ -132,6 +132,8 @@ pub enum ValidatorStatus {
     Active,
     Reactivating,
     Inactive,
+    Jailed,
+    Unjailing,
 }
 
 impl fmt::Display for ValidatorStatus {
@@ -140,6 +142,8 @@ impl fmt::Display for ValidatorStatus {
             ValidatorStatus::Active => write!(f, "active"),
             ValidatorStatus::Inactive => write!(f, "inactive"),
             ValidatorStatus::Reactivating => write!(f, "reactivating"),
+            ValidatorStatus::Jailed => write!(f, "jailed"),
+            ValidatorStatus::Unjailing => write!(f, "unjailing"),
         }
     }
 }
@@ -8,11 +8,13 @@ use crate::constants::{INIT_DELAY_SEC, MAX_DELAY_SEC, MAX_RETRY_COUNT};
 mod cosmos;
 mod ibc;
 mod query;
+mod rng;
 mod tx;
 
 pub use cosmos::*;
 pub use ibc::*;
 pub use query::*;
+pub use rng::*;
 pub use tx::*;
 
 pub fn base_dir() -> PathBuf {
@@ -5,17 +5,89 @@ use cosmrs::tx::{AuthInfo, Body, Fee, SignDoc, SignerInfo};
 use cosmrs::Any;
 use ibc_proto::cosmos::auth::v1beta1::query_client::QueryClient;
 use ibc_proto::cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest};
+use ibc_proto::cosmos::bank::v1beta1::query_client::QueryClient as BankQueryClient;
+use ibc_proto::cosmos::bank::v1beta1::QueryBalanceRequest;
 use ibc_proto::cosmos::base::v1beta1::Coin;
 use ibc_proto::ibc::apps::transfer::v1::MsgTransfer;
 use namada_sdk::ibc::core::host::types::identifiers::{ChannelId, PortId};
 use tendermint_rpc::Client;
 use tokio::time::{sleep, Duration};
 
-use crate::constants::{COSMOS_CHAIN_ID, COSMOS_FEE_AMOUNT, COSMOS_FEE_TOKEN, COSMOS_GAS_LIMIT};
-use crate::context::Ctx;
+use crate::constants::{COSMOS_FEE_AMOUNT, COSMOS_FEE_TOKEN, COSMOS_GAS_LIMIT};
+use crate::context::CosmosCtx;
 use crate::error::{QueryError, TaskError};
-use crate::types::{Amount, Height};
-use crate::utils::RetryConfig;
+use crate::types::{Alias, Amount, Height};
+use crate::utils::{nft_port_id, RetryConfig};
+
+/// `MsgTransfer` of the ICS-721 nft-transfer module
+#[derive(Clone, PartialEq, prost::Message)]
+struct MsgNftTransfer {
+    #[prost(string, tag = "1")]
+    source_port: String,
+    #[prost(string, tag = "2")]
+    source_channel: String,
+    #[prost(string, tag = "3")]
+    class_id: String,
+    #[prost(string, repeated, tag = "4")]
+    token_ids: Vec<String>,
+    #[prost(string, tag = "5")]
+    sender: String,
+    #[prost(string, tag = "6")]
+    receiver: String,
+    #[prost(message, optional, tag = "7")]
+    timeout_height: Option<ibc_proto::ibc::core::client::v1::Height>,
+    #[prost(uint64, tag = "8")]
+    timeout_timestamp: u64,
+    #[prost(string, tag = "9")]
+    memo: String,
+}
+
+impl Name for MsgNftTransfer {
+    const NAME: &'static str = "MsgTransfer";
+    const PACKAGE: &'static str = "ibc.applications.nft_transfer.v1";
+}
+
+/// `MsgMintNFT` of the NFT module on the counterparty
+#[derive(Clone, PartialEq, prost::Message)]
+struct MsgMintNft {
+    #[prost(string, tag = "1")]
+    id: String,
+    #[prost(string, tag = "2")]
+    denom_id: String,
+    #[prost(string, tag = "3")]
+    name: String,
+    #[prost(string, tag = "4")]
+    uri: String,
+    #[prost(string, tag = "5")]
+    data: String,
+    #[prost(string, tag = "6")]
+    sender: String,
+    #[prost(string, tag = "7")]
+    recipient: String,
+    #[prost(string, tag = "8")]
+    uri_hash: String,
+}
+
+impl Name for MsgMintNft {
+    const NAME: &'static str = "MsgMintNFT";
+    const PACKAGE: &'static str = "irismod.nft";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct QueryNftOwnerRequest {
+    #[prost(string, tag = "1")]
+    class_id: String,
+    #[prost(string, tag = "2")]
+    id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct QueryNftOwnerResponse {
+    #[prost(string, tag = "1")]
+    owner: String,
+}
+
+const NFT_OWNER_QUERY_PATH: &str = "/cosmos.nft.v1beta1.Query/Owner";
 
 pub fn build_cosmos_ibc_transfer(
     sender: &str,
@@ -52,17 +124,75 @@ pub fn build_cosmos_ibc_transfer(
     }
 }
 
-pub async fn execute_cosmos_tx(ctx: &Ctx, any_msg: Any) -> Result<Height, TaskError> {
+/// Memo of the packet-forward-middleware to forward the packet to another chain
+pub fn pfm_memo(receiver: &str, channel_id: &ChannelId) -> String {
+    serde_json::json!({
+        "forward": {
+            "receiver": receiver,
+            "port": PortId::transfer().to_string(),
+            "channel": channel_id.to_string(),
+        }
+    })
+    .to_string()
+}
+
+pub fn build_cosmos_nft_mint(sender: &str, class_id: &str, token_id: &str) -> Any {
+    let msg = MsgMintNft {
+        id: token_id.to_string(),
+        denom_id: class_id.to_string(),
+        name: token_id.to_string(),
+        sender: sender.to_string(),
+        recipient: sender.to_string(),
+        ..Default::default()
+    };
+
+    Any {
+        type_url: MsgMintNft::type_url(),
+        value: msg.encode_to_vec(),
+    }
+}
+
+pub fn build_cosmos_nft_transfer(
+    sender: &str,
+    receiver: &str,
+    class_id: &str,
+    token_id: &str,
+    src_channel_id: &ChannelId,
+    timeout_height: Height,
+) -> Any {
+    let timeout_height = ibc_proto::ibc::core::client::v1::Height {
+        revision_number: 0,
+        revision_height: timeout_height,
+    };
+    let msg = MsgNftTransfer {
+        source_port: nft_port_id().to_string(),
+        source_channel: src_channel_id.to_string(),
+        class_id: class_id.to_string(),
+        token_ids: vec![token_id.to_string()],
+        sender: sender.to_string(),
+        receiver: receiver.to_string(),
+        timeout_height: Some(timeout_height),
+        timeout_timestamp: 0,
+        memo: String::new(),
+    };
+
+    Any {
+        type_url: MsgNftTransfer::type_url(),
+        value: msg.encode_to_vec(),
+    }
+}
+
+pub async fn execute_cosmos_tx(cosmos: &CosmosCtx, any_msg: Any) -> Result<Height, TaskError> {
     let body = Body::new(vec![any_msg], "", 0u32);
-    let signing_key = &ctx.cosmos.signing_key;
+    let signing_key = &cosmos.signing_key;
 
     // Account
-    let mut grpc_client = QueryClient::connect(ctx.cosmos.grpc_endpoint.clone())
+    let mut grpc_client = QueryClient::connect(cosmos.grpc_endpoint.clone())
         .await
         .expect("invalid gRPC");
     let res = grpc_client
         .account(QueryAccountRequest {
-            address: ctx.cosmos.account.to_string(),
+            address: cosmos.account.to_string(),
         })
         .await
         .map_err(|e| QueryError::Grpc(e.to_string()))?;
@@ -89,7 +219,7 @@ pub async fn execute_cosmos_tx(ctx: &Ctx, any_msg: Any) -> Result<Height, TaskEr
     let sign_doc = SignDoc::new(
         &body,
         &auth_info,
-        &tendermint::chain::Id::from_str(COSMOS_CHAIN_ID).expect("chain ID should be parsable"),
+        &tendermint::chain::Id::from_str(&cosmos.chain_id).expect("chain ID should be parsable"),
         base_account.account_number,
     )
     .map_err(|e| TaskError::CosmosTx(e.to_string()))?;
@@ -99,8 +229,7 @@ pub async fn execute_cosmos_tx(ctx: &Ctx, any_msg: Any) -> Result<Height, TaskEr
 
     let tx_bytes = tx_raw.to_bytes().expect("tx should be encoded");
 
-    let response = ctx
-        .cosmos
+    let response = cosmos
         .client
         .broadcast_tx_commit(tx_bytes)
         .await
@@ -115,8 +244,11 @@ pub async fn execute_cosmos_tx(ctx: &Ctx, any_msg: Any) -> Result<Height, TaskEr
     }
 }
 
-pub async fn get_cosmos_height(ctx: &Ctx, retry_config: RetryConfig) -> Result<Height, QueryError> {
-    let status = tryhard::retry_fn(|| ctx.cosmos.client.status())
+pub async fn get_cosmos_height(
+    cosmos: &CosmosCtx,
+    retry_config: RetryConfig,
+) -> Result<Height, QueryError> {
+    let status = tryhard::retry_fn(|| cosmos.client.status())
         .with_config(retry_config)
         .on_retry(|attempt, _, error| {
             let error = error.to_string();
@@ -130,9 +262,9 @@ pub async fn get_cosmos_height(ctx: &Ctx, retry_config: RetryConfig) -> Result<H
     Ok(status.sync_info.latest_block_height.into())
 }
 
-pub async fn wait_cosmos_settlement(ctx: &Ctx, height: Height) {
+pub async fn wait_cosmos_settlement(cosmos: &CosmosCtx, height: Height) {
     loop {
-        if let Ok(status) = ctx.cosmos.client.status().await {
+        if let Ok(status) = cosmos.client.status().await {
             let current_height: u64 = status.sync_info.latest_block_height.into();
             if current_height > height {
                 break;
@@ -146,3 +278,84 @@ pub async fn wait_cosmos_settlement(ctx: &Ctx, height: Height) {
         sleep(Duration::from_secs(2)).await
     }
 }
+
+/// The owner of the NFT on Cosmos. Returns `None` if the NFT doesn't exist.
+pub async fn get_cosmos_nft_owner(
+    cosmos: &CosmosCtx,
+    class_id: &str,
+    token_id: &str,
+    retry_config: RetryConfig,
+) -> Result<Option<String>, QueryError> {
+    let request = QueryNftOwnerRequest {
+        class_id: class_id.to_string(),
+        id: token_id.to_string(),
+    }
+    .encode_to_vec();
+
+    let response = tryhard::retry_fn(|| {
+        cosmos.client.abci_query(
+            Some(NFT_OWNER_QUERY_PATH.to_string()),
+            request.clone(),
+            None,
+            false,
+        )
+    })
+    .with_config(retry_config)
+    .on_retry(|attempt, _, error| {
+        let error = error.to_string();
+        async move {
+            tracing::info!("Retry {} due to {}...", attempt, error);
+        }
+    })
+    .await
+    .map_err(QueryError::CosmosRpc)?;
+
+    if response.code.is_err() {
+        return Ok(None);
+    }
+    let owner = QueryNftOwnerResponse::decode(response.value.as_slice())
+        .map_err(|e| QueryError::Convert(e.to_string()))?
+        .owner;
+
+    Ok((!owner.is_empty()).then_some(owner))
+}
+
+/// The balance of the account on Cosmos via gRPC
+pub async fn get_cosmos_balance(
+    cosmos: &CosmosCtx,
+    account: &Alias,
+    denom: &str,
+    retry_config: RetryConfig,
+) -> Result<Amount, QueryError> {
+    let balance = tryhard::retry_fn(|| async {
+        let mut grpc_client = BankQueryClient::connect(cosmos.grpc_endpoint.clone())
+            .await
+            .map_err(|e| e.to_string())?;
+        grpc_client
+            .balance(QueryBalanceRequest {
+                address: account.name.clone(),
+                denom: denom.to_string(),
+            })
+            .await
+            .map_err(|e| e.to_string())
+    })
+    .with_config(retry_config)
+    .on_retry(|attempt, _, error| {
+        let error = error.to_string();
+        async move {
+            tracing::info!("Retry {} due to {}...", attempt, error);
+        }
+    })
+    .await
+    .map_err(QueryError::Grpc)?
+    .into_inner()
+    .balance;
+
+    match balance {
+        Some(coin) => coin
+            .amount
+            .parse()
+            .map_err(|e: std::num::ParseIntError| QueryError::Convert(e.to_string())),
+        None => Ok(0),
+    }
+}
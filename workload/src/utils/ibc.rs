@@ -2,7 +2,6 @@ use namada_sdk::address::Address;
 use namada_sdk::events::extend::Height as HeightAttr;
 use namada_sdk::events::Event;
 use namada_sdk::ibc::apps::transfer::types::ack_success_b64;
-use namada_sdk::ibc::apps::transfer::types::packet::PacketData;
 use namada_sdk::ibc::core::channel::types::acknowledgement::{
     Acknowledgement, AcknowledgementStatus,
 };
@@ -21,8 +20,8 @@ use tendermint_rpc::Order;
 use namada_sdk::io::Client;
 use sha2::{Digest, Sha256};
 
-use crate::constants::IBC_TIMEOUT_HEIGHT_OFFSET;
-use crate::context::Ctx;
+use crate::constants::{IBC_TIMEOUT_HEIGHT_OFFSET, NFT_PORT_ID};
+use crate::context::{CosmosCtx, Ctx};
 use crate::error::QueryError;
 use crate::types::{Alias, Height};
 use crate::utils::{
@@ -37,8 +36,61 @@ pub fn ibc_denom(channel_id: &ChannelId, base_token: &str) -> String {
     format!("transfer/{channel_id}/{base_token}")
 }
 
+/// The denom on Cosmos of the token with the trace from Cosmos, e.g. `transfer/channel-0/nam`
+pub async fn cosmos_denom(ctx: &Ctx, denom: &str) -> Result<String, QueryError> {
+    if is_native_denom(denom) {
+        return Ok(denom.to_string());
+    }
+
+    let base_token = base_denom(denom);
+    let wallet = ctx.namada.wallet.read().await;
+    let token_address = wallet
+        .find_address(&base_token)
+        .ok_or_else(|| QueryError::Wallet(format!("No native token address: {base_token}")))?;
+    let denom = denom.replace(&base_token, &token_address.to_string());
+
+    Ok(cosmos_denom_hash(&denom))
+}
+
+pub fn nft_port_id() -> PortId {
+    NFT_PORT_ID.parse().expect("NFT port ID should be valid")
+}
+
+/// The IBC trace of the NFT received through the channel
+pub fn nft_trace(channel_id: &ChannelId, class_id: &str, token_id: &str) -> String {
+    format!("{NFT_PORT_ID}/{channel_id}/{class_id}/{token_id}")
+}
+
+/// The base class ID and the token ID of the NFT trace
+pub fn nft_class_and_token_id(trace: &str) -> (String, String) {
+    let (class_trace, token_id) = trace
+        .rsplit_once('/')
+        .expect("NFT trace should have the token ID");
+    (base_denom(class_trace), token_id.to_string())
+}
+
+/// Split the trace into the hops and the base denom, e.g.
+/// `transfer/channel-1/transfer/channel-0/uatom` into
+/// `[(transfer, channel-1), (transfer, channel-0)]` and `uatom`
+pub fn parse_trace(denom: &str) -> (Vec<(PortId, ChannelId)>, String) {
+    let parts: Vec<_> = denom.split('/').collect();
+    let mut hops = vec![];
+    let mut rest = parts.as_slice();
+    while let [port_id, channel_id, remaining @ ..] = rest {
+        if remaining.is_empty() || !channel_id.starts_with("channel-") {
+            break;
+        }
+        let (Ok(port_id), Ok(channel_id)) = (port_id.parse(), channel_id.parse()) else {
+            break;
+        };
+        hops.push((port_id, channel_id));
+        rest = remaining;
+    }
+    (hops, rest.join("/"))
+}
+
 pub fn base_denom(denom: &str) -> String {
-    denom.split('/').skip(2).collect::<Vec<_>>().join("/")
+    parse_trace(denom).1
 }
 
 pub fn ibc_token_address(denom: &str) -> Address {
@@ -54,8 +106,10 @@ pub fn cosmos_denom_hash(denom: &str) -> String {
 
 /// Get the IBC packet sequence.
 /// This function assumes that the workload has submitted only one tx with send_packet at once.
+/// `namada_channel_id` is the channel on Namada to find the counterparty.
 pub async fn get_ibc_packet_sequence(
     ctx: &Ctx,
+    namada_channel_id: &ChannelId,
     sender: &Alias,
     receiver: &Alias,
     height: Height,
@@ -85,7 +139,8 @@ pub async fn get_ibc_packet_sequence(
     let query_fn: Box<dyn Fn() -> _> = if from_namada {
         Box::new(|| ctx.namada.client.block_results(height))
     } else {
-        Box::new(|| ctx.cosmos.client.block_results(height))
+        let cosmos = ctx.counterparty(namada_channel_id)?;
+        Box::new(move || cosmos.client.block_results(height))
     };
 
     let block_results = tryhard::retry_fn(query_fn)
@@ -128,10 +183,11 @@ fn get_packet_sequence(
             for attr in &event.attributes {
                 if attr.key_str().expect("key should exist") == "packet_data" {
                     let val = attr.value_str().expect("value should exist");
-                    let packet_data: PacketData =
+                    // ICS-20 and ICS-721 packets have the same sender and receiver fields
+                    let packet_data: serde_json::Value =
                         serde_json::from_str(val).expect("packet should be parsable");
-                    if packet_data.sender.as_ref() == sender
-                        && packet_data.receiver.as_ref() == receiver
+                    if packet_data["sender"].as_str() == Some(sender)
+                        && packet_data["receiver"].as_str() == Some(receiver)
                     {
                         is_target = true;
                         break;
@@ -159,6 +215,7 @@ fn get_packet_sequence(
 
 pub async fn is_ibc_transfer_successful(
     ctx: &Ctx,
+    port_id: &PortId,
     src_channel_id: &ChannelId,
     dest_channel_id: &ChannelId,
     sequence: Sequence,
@@ -167,6 +224,7 @@ pub async fn is_ibc_transfer_successful(
     let event = loop {
         if let Some(event) = get_ibc_event(
             ctx,
+            port_id,
             "acknowledge_packet",
             src_channel_id,
             dest_channel_id,
@@ -180,6 +238,7 @@ pub async fn is_ibc_transfer_successful(
 
         if get_ibc_event(
             ctx,
+            port_id,
             "timeout_packet",
             src_channel_id,
             dest_channel_id,
@@ -251,8 +310,57 @@ pub async fn is_ibc_transfer_successful(
     Err(QueryError::Ibc(format!("Tx with packet ack was not found: src_channel {src_channel_id}, dest_channel {dest_channel_id}, sequence {sequence}")))
 }
 
+/// Wait for the packet to be timed out on Namada.
+/// Returns the height where the timeout was processed, or `None` if the packet was acknowledged.
+pub async fn wait_ibc_timeout(
+    ctx: &Ctx,
+    port_id: &PortId,
+    src_channel_id: &ChannelId,
+    dest_channel_id: &ChannelId,
+    sequence: Sequence,
+    retry_config: RetryConfig,
+) -> Result<Option<Height>, QueryError> {
+    loop {
+        if let Some(event) = get_ibc_event(
+            ctx,
+            port_id,
+            "timeout_packet",
+            src_channel_id,
+            dest_channel_id,
+            sequence,
+            retry_config,
+        )
+        .await?
+        {
+            let height = event
+                .read_attribute::<HeightAttr>()
+                .expect("Height should exist");
+            return Ok(Some(height.into()));
+        }
+
+        if get_ibc_event(
+            ctx,
+            port_id,
+            "acknowledge_packet",
+            src_channel_id,
+            dest_channel_id,
+            sequence,
+            retry_config,
+        )
+        .await?
+        .is_some()
+        {
+            // packet was received before the timeout
+            return Ok(None);
+        }
+
+        tracing::info!("TimeoutPacket or Ack not found. Retry queries...");
+    }
+}
+
 pub async fn is_recv_packet(
     ctx: &Ctx,
+    port_id: &PortId,
     src_channel_id: &ChannelId,
     dest_channel_id: &ChannelId,
     sequence: Sequence,
@@ -261,6 +369,7 @@ pub async fn is_recv_packet(
     let event = loop {
         if let Some(event) = get_ibc_event(
             ctx,
+            port_id,
             "write_acknowledgement",
             src_channel_id,
             dest_channel_id,
@@ -273,7 +382,8 @@ pub async fn is_recv_packet(
         }
 
         if get_ibc_event_cosmos(
-            ctx,
+            ctx.counterparty(dest_channel_id)?,
+            port_id,
             "timeout_packet",
             src_channel_id,
             dest_channel_id,
@@ -307,6 +417,7 @@ pub async fn is_recv_packet(
 
 async fn get_ibc_event(
     ctx: &Ctx,
+    port_id: &PortId,
     ibc_event_type: &str,
     src_channel_id: &ChannelId,
     dest_channel_id: &ChannelId,
@@ -314,7 +425,6 @@ async fn get_ibc_event(
     retry_config: RetryConfig,
 ) -> Result<Option<Event>, QueryError> {
     let ibc_event_type = IbcEventType(ibc_event_type.to_string());
-    let port_id = PortId::transfer();
     let shell = RPC.shell();
 
     // Look for recv_packet event with the sequence
@@ -325,9 +435,9 @@ async fn get_ibc_event(
             .ibc_packet(
                 &ctx.namada.client,
                 &ibc_event_type,
-                &port_id,
+                port_id,
                 src_channel_id,
-                &port_id,
+                port_id,
                 dest_channel_id,
                 &sequence,
             )
@@ -345,19 +455,25 @@ async fn get_ibc_event(
 }
 
 async fn get_ibc_event_cosmos(
-    ctx: &Ctx,
+    cosmos: &CosmosCtx,
+    port_id: &PortId,
     ibc_event_type: &str,
     src_channel_id: &ChannelId,
     dest_channel_id: &ChannelId,
     sequence: Sequence,
     retry_config: RetryConfig,
 ) -> Result<Option<TmEvent>, QueryError> {
-    let query = packet_query(ibc_event_type, src_channel_id, dest_channel_id, sequence);
-    let mut cosmos_height = get_cosmos_height(ctx, retry_config).await?;
+    let query = packet_query(
+        ibc_event_type,
+        port_id,
+        src_channel_id,
+        dest_channel_id,
+        sequence,
+    );
+    let mut cosmos_height = get_cosmos_height(cosmos, retry_config).await?;
     let timeout_height = cosmos_height + IBC_TIMEOUT_HEIGHT_OFFSET * 2;
     while cosmos_height < timeout_height {
-        match ctx
-            .cosmos
+        match cosmos
             .client
             .tx_search(query.clone(), false, 1, 10, Order::Descending)
             .await
@@ -370,7 +486,7 @@ async fn get_ibc_event_cosmos(
                 }
             }
             _ => {
-                wait_cosmos_settlement(ctx, cosmos_height).await;
+                wait_cosmos_settlement(cosmos, cosmos_height).await;
                 cosmos_height += 1;
                 tracing::info!(
                     "Retry IBC {ibc_event_type} event query at {cosmos_height} on Cosmos..."
@@ -383,6 +499,7 @@ async fn get_ibc_event_cosmos(
 
 fn packet_query(
     ibc_event_type: &str,
+    port_id: &PortId,
     src_channel_id: &ChannelId,
     dest_channel_id: &ChannelId,
     sequence: Sequence,
@@ -393,7 +510,7 @@ fn packet_query(
     )
     .and_eq(
         format!("{ibc_event_type}.packet_src_port"),
-        PortId::transfer().to_string(),
+        port_id.to_string(),
     )
     .and_eq(
         format!("{ibc_event_type}.packet_dst_channel"),
@@ -401,7 +518,7 @@ fn packet_query(
     )
     .and_eq(
         format!("{ibc_event_type}.packet_dst_port"),
-        PortId::transfer().to_string(),
+        port_id.to_string(),
     )
     .and_eq(
         format!("{ibc_event_type}.packet_sequence"),
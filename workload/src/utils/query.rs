@@ -1,15 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::str::FromStr;
 use std::time::{self, Instant};
 
 use namada_sdk::account::Account;
 use namada_sdk::address::Address;
 use namada_sdk::control_flow::install_shutdown_signal;
+use namada_sdk::dec::Dec;
+use namada_sdk::governance::utils::ProposalResult;
 use namada_sdk::io::DevNullProgressBar;
 use namada_sdk::masp::shielded_wallet::ShieldedApi;
-use namada_sdk::masp::{IndexerMaspClient, LedgerMaspClient, MaspLocalTaskEnv, ShieldedSyncConfig};
+use namada_sdk::masp::{
+    encode_asset_type, IndexerMaspClient, LedgerMaspClient, MaspLocalTaskEnv, ShieldedSyncConfig,
+};
 use namada_sdk::masp_primitives::zip32;
-use namada_sdk::proof_of_stake::types::ValidatorStateInfo;
+use namada_sdk::proof_of_stake::types::{Slash, ValidatorStateInfo};
+use namada_sdk::queries::RPC;
 use namada_sdk::token::{self, MaspEpoch};
 use namada_sdk::{rpc, Namada};
 use namada_wallet::DatedKeypair;
@@ -18,9 +23,12 @@ use serde_json::json;
 use tokio::time::{sleep, Duration};
 use tryhard::{backoff_strategies::ExponentialBackoff, NoOnRetry, RetryFutureConfig};
 
+use crate::constants::{CUBIC_SLASHING_WINDOW_LEN, SLASH_PROCESSING_EPOCH_OFFSET, UNBONDING_LEN};
 use crate::context::Ctx;
 use crate::error::QueryError;
-use crate::types::{Alias, Epoch, Height, ProposalId, ProposalVote};
+use crate::types::{
+    Alias, CommissionChange, CommissionRate, Epoch, Height, ProposalId, ProposalVote,
+};
 use crate::utils::{ibc_token_address, is_native_denom, RetryConfig};
 
 pub async fn get_account_info(
@@ -104,17 +112,96 @@ pub async fn get_validator_state(
     Ok((target_address, state))
 }
 
+pub async fn get_validator_last_slash_epoch(
+    ctx: &Ctx,
+    target: &Alias,
+    retry_config: RetryConfig,
+) -> Result<Option<Epoch>, QueryError> {
+    let wallet = ctx.namada.wallet.read().await;
+    let target_address = wallet
+        .find_address(&target.name)
+        .ok_or_else(|| QueryError::Wallet(format!("No target address: {}", target.name)))?
+        .into_owned();
+    drop(wallet);
+
+    let key = namada_sdk::proof_of_stake::storage_key::validator_last_slash_key(&target_address);
+    let has_key = tryhard::retry_fn(|| rpc::query_has_storage_key(&ctx.namada.client, &key))
+        .with_config(retry_config)
+        .on_retry(|attempt, _, error| {
+            let error = error.to_string();
+            async move {
+                tracing::info!("Retry {attempt} due to {error}...");
+            }
+        })
+        .await
+        .map_err(QueryError::Rpc)?;
+    if !has_key {
+        return Ok(None);
+    }
+
+    let epoch: namada_sdk::state::Epoch =
+        tryhard::retry_fn(|| rpc::query_storage_value(&ctx.namada.client, &key))
+            .with_config(retry_config)
+            .on_retry(|attempt, _, error| {
+                let error = error.to_string();
+                async move {
+                    tracing::info!("Retry {attempt} due to {error}...");
+                }
+            })
+            .await
+            .map_err(QueryError::Rpc)?;
+
+    Ok(Some(epoch.0))
+}
+
+pub async fn get_validator_commission(
+    ctx: &Ctx,
+    target: &Alias,
+    epoch: Epoch,
+    retry_config: RetryConfig,
+) -> Result<(Address, Option<(CommissionRate, CommissionChange)>), QueryError> {
+    let wallet = ctx.namada.wallet.read().await;
+    let target_address = wallet
+        .find_address(&target.name)
+        .ok_or_else(|| QueryError::Wallet(format!("No target address: {}", target.name)))?
+        .into_owned();
+    drop(wallet);
+
+    let commission = tryhard::retry_fn(|| {
+        rpc::query_commission_rate(&ctx.namada.client, &target_address, Some(epoch.into()))
+    })
+    .with_config(retry_config)
+    .on_retry(|attempt, _, error| {
+        let error = error.to_string();
+        async move {
+            tracing::info!("Retry {attempt} due to {error}...");
+        }
+    })
+    .await
+    .map_err(QueryError::Rpc)?;
+
+    Ok((
+        target_address,
+        commission
+            .commission_rate
+            .zip(commission.max_commission_change_per_epoch),
+    ))
+}
+
 pub async fn get_validator_addresses(
     ctx: &Ctx,
     retry_config: RetryConfig,
 ) -> Result<Vec<Address>, QueryError> {
     let current_epoch = get_epoch(ctx, retry_config).await?;
-    let validators = rpc::get_all_consensus_validators(&ctx.namada.client, current_epoch.into())
-        .await
-        .map_err(QueryError::Rpc)?
-        .into_iter()
-        .map(|v| v.address)
-        .collect();
+    let mut validators: Vec<_> =
+        rpc::get_all_consensus_validators(&ctx.namada.client, current_epoch.into())
+            .await
+            .map_err(QueryError::Rpc)?
+            .into_iter()
+            .map(|v| v.address)
+            .collect();
+    // Sorted so that a seeded run picks the same validators
+    validators.sort();
 
     Ok(validators)
 }
@@ -178,7 +265,8 @@ pub async fn get_balance(
     Ok((target_address.into_owned(), balance))
 }
 
-/// Shielded balance. Need shielded-sync in advance.
+/// Shielded balance including the rewards of the conversions up to the
+/// current MASP epoch. Need shielded-sync in advance.
 pub async fn get_shielded_balance(
     ctx: &Ctx,
     source: &Alias,
@@ -211,8 +299,10 @@ pub async fn get_shielded_balance(
         .fvk
         .vk;
 
+    // The exchanged balance doesn't depend on which notes have been already
+    // converted by the spending transactions
     let Some(balance) = shielded_ctx
-        .compute_shielded_balance(&viewing_key)
+        .compute_exchanged_balance(client, ctx.namada.io(), &viewing_key, masp_epoch)
         .await
         .map_err(|e| QueryError::ShieldedContext(e.to_string()))?
     else {
@@ -228,6 +318,74 @@ pub async fn get_shielded_balance(
     Ok(Some(total_balance.into()))
 }
 
+/// Shielded reward in the native token for holding the amount of the token
+/// in the MASP since the given MASP epoch. It is computed from the conversion
+/// of the token's asset type at the epoch to the latest MASP epoch for each
+/// digit position of the amount.
+pub async fn get_shielded_reward(
+    ctx: &Ctx,
+    denom: &str,
+    amount: token::Amount,
+    from_epoch: MaspEpoch,
+) -> Result<token::Amount, QueryError> {
+    if amount.is_zero() {
+        return Ok(token::Amount::zero());
+    }
+
+    let (token_address, token_denom) = if is_native_denom(denom) {
+        let wallet = ctx.namada.wallet.read().await;
+        let address = wallet
+            .find_address(denom)
+            .ok_or_else(|| QueryError::Wallet(format!("No native token address: {denom}",)))?
+            .into_owned();
+        (address, token::NATIVE_MAX_DECIMAL_PLACES.into())
+    } else {
+        (ibc_token_address(denom), 0u8.into())
+    };
+
+    let mut reward = token::Amount::zero();
+    for digit in token::MaspDigitPos::iter() {
+        let value = digit.denominate(&amount);
+        if value == 0 {
+            continue;
+        }
+        let asset_type =
+            encode_asset_type(token_address.clone(), token_denom, digit, Some(from_epoch))
+                .map_err(|e| QueryError::Convert(e.to_string()))?;
+
+        // No conversion when the asset type is already at the latest MASP epoch
+        // or the token isn't rewarded
+        let Some((_, _, _, _, conversion, _)) =
+            rpc::query_conversion(&ctx.namada.client, asset_type).await
+        else {
+            continue;
+        };
+
+        // The conversion burns the precision of the old asset type and mints
+        // the same amount of the latest asset type with the reward in the
+        // native token at the same digit position
+        let precision = conversion.get(&asset_type).unsigned_abs();
+        let rate: i128 = conversion.components().map(|(_, value)| *value).sum();
+        reward = converted_reward(value, precision, rate)
+            .and_then(|r| token::Amount::from_masp_denominated_i128(r, digit))
+            .and_then(|r| reward.checked_add(r))
+            .ok_or_else(|| QueryError::Convert("Shielded reward is overflowing".to_string()))?;
+    }
+
+    Ok(reward)
+}
+
+/// The reward of the value at a digit position. As the conversion by the
+/// shielded wallet, the conversion is applied to the multiple of the precision
+/// and the remainder stays at the old asset type without the reward.
+fn converted_reward(value: u64, precision: u128, rate: i128) -> Option<i128> {
+    if precision == 0 {
+        return Some(0);
+    }
+    let usage = i128::try_from(u128::from(value) / precision).ok()?;
+    usage.checked_mul(rate.max(0))
+}
+
 pub async fn get_block_height(ctx: &Ctx, retry_config: RetryConfig) -> Result<Height, QueryError> {
     let block = tryhard::retry_fn(|| rpc::query_block(&ctx.namada.client))
         .with_config(retry_config)
@@ -354,6 +512,229 @@ pub async fn get_bond(
     .map_err(QueryError::Rpc)
 }
 
+pub async fn get_unbonds(
+    ctx: &Ctx,
+    source: &Alias,
+    validator: &str,
+    retry_config: RetryConfig,
+) -> Result<BTreeMap<Epoch, token::Amount>, QueryError> {
+    let wallet = ctx.namada.wallet.read().await;
+    let source_address = wallet
+        .find_address(&source.name)
+        .ok_or_else(|| QueryError::Wallet(format!("No source address: {}", source.name)))?
+        .into_owned();
+    drop(wallet);
+    let validator_address =
+        Address::from_str(validator).expect("ValidatorAddress should be converted");
+
+    let unbonds = tryhard::retry_fn(|| {
+        rpc::query_unbond_with_slashing(&ctx.namada.client, &source_address, &validator_address)
+    })
+    .with_config(retry_config)
+    .on_retry(|attempt, _, error| {
+        let error = error.to_string();
+        async move {
+            tracing::info!("Retry {} due to {}...", attempt, error);
+        }
+    })
+    .await
+    .map_err(QueryError::Rpc)?;
+
+    // aggregate the unbonds by the withdraw epoch
+    let mut chunks: BTreeMap<Epoch, token::Amount> = BTreeMap::new();
+    for ((_start_epoch, withdraw_epoch), amount) in unbonds {
+        let chunk = chunks.entry(withdraw_epoch.0).or_default();
+        *chunk = chunk
+            .checked_add(amount)
+            .ok_or_else(|| QueryError::Convert("Unbond amount overflow".to_string()))?;
+    }
+
+    Ok(chunks)
+}
+
+/// Processed slashes of the validator
+pub async fn get_slashes(
+    ctx: &Ctx,
+    validator: &str,
+    retry_config: RetryConfig,
+) -> Result<Vec<Slash>, QueryError> {
+    let validator_address =
+        Address::from_str(validator).expect("ValidatorAddress should be converted");
+
+    tryhard::retry_fn(|| {
+        RPC.vp()
+            .pos()
+            .validator_slashes(&ctx.namada.client, &validator_address)
+    })
+    .with_config(retry_config)
+    .on_retry(|attempt, _, error| {
+        let error = error.to_string();
+        async move {
+            tracing::info!("Retry {} due to {}...", attempt, error);
+        }
+    })
+    .await
+    .map_err(|e| QueryError::Rpc(namada_sdk::error::Error::Other(e.to_string())))
+}
+
+/// The amount after applying the slashes in the same way as the ledger. The
+/// slashes should be sorted by the infraction epoch.
+pub fn apply_slashes(
+    amount: token::Amount,
+    slashes: &[&Slash],
+) -> Result<token::Amount, QueryError> {
+    let mut final_amount = amount;
+    let mut computed_slashes: BTreeMap<Epoch, token::Amount> = BTreeMap::new();
+    for slash in slashes {
+        // the slashes processed before this infraction reduced the slashable amount
+        let slashable_amount = computed_slashes
+            .iter()
+            .filter(|(epoch, _)| **epoch + SLASH_PROCESSING_EPOCH_OFFSET <= slash.epoch.0)
+            .fold(amount, |acc, (_, slashed)| {
+                acc.checked_sub(*slashed).unwrap_or_default()
+            });
+        let slashed = slashable_amount
+            .mul_ceil(slash.rate)
+            .map_err(|e| QueryError::Convert(e.to_string()))?;
+        final_amount = final_amount.checked_sub(slashed).unwrap_or_default();
+        computed_slashes.insert(slash.epoch.0, slashed);
+    }
+
+    Ok(final_amount)
+}
+
+/// Reduction of the bond and the unbonds by the slashes processed after the epoch
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SlashedAmount {
+    pub bond: token::Amount,
+    pub unbond: token::Amount,
+    /// Each new slash can differ by 1 from the ledger due to the rounding
+    pub tolerance: token::Amount,
+}
+
+impl SlashedAmount {
+    /// Whether the amounts are equal within the rounding tolerance
+    pub fn is_within_tolerance(&self, actual: token::Amount, expected: token::Amount) -> bool {
+        let diff = if actual > expected {
+            actual.checked_sub(expected)
+        } else {
+            expected.checked_sub(actual)
+        };
+        diff.is_some_and(|diff| diff <= self.tolerance)
+    }
+}
+
+/// Compute the amounts slashed by the slashes processed after the epoch from
+/// the bonded and unbonded chunks of the source
+pub async fn get_slashed_amount_since(
+    ctx: &Ctx,
+    source: &Alias,
+    validator: &str,
+    epoch: Epoch,
+    retry_config: RetryConfig,
+) -> Result<SlashedAmount, QueryError> {
+    let mut slashes = get_slashes(ctx, validator, retry_config).await?;
+    if slashes
+        .iter()
+        .all(|slash| slash.epoch.0 + SLASH_PROCESSING_EPOCH_OFFSET <= epoch)
+    {
+        return Ok(SlashedAmount::default());
+    }
+    slashes.sort_by_key(|slash| slash.epoch);
+
+    let wallet = ctx.namada.wallet.read().await;
+    let source_address = wallet
+        .find_address(&source.name)
+        .ok_or_else(|| QueryError::Wallet(format!("No source address: {}", source.name)))?
+        .into_owned();
+    drop(wallet);
+    let validator_address =
+        Address::from_str(validator).expect("ValidatorAddress should be converted");
+
+    let details = tryhard::retry_fn(|| {
+        rpc::bonds_and_unbonds(
+            &ctx.namada.client,
+            &Some(source_address.clone()),
+            &Some(validator_address.clone()),
+        )
+    })
+    .with_config(retry_config)
+    .on_retry(|attempt, _, error| {
+        let error = error.to_string();
+        async move {
+            tracing::info!("Retry {} due to {}...", attempt, error);
+        }
+    })
+    .await
+    .map_err(QueryError::Rpc)?;
+
+    // the indexes of the slashes processed after the epoch
+    let new_slashes: BTreeSet<usize> = slashes
+        .iter()
+        .enumerate()
+        .filter(|(_, slash)| slash.epoch.0 + SLASH_PROCESSING_EPOCH_OFFSET > epoch)
+        .map(|(index, _)| index)
+        .collect();
+    let mut applied_slashes = BTreeSet::new();
+    let mut slashed_amount = SlashedAmount::default();
+    for detail in details.into_values() {
+        for bond in detail.bonds {
+            let bond_slashes: Vec<usize> = (0..slashes.len())
+                .filter(|index| bond.start <= slashes[*index].epoch)
+                .collect();
+            let slashed = slashed_since(bond.amount, &slashes, &bond_slashes, epoch)?;
+            slashed_amount.bond = slashed_amount
+                .bond
+                .checked_add(slashed)
+                .ok_or_else(|| QueryError::Convert("Slashed amount overflow".to_string()))?;
+            applied_slashes.extend(bond_slashes);
+        }
+        for unbond in detail.unbonds {
+            // the unbonded tokens were slashable until the unbond took effect
+            let end_epoch = unbond
+                .withdraw
+                .0
+                .saturating_sub(UNBONDING_LEN + CUBIC_SLASHING_WINDOW_LEN);
+            let unbond_slashes: Vec<usize> = (0..slashes.len())
+                .filter(|index| {
+                    let slash = &slashes[*index];
+                    unbond.start <= slash.epoch && slash.epoch.0 < end_epoch
+                })
+                .collect();
+            let slashed = slashed_since(unbond.amount, &slashes, &unbond_slashes, epoch)?;
+            slashed_amount.unbond = slashed_amount
+                .unbond
+                .checked_add(slashed)
+                .ok_or_else(|| QueryError::Convert("Slashed amount overflow".to_string()))?;
+            applied_slashes.extend(unbond_slashes);
+        }
+    }
+    // the rounding of each new slash can differ by 1
+    slashed_amount.tolerance =
+        token::Amount::from_u64(applied_slashes.intersection(&new_slashes).count() as u64);
+
+    Ok(slashed_amount)
+}
+
+/// Returns the reduction of the chunk by the slashes processed after the epoch
+fn slashed_since(
+    amount: token::Amount,
+    slashes: &[Slash],
+    chunk_slashes: &[usize],
+    epoch: Epoch,
+) -> Result<token::Amount, QueryError> {
+    let chunk_slashes: Vec<&Slash> = chunk_slashes.iter().map(|index| &slashes[*index]).collect();
+    let processed_slashes: Vec<&Slash> = chunk_slashes
+        .iter()
+        .filter(|slash| slash.epoch.0 + SLASH_PROCESSING_EPOCH_OFFSET <= epoch)
+        .copied()
+        .collect();
+    let pre_amount = apply_slashes(amount, &processed_slashes)?;
+    let post_amount = apply_slashes(amount, &chunk_slashes)?;
+
+    Ok(pre_amount.checked_sub(post_amount).unwrap_or_default())
+}
+
 pub async fn get_rewards(
     ctx: &Ctx,
     source: &Alias,
@@ -573,6 +954,180 @@ pub async fn get_proposals(
     Ok(proposals)
 }
 
+pub async fn find_proposal_id(
+    ctx: &Ctx,
+    author: &Alias,
+    start_epoch: Epoch,
+    activation_epoch: Epoch,
+    last_proposal_id: ProposalId,
+    retry_config: RetryConfig,
+) -> Result<Option<ProposalId>, QueryError> {
+    let wallet = ctx.namada.wallet.read().await;
+    let author_address = wallet
+        .find_address(&author.name)
+        .ok_or_else(|| QueryError::Wallet(format!("No author address: {}", author.name)))?
+        .into_owned();
+    drop(wallet);
+
+    // the proposal has been just submitted, search from the latest one
+    for proposal_id in (0..=last_proposal_id).rev() {
+        let proposal =
+            tryhard::retry_fn(|| rpc::query_proposal_by_id(&ctx.namada.client, proposal_id))
+                .with_config(retry_config)
+                .on_retry(|attempt, _, error| {
+                    let error = error.to_string();
+                    async move {
+                        tracing::info!("Retry {attempt} due to {error}...");
+                    }
+                })
+                .await
+                .map_err(QueryError::Rpc)?;
+        if proposal.is_some_and(|proposal| {
+            proposal.author == author_address
+                && proposal.voting_start_epoch.0 == start_epoch
+                && proposal.activation_epoch.0 == activation_epoch
+        }) {
+            return Ok(Some(proposal_id));
+        }
+    }
+
+    Ok(None)
+}
+
+pub async fn get_proposal_activation_epoch(
+    ctx: &Ctx,
+    proposal_id: ProposalId,
+    retry_config: RetryConfig,
+) -> Result<Option<Epoch>, QueryError> {
+    let proposal = tryhard::retry_fn(|| rpc::query_proposal_by_id(&ctx.namada.client, proposal_id))
+        .with_config(retry_config)
+        .on_retry(|attempt, _, error| {
+            let error = error.to_string();
+            async move {
+                tracing::info!("Retry {attempt} due to {error}...");
+            }
+        })
+        .await
+        .map_err(QueryError::Rpc)?;
+
+    Ok(proposal.map(|proposal| proposal.activation_epoch.0))
+}
+
+pub async fn get_proposal_votes(
+    ctx: &Ctx,
+    proposal_id: ProposalId,
+    retry_config: RetryConfig,
+) -> Result<Vec<(Address, Address, ProposalVote)>, QueryError> {
+    let votes = tryhard::retry_fn(|| rpc::query_proposal_votes(&ctx.namada.client, proposal_id))
+        .with_config(retry_config)
+        .on_retry(|attempt, _, error| {
+            let error = error.to_string();
+            async move {
+                tracing::info!("Retry {attempt} due to {error}...");
+            }
+        })
+        .await
+        .map_err(QueryError::Rpc)?;
+
+    Ok(votes
+        .into_iter()
+        .map(|vote| (vote.validator, vote.delegator, vote.data))
+        .collect())
+}
+
+pub async fn get_proposal_result(
+    ctx: &Ctx,
+    proposal_id: ProposalId,
+    retry_config: RetryConfig,
+) -> Result<Option<ProposalResult>, QueryError> {
+    tryhard::retry_fn(|| rpc::query_proposal_result(&ctx.namada.client, proposal_id))
+        .with_config(retry_config)
+        .on_retry(|attempt, _, error| {
+            let error = error.to_string();
+            async move {
+                tracing::info!("Retry {attempt} due to {error}...");
+            }
+        })
+        .await
+        .map_err(QueryError::Rpc)
+}
+
+pub async fn has_proposal_code(
+    ctx: &Ctx,
+    proposal_id: ProposalId,
+    retry_config: RetryConfig,
+) -> Result<bool, QueryError> {
+    let key = namada_sdk::governance::storage::keys::get_proposal_code_key(proposal_id);
+    tryhard::retry_fn(|| rpc::query_has_storage_key(&ctx.namada.client, &key))
+        .with_config(retry_config)
+        .on_retry(|attempt, _, error| {
+            let error = error.to_string();
+            async move {
+                tracing::info!("Retry {attempt} due to {error}...");
+            }
+        })
+        .await
+        .map_err(QueryError::Rpc)
+}
+
+pub async fn has_storage_key(
+    ctx: &Ctx,
+    key: &str,
+    retry_config: RetryConfig,
+) -> Result<bool, QueryError> {
+    let key = namada_sdk::storage::Key::parse(key)
+        .map_err(|e| QueryError::Convert(format!("Invalid storage key {key}: {e}")))?;
+    tryhard::retry_fn(|| rpc::query_has_storage_key(&ctx.namada.client, &key))
+        .with_config(retry_config)
+        .on_retry(|attempt, _, error| {
+            let error = error.to_string();
+            async move {
+                tracing::info!("Retry {attempt} due to {error}...");
+            }
+        })
+        .await
+        .map_err(QueryError::Rpc)
+}
+
+pub async fn get_pgf_stewards(
+    ctx: &Ctx,
+    retry_config: RetryConfig,
+) -> Result<HashMap<Address, HashMap<Address, Dec>>, QueryError> {
+    let stewards = tryhard::retry_fn(|| rpc::query_pgf_stewards(&ctx.namada.client))
+        .with_config(retry_config)
+        .on_retry(|attempt, _, error| {
+            let error = error.to_string();
+            async move {
+                tracing::info!("Retry {attempt} due to {error}...");
+            }
+        })
+        .await
+        .map_err(QueryError::Rpc)?;
+
+    Ok(stewards
+        .into_iter()
+        .map(|steward| (steward.address, steward.commission))
+        .collect())
+}
+
+pub async fn is_pgf_steward(
+    ctx: &Ctx,
+    target: &Alias,
+    retry_config: RetryConfig,
+) -> Result<(Address, bool), QueryError> {
+    let wallet = ctx.namada.wallet.read().await;
+    let target_address = wallet
+        .find_address(&target.name)
+        .ok_or_else(|| QueryError::Wallet(format!("No target address: {}", target.name)))?
+        .into_owned();
+    drop(wallet);
+
+    let stewards = get_pgf_stewards(ctx, retry_config).await?;
+    let is_steward = stewards.contains_key(&target_address);
+
+    Ok((target_address, is_steward))
+}
+
 pub async fn get_vote_results(
     ctx: &Ctx,
     target: &Alias,
@@ -610,3 +1165,51 @@ pub async fn get_vote_results(
 
     Ok(votes)
 }
+
+/// The IBC mint limit and the per-epoch throughput limit of the IBC token
+pub async fn get_ibc_rate_limits(
+    ctx: &Ctx,
+    denom: &str,
+    retry_config: RetryConfig,
+) -> Result<(token::Amount, token::Amount), QueryError> {
+    let token_address = ibc_token_address(denom);
+    let limits =
+        tryhard::retry_fn(|| rpc::query_ibc_rate_limits(&ctx.namada.client, &token_address))
+            .with_config(retry_config)
+            .on_retry(|attempt, _, error| {
+                let error = error.to_string();
+                async move {
+                    tracing::info!("Retry {attempt} due to {error}...");
+                }
+            })
+            .await
+            .map_err(QueryError::Rpc)?;
+
+    Ok((limits.mint_limit, limits.throughput_per_epoch_limit))
+}
+
+/// The amount of the IBC token minted on Namada
+pub async fn get_ibc_mint_amount(
+    ctx: &Ctx,
+    denom: &str,
+    retry_config: RetryConfig,
+) -> Result<token::Amount, QueryError> {
+    let token_address = ibc_token_address(denom);
+    let key = namada_sdk::ibc::storage::mint_amount_key(&token_address);
+    tryhard::retry_fn(|| async {
+        if rpc::query_has_storage_key(&ctx.namada.client, &key).await? {
+            rpc::query_storage_value(&ctx.namada.client, &key).await
+        } else {
+            Ok(token::Amount::zero())
+        }
+    })
+    .with_config(retry_config)
+    .on_retry(|attempt, _, error| {
+        let error = error.to_string();
+        async move {
+            tracing::info!("Retry {attempt} due to {error}...");
+        }
+    })
+    .await
+    .map_err(QueryError::Rpc)
+}
@@ -0,0 +1,46 @@
+use std::sync::Mutex;
+
+use antithesis_sdk::random::AntithesisRng;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+static SEEDED_RNG: Mutex<Option<ChaCha20Rng>> = Mutex::new(None);
+
+/// Make the following random choices deterministic. Without the seed, the
+/// randomness is provided by Antithesis.
+pub fn seed_rng(seed: u64) {
+    *SEEDED_RNG.lock().expect("RNG lock shouldn't be poisoned") =
+        Some(ChaCha20Rng::seed_from_u64(seed));
+}
+
+/// RNG for every random choice of the workload
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WorkloadRng;
+
+impl WorkloadRng {
+    fn with_rng<T>(f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+        let mut seeded_rng = SEEDED_RNG.lock().expect("RNG lock shouldn't be poisoned");
+        match seeded_rng.as_mut() {
+            Some(rng) => f(rng),
+            None => f(&mut AntithesisRng),
+        }
+    }
+}
+
+impl RngCore for WorkloadRng {
+    fn next_u32(&mut self) -> u32 {
+        Self::with_rng(|rng| rng.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        Self::with_rng(|rng| rng.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        Self::with_rng(|rng| rng.fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        Self::with_rng(|rng| rng.try_fill_bytes(dest))
+    }
+}
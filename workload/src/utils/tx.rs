@@ -7,7 +7,7 @@ use namada_sdk::collections::HashSet;
 use namada_sdk::control_flow::time;
 use namada_sdk::error::{Error as NamadaError, TxSubmitError};
 use namada_sdk::hash::Hash;
-use namada_sdk::ibc::core::host::types::identifiers::PortId;
+use namada_sdk::ibc::core::host::types::identifiers::{ChannelId, PortId};
 use namada_sdk::key::common;
 use namada_sdk::masp_primitives::transaction::Transaction as MaspTransaction;
 use namada_sdk::rpc::{self, InnerTxResult, TxResponse};
@@ -27,37 +27,52 @@ use crate::task::TaskSettings;
 use crate::types::{Alias, Amount, Height};
 use crate::utils::is_native_denom;
 
+/// The errors of the inner txs, and whether the tx was rejected by the
+/// invalid signatures, e.g. the signatures don't reach the threshold of the
+/// account
 fn get_tx_errors(
     cmts: HashSet<TxCommitments>,
     wrapper_hash: Option<Hash>,
     tx_response: &ProcessTxResponse,
-) -> Option<String> {
+) -> Option<(String, bool)> {
     if let ProcessTxResponse::Applied(result) = tx_response {
         if let Some(batch) = &result.batch {
             tracing::info!("batch result: {:#?}", batch);
 
-            let errors = cmts
+            let results = cmts
                 .iter()
                 .filter_map(|cmt| {
-                    batch
-                        .get_inner_tx_result(wrapper_hash.as_ref(), either::Right(cmt))
-                        .map(|res| match res.as_ref() {
-                            Ok(res) => serde_json::to_string(&res.vps_result.errors)
-                                .expect("errors should be json"),
-                            Err(e) => e.to_string(),
-                        })
+                    batch.get_inner_tx_result(wrapper_hash.as_ref(), either::Right(cmt))
+                })
+                .collect::<Vec<_>>();
+            let errors = results
+                .iter()
+                .map(|res| match res.as_ref() {
+                    Ok(res) => serde_json::to_string(&res.vps_result.errors)
+                        .expect("errors should be json"),
+                    Err(e) => e.to_string(),
                 })
                 .collect::<Vec<_>>()
                 .join(", ");
+            let is_invalid_sig = results
+                .iter()
+                .any(|res| matches!(res.as_ref(), Ok(res) if res.vps_result.invalid_sig));
 
-            return Some(errors);
+            return Some((errors, is_invalid_sig));
         } else {
-            return Some(result.info.clone());
+            return Some((result.info.clone(), false));
         }
     }
     None
 }
 
+/// Whether building the shielded tx failed because the shielded balance
+/// doesn't cover the amount and the fee, i.e. the MASP builder returned
+/// `InsufficientFunds`
+pub(crate) fn is_insufficient_shielded_funds(err: &str) -> bool {
+    err.contains("Insufficient funds")
+}
+
 async fn default_tx_arg(ctx: &Ctx) -> args::Tx {
     let wallet = ctx.namada.wallet.read().await;
     let nam = wallet
@@ -221,11 +236,13 @@ pub(crate) async fn execute_tx(
         .is_applied_and_valid(wrapper_hash.as_ref(), &first_cmt)
         .is_none()
     {
-        let errors = get_tx_errors(cmts, wrapper_hash, &tx_response).unwrap_or_default();
+        let (errors, is_invalid_sig) =
+            get_tx_errors(cmts, wrapper_hash, &tx_response).unwrap_or_default();
         if u64::from(gas_used) != 0 {
             return Err(TaskError::Execution {
                 err: errors,
                 height,
+                is_invalid_sig,
             });
         } else {
             return Err(TaskError::InsufficientGas {
@@ -279,6 +296,7 @@ async fn retry_tx_status_check(
 /// Generate a MASP transaction for a transfer from Cosmos to Namada
 pub async fn gen_shielding_tx(
     ctx: &Ctx,
+    channel_id: &ChannelId,
     target: PaymentAddress,
     denom: &str,
     amount: Amount,
@@ -299,7 +317,7 @@ pub async fn gen_shielding_tx(
         asset: IbcShieldingTransferAsset::LookupNamadaAddress {
             token: denom.to_string(),
             port_id: PortId::transfer(),
-            channel_id: ctx.namada_channel_id.clone(),
+            channel_id: channel_id.clone(),
         },
         amount: InputAmount::Validated(denominated_amount),
         expiration: args::TxExpiration::NoExpiration,
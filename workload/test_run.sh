@@ -4,8 +4,12 @@ MAX_RETRY_COUNT=15
 
 touch state-$WORKLOAD_ID.json
 echo "" > state-$WORKLOAD_ID.json
+rm -f state-$WORKLOAD_ID.json.*
 touch /opt/antithesis/test/v1/namada/state-$WORKLOAD_ID.json
 echo "" > /opt/antithesis/test/v1/namada/state-$WORKLOAD_ID.json
+rm -f /opt/antithesis/test/v1/namada/state-$WORKLOAD_ID.json.*
+rm -f steps-$WORKLOAD_ID.jsonl
+rm -f /opt/antithesis/test/v1/namada/steps-$WORKLOAD_ID.jsonl
 
 mkdir -p base/wallet-$WORKLOAD_ID
 mkdir -p base/masp-$WORKLOAD_ID